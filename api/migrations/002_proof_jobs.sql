@@ -0,0 +1,33 @@
+-- Proof Jobs Migration: 비동기 증명 작업 큐
+--
+-- POST /proof/jobs로 제출된 증명 작업의 상태를 저장해 GET /proof/jobs/:id로 조회.
+-- 요청은 private input(금액, salt)을 포함하므로 저장하지 않음.
+
+-- ============ Proof Jobs Table ============
+
+CREATE TABLE IF NOT EXISTS proof_jobs (
+    -- 작업 ID (응답의 job_id)
+    id UUID PRIMARY KEY,
+
+    -- 회로 종류: collateral, ltv, multi_asset_ltv, liquidation, ...
+    circuit VARCHAR(32) NOT NULL,
+
+    -- 작업 상태: pending, running, done, failed
+    status VARCHAR(16) NOT NULL,
+
+    -- 증명 응답 (JSON, done일 때)
+    result TEXT,
+
+    -- 실패 사유 (failed일 때)
+    error TEXT,
+
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- 인덱스: 재시작 시 중단된 작업 조회
+CREATE INDEX idx_proof_jobs_status ON proof_jobs(status);
+
+-- ============ Comments ============
+
+COMMENT ON TABLE proof_jobs IS '비동기 ZK 증명 작업 - 상태 조회용 (요청의 private input은 저장하지 않음)';
@@ -6,6 +6,8 @@
 use std::env;
 use anyhow::{Context, Result};
 
+use crate::services::ProvingBackend;
+
 /// 애플리케이션 설정
 #[derive(Debug, Clone)]
 pub struct Config {
@@ -22,9 +24,31 @@ pub struct Config {
     /// Ethereum RPC URL (스마트 컨트랙트 상호작용용)
     pub eth_rpc_url: String,
 
-    /// ZK Proving Key 경로 (옵션, 없으면 생성)
+    /// SRS와 proving/verifying key 저장 디렉터리
+    /// (옵션, 없으면 프로세스마다 메모리에서 생성)
     pub proving_key_path: Option<String>,
 
+    /// 증명 backend (기본값: ipa)
+    /// KZG backend(kzg-shplonk, kzg-gwc)는 BN254 위에서 EVM transcript 인코딩으로 증명
+    /// (온체인 검증에는 vk로 생성한 Solidity verifier가 별도로 필요)
+    pub proving_backend: ProvingBackend,
+
+    /// 동시에 실행하는 비동기 증명 작업 수 (기본값: 2)
+    pub proof_workers: usize,
+
+    /// 실행을 기다리는 비동기 증명 작업의 최대 수, 초과하면 503 (기본값: 64)
+    pub proof_job_queue_depth: usize,
+
+    /// 동시에 `create_proof`/`verify_proof`를 실행하는 수 (기본값: 2)
+    pub prover_concurrency: usize,
+
+    /// 실행 대기 중인 증명의 최대 수, 초과하면 503 (기본값: 16)
+    pub prover_queue_depth: usize,
+
+    /// 운영자 엔드포인트(솔벤시 증명 등) 인증 키
+    /// (옵션, 없으면 운영자 엔드포인트를 모두 거부)
+    pub operator_api_key: Option<String>,
+
     /// 환경 (development, staging, production)
     pub environment: Environment,
 }
@@ -48,7 +72,13 @@ impl Config {
     /// - `PORT`: 서버 포트 (기본값: 3001)
     /// - `PRICE_ORACLE_URL`: 가격 오라클 URL
     /// - `ETH_RPC_URL`: Ethereum RPC URL
-    /// - `PROVING_KEY_PATH`: ZK Proving Key 경로
+    /// - `PROVING_KEY_PATH`: SRS와 ZK 키 저장 디렉터리
+    /// - `PROVING_BACKEND`: ipa | kzg-shplonk | kzg-gwc (기본값: ipa)
+    /// - `PROOF_WORKERS`: 동시 증명 작업 수 (기본값: 2)
+    /// - `PROOF_JOB_QUEUE_DEPTH`: 증명 작업 대기열 길이 (기본값: 64)
+    /// - `PROVER_CONCURRENCY`: 동시 증명 생성/검증 수 (기본값: 2)
+    /// - `PROVER_QUEUE_DEPTH`: 증명 실행 대기열 길이 (기본값: 16)
+    /// - `OPERATOR_API_KEY`: 운영자 엔드포인트의 `Authorization: Bearer` 키
     /// - `ENVIRONMENT`: development | staging | production
     ///
     /// # Design Decision
@@ -87,6 +117,35 @@ impl Config {
 
             proving_key_path: env::var("PROVING_KEY_PATH").ok(),
 
+            proving_backend: env::var("PROVING_BACKEND")
+                .unwrap_or_else(|_| "ipa".to_string())
+                .parse()
+                .context("PROVING_BACKEND must be ipa, kzg-shplonk or kzg-gwc")?,
+
+            proof_workers: env::var("PROOF_WORKERS")
+                .unwrap_or_else(|_| "2".to_string())
+                .parse()
+                .context("PROOF_WORKERS must be a valid number")?,
+
+            proof_job_queue_depth: env::var("PROOF_JOB_QUEUE_DEPTH")
+                .unwrap_or_else(|_| "64".to_string())
+                .parse()
+                .context("PROOF_JOB_QUEUE_DEPTH must be a valid number")?,
+
+            prover_concurrency: env::var("PROVER_CONCURRENCY")
+                .unwrap_or_else(|_| "2".to_string())
+                .parse()
+                .context("PROVER_CONCURRENCY must be a valid number")?,
+
+            prover_queue_depth: env::var("PROVER_QUEUE_DEPTH")
+                .unwrap_or_else(|_| "16".to_string())
+                .parse()
+                .context("PROVER_QUEUE_DEPTH must be a valid number")?,
+
+            operator_api_key: env::var("OPERATOR_API_KEY")
+                .ok()
+                .filter(|key| !key.is_empty()),
+
             environment,
         })
     }
@@ -106,6 +165,12 @@ mod tests {
         // 환경변수 없이 기본값으로 설정 생성
         let config = Config::from_env().unwrap();
         assert_eq!(config.port, 3001);
+        assert_eq!(config.proving_backend, ProvingBackend::Ipa);
+        assert_eq!(config.proof_workers, 2);
+        assert_eq!(config.proof_job_queue_depth, 64);
+        assert_eq!(config.prover_concurrency, 2);
+        assert_eq!(config.prover_queue_depth, 16);
+        assert_eq!(config.operator_api_key, None);
         assert_eq!(config.environment, Environment::Development);
     }
 }
@@ -7,8 +7,13 @@ mod models;
 mod repository;
 
 pub use models::*;
+pub use repository::ProofJobRepository;
+#[cfg(test)]
+pub use repository::mock;
 use anyhow::Result;
+use async_trait::async_trait;
 use sqlx::{postgres::PgPoolOptions, PgPool};
+use uuid::Uuid;
 
 /// 데이터베이스 연결 및 쿼리 담당
 pub struct Database {
@@ -73,6 +78,31 @@ impl Database {
         Ok(position)
     }
 
+    /// Commitment가 등록된 모든 포지션 조회 (주소 순)
+    ///
+    /// 솔벤시 증명이 포함해야 하는 기준 포지션 집합입니다.
+    pub async fn get_committed_positions(&self) -> Result<Vec<Position>> {
+        let positions = sqlx::query_as::<_, Position>(
+            r#"
+            SELECT
+                address,
+                has_deposit,
+                has_borrow,
+                borrowed_amount,
+                collateral_commitment,
+                debt_commitment,
+                updated_at
+            FROM positions
+            WHERE collateral_commitment IS NOT NULL
+            ORDER BY address
+            "#
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(positions)
+    }
+
     /// 포지션 히스토리 조회 (페이지네이션)
     pub async fn get_position_history(
         &self,
@@ -189,3 +219,64 @@ impl Database {
         Ok(())
     }
 }
+
+#[async_trait]
+impl ProofJobRepository for Database {
+    async fn save_job(&self, job: &ProofJobRecord) -> Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO proof_jobs (
+                id, circuit, status, result, error, created_at, updated_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            ON CONFLICT (id)
+            DO UPDATE SET
+                status = EXCLUDED.status,
+                result = EXCLUDED.result,
+                error = EXCLUDED.error,
+                updated_at = EXCLUDED.updated_at
+            "#
+        )
+        .bind(job.id)
+        .bind(&job.circuit)
+        .bind(&job.status)
+        .bind(&job.result)
+        .bind(&job.error)
+        .bind(job.created_at)
+        .bind(job.updated_at)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn find_job(&self, id: Uuid) -> Result<Option<ProofJobRecord>> {
+        let job = sqlx::query_as::<_, ProofJobRecord>(
+            r#"
+            SELECT id, circuit, status, result, error, created_at, updated_at
+            FROM proof_jobs
+            WHERE id = $1
+            "#
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(job)
+    }
+
+    async fn find_unfinished_jobs(&self) -> Result<Vec<ProofJobRecord>> {
+        let jobs = sqlx::query_as::<_, ProofJobRecord>(
+            r#"
+            SELECT id, circuit, status, result, error, created_at, updated_at
+            FROM proof_jobs
+            WHERE status IN ('pending', 'running')
+            ORDER BY created_at
+            "#
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(jobs)
+    }
+}
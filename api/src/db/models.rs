@@ -5,6 +5,7 @@
 
 use chrono::{DateTime, Utc};
 use sqlx::FromRow;
+use uuid::Uuid;
 
 /// 사용자 포지션
 #[derive(Debug, Clone, FromRow)]
@@ -70,3 +71,25 @@ pub struct ProofLog {
     pub generation_time_ms: i64,
     pub created_at: DateTime<Utc>,
 }
+
+/// 비동기 증명 작업
+#[derive(Debug, Clone, FromRow)]
+pub struct ProofJobRecord {
+    /// 작업 ID
+    pub id: Uuid,
+
+    /// 회로 종류 (collateral, ltv, ...)
+    pub circuit: String,
+
+    /// 작업 상태 (pending, running, done, failed)
+    pub status: String,
+
+    /// 증명 응답 JSON (done)
+    pub result: Option<String>,
+
+    /// 실패 사유 (failed)
+    pub error: Option<String>,
+
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
@@ -8,8 +8,9 @@
 
 use async_trait::async_trait;
 use anyhow::Result;
+use uuid::Uuid;
 
-use super::models::{Position, PositionEvent};
+use super::models::{Position, PositionEvent, ProofJobRecord};
 
 /// Position Repository 인터페이스 (향후 확장용)
 #[async_trait]
@@ -24,6 +25,18 @@ pub trait PositionRepository: Send + Sync {
     ) -> Result<(Vec<PositionEvent>, i64)>;
 }
 
+/// Proof Job Repository 인터페이스
+///
+/// 비동기 증명 작업 큐가 사용 (PostgreSQL 또는 테스트용 Mock)
+#[async_trait]
+pub trait ProofJobRepository: Send + Sync {
+    /// 작업 저장 (같은 id가 있으면 갱신)
+    async fn save_job(&self, job: &ProofJobRecord) -> Result<()>;
+    async fn find_job(&self, id: Uuid) -> Result<Option<ProofJobRecord>>;
+    /// pending/running 상태의 작업 (생성 순)
+    async fn find_unfinished_jobs(&self) -> Result<Vec<ProofJobRecord>>;
+}
+
 // PostgreSQL 구현은 db/mod.rs의 Database 구조체에 있음
 // 테스트용 Mock 구현:
 
@@ -69,4 +82,41 @@ pub mod mock {
             Ok((vec![], 0))
         }
     }
+
+    pub struct MockProofJobRepository {
+        jobs: RwLock<HashMap<Uuid, ProofJobRecord>>,
+    }
+
+    impl MockProofJobRepository {
+        pub fn new() -> Self {
+            Self {
+                jobs: RwLock::new(HashMap::new()),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl ProofJobRepository for MockProofJobRepository {
+        async fn save_job(&self, job: &ProofJobRecord) -> Result<()> {
+            let mut jobs = self.jobs.write().unwrap();
+            jobs.insert(job.id, job.clone());
+            Ok(())
+        }
+
+        async fn find_job(&self, id: Uuid) -> Result<Option<ProofJobRecord>> {
+            let jobs = self.jobs.read().unwrap();
+            Ok(jobs.get(&id).cloned())
+        }
+
+        async fn find_unfinished_jobs(&self) -> Result<Vec<ProofJobRecord>> {
+            let jobs = self.jobs.read().unwrap();
+            let mut unfinished: Vec<_> = jobs
+                .values()
+                .filter(|job| job.status == "pending" || job.status == "running")
+                .cloned()
+                .collect();
+            unfinished.sort_by_key(|job| job.created_at);
+            Ok(unfinished)
+        }
+    }
 }
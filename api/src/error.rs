@@ -11,6 +11,8 @@ use axum::{
 use serde::Serialize;
 use thiserror::Error;
 
+use crate::services::{InvalidPublicInput, ProofJobQueueFull, ProverBusy, UnregisteredCommitment};
+
 /// API 에러 타입
 ///
 /// # Design Decision
@@ -153,8 +155,26 @@ impl From<sqlx::Error> for ApiError {
 }
 
 /// anyhow 에러를 ApiError로 변환
+///
+/// Prover 실행기 포화(`ProverBusy`)와 증명 작업 큐 포화(`ProofJobQueueFull`)는 재시도 가능한 503으로,
+/// 검증 요청의 잘못된 public input(`InvalidPublicInput`)은 400으로,
+/// 등록되지 않은 commitment(`UnregisteredCommitment`)는 `InvalidCommitment`로 변환
 impl From<anyhow::Error> for ApiError {
     fn from(err: anyhow::Error) -> Self {
+        if let Some(busy) = err.downcast_ref::<ProverBusy>() {
+            tracing::warn!("Prover busy: {}", busy);
+            return ApiError::ServiceUnavailable("Prover".to_string());
+        }
+        if let Some(full) = err.downcast_ref::<ProofJobQueueFull>() {
+            tracing::warn!("Proof job queue full: {}", full);
+            return ApiError::ServiceUnavailable("Proof job queue".to_string());
+        }
+        if let Some(invalid) = err.downcast_ref::<InvalidPublicInput>() {
+            return ApiError::ValidationError(invalid.to_string());
+        }
+        if let Some(unregistered) = err.downcast_ref::<UnregisteredCommitment>() {
+            return ApiError::InvalidCommitment(unregistered.to_string());
+        }
         tracing::error!("Anyhow error: {:?}", err);
         ApiError::InternalError
     }
@@ -49,7 +49,9 @@
 //! }
 //! ```
 
+use axum::extract::FromRef;
 use std::sync::Arc;
+use tokio::sync::RwLock;
 
 pub mod config;
 pub mod error;
@@ -62,7 +64,9 @@ pub mod types;
 pub use config::Config;
 pub use error::ApiError;
 pub use db::Database;
-pub use services::{ZKProver, PriceOracle};
+pub use services::{ZKProver, PriceOracle, ProofJobQueue, ProverExecutor, WsHub};
+
+use routes::proof::SolvencyProofResponse;
 
 /// 애플리케이션 전역 상태
 #[derive(Clone)]
@@ -71,4 +75,17 @@ pub struct AppState {
     pub zk_prover: Arc<ZKProver>,
     pub price_oracle: Arc<PriceOracle>,
     pub config: Arc<Config>,
+    /// 가장 최근에 생성된 솔벤시 증명
+    pub latest_solvency_proof: Arc<RwLock<Option<SolvencyProofResponse>>>,
+    /// WebSocket 브로드캐스트 허브
+    pub ws_hub: Arc<WsHub>,
+    /// 비동기 증명 작업 큐
+    pub proof_jobs: Arc<ProofJobQueue>,
+}
+
+/// `/ws` 핸들러는 `WsHub`만 사용
+impl FromRef<AppState> for Arc<WsHub> {
+    fn from_ref(state: &AppState) -> Self {
+        state.ws_hub.clone()
+    }
 }
@@ -33,6 +33,7 @@
 
 use std::net::SocketAddr;
 use std::sync::Arc;
+use tokio::sync::RwLock;
 
 use axum::{
     routing::{get, post},
@@ -44,7 +45,7 @@ use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
 // 라이브러리에서 가져오기
 use zk_lending_api::{
-    AppState, Config, Database, ZKProver, PriceOracle,
+    AppState, Config, Database, ZKProver, PriceOracle, ProofJobQueue, ProverExecutor, WsHub,
     routes, config, services,
 };
 
@@ -76,20 +77,51 @@ async fn main() -> anyhow::Result<()> {
     tracing::info!("📦 Migrations completed");
 
     // 서비스 초기화
-    let zk_prover = ZKProver::new()?;
-    tracing::info!("🔐 ZK Prover initialized");
+    // 키 저장소가 설정되면 SRS와 모든 회로 키를 디스크에서 로드 (없으면 생성 후 저장)
+    // 증명 생성/검증은 runtime 스레드를 막지 않도록 전용 실행기에서 실행
+    let executor = ProverExecutor::new(config.prover_concurrency, config.prover_queue_depth);
+    let zk_prover = match &config.proving_key_path {
+        Some(dir) => {
+            let prover = ZKProver::with_key_store(config.proving_backend, dir)?.with_executor(executor);
+            prover.preload_keys().await?;
+            prover
+        }
+        None => ZKProver::with_backend(config.proving_backend)?.with_executor(executor),
+    };
+    tracing::info!("🔐 ZK Prover initialized ({})", zk_prover.proving_system());
 
     let price_oracle = PriceOracle::new(&config.price_oracle_url);
     tracing::info!("💰 Price Oracle connected");
 
+    let db = Arc::new(db);
+    let ws_hub = Arc::new(WsHub::new());
+
+    // 증명 작업 큐: 요청을 저장하지 않으므로 재시작 전 미완료 작업은 실패로 표시
+    let proof_jobs = Arc::new(ProofJobQueue::new(
+        db.clone(),
+        ws_hub.clone(),
+        config.proof_workers,
+        config.proof_job_queue_depth,
+    ));
+    proof_jobs.fail_interrupted().await?;
+
     // 앱 상태 구성
     let state = AppState {
-        db: Arc::new(db),
+        db,
         zk_prover: Arc::new(zk_prover),
         price_oracle: Arc::new(price_oracle),
         config: Arc::new(config.clone()),
+        latest_solvency_proof: Arc::new(RwLock::new(None)),
+        ws_hub,
+        proof_jobs: proof_jobs.clone(),
     };
 
+    let job_state = state.clone();
+    proof_jobs
+        .start(move |request| routes::proof::run_proof_job(job_state.clone(), request))
+        .await?;
+    tracing::info!("⚙️  Proof job workers started");
+
     // 라우터 구성
     let app = create_router(state);
 
@@ -112,13 +144,27 @@ async fn main() -> anyhow::Result<()> {
 ///
 /// POST /proof/collateral    - 담보 충분 증명 생성
 /// POST /proof/ltv           - LTV 비율 증명 생성
+/// POST /proof/ltv/multi-asset - 다중 담보 LTV 증명 생성
 /// POST /proof/liquidation   - 청산 가능 증명 생성
+/// POST /proof/liquidation/settlement - 청산 정산 (상환액, 압류 담보) 증명 생성
+/// POST /proof/health-factor - Health factor 구간 증명 생성
+/// POST /proof/withdraw      - 담보 인출 증명 생성
+/// POST /proof/transition    - Commitment 갱신 증명 생성
+/// POST /proof/interest      - 이자 반영 부채 commitment 증명 생성
+/// GET  /proof/keys          - 준비된 verifying key 해시 목록
+/// POST /proof/verify        - 서버 verifying key로 proof 검증
+/// POST /proof/solvency      - 프로토콜 솔벤시 증명 생성 (최신 증명으로 보관)
+/// GET  /proof/solvency/latest - 최신 솔벤시 증명 조회
+/// POST /proof/jobs          - 비동기 증명 작업 제출 (job id 반환)
+/// GET  /proof/jobs/:id      - 증명 작업 상태/결과 조회
 ///
 /// POST /commitment/create   - 커밋먼트 계산
 /// POST /commitment/verify   - 커밋먼트 검증
 ///
 /// GET  /position/:address   - 사용자 포지션 조회
 /// GET  /position/:address/history - 포지션 히스토리
+///
+/// GET  /ws                  - WebSocket (가격, Pool 상태, 증명 작업 상태)
 /// ```
 fn create_router(state: AppState) -> Router {
     // CORS 설정
@@ -138,6 +184,8 @@ fn create_router(state: AppState) -> Router {
             .allow_origin(origins)
             .allow_methods([axum::http::Method::GET, axum::http::Method::POST])
             .allow_headers([axum::http::header::CONTENT_TYPE])
+            // GET /proof/params의 backend 이름 (브라우저 증명용)
+            .expose_headers([axum::http::HeaderName::from_static("x-proving-backend")])
     } else {
         // 개발: localhost 허용
         CorsLayer::new()
@@ -148,6 +196,7 @@ fn create_router(state: AppState) -> Router {
             ])
             .allow_methods(Any)
             .allow_headers(Any)
+            .expose_headers(Any)
     };
 
     Router::new()
@@ -157,7 +206,23 @@ fn create_router(state: AppState) -> Router {
         // Proof generation
         .route("/proof/collateral", post(routes::proof::generate_collateral_proof))
         .route("/proof/ltv", post(routes::proof::generate_ltv_proof))
+        .route("/proof/ltv/multi-asset", post(routes::proof::generate_multi_asset_ltv_proof))
         .route("/proof/liquidation", post(routes::proof::generate_liquidation_proof))
+        .route("/proof/liquidation/settlement", post(routes::proof::generate_liquidation_settlement_proof))
+        .route("/proof/health-factor", post(routes::proof::generate_health_factor_proof))
+        .route("/proof/health-factor/verify", post(routes::proof::verify_health_factor_proof))
+        .route("/proof/withdraw", post(routes::proof::generate_withdraw_proof))
+        .route("/proof/transition", post(routes::proof::generate_transition_proof))
+        .route("/proof/interest", post(routes::proof::generate_interest_proof))
+        .route("/proof/keys", get(routes::proof::get_verifying_keys))
+        .route("/proof/params", get(routes::proof::get_params))
+        .route("/proof/verify", post(routes::proof::verify_proof))
+        .route("/proof/solvency", post(routes::proof::generate_solvency_proof))
+        .route("/proof/solvency/latest", get(routes::proof::get_latest_solvency_proof))
+
+        // Proof jobs
+        .route("/proof/jobs", post(routes::proof::submit_proof_job))
+        .route("/proof/jobs/:id", get(routes::proof::get_proof_job))
 
         // Commitment
         .route("/commitment/create", post(routes::commitment::create_commitment))
@@ -170,6 +235,9 @@ fn create_router(state: AppState) -> Router {
         // Price
         .route("/price/eth", get(routes::price::get_eth_price))
 
+        // WebSocket
+        .route("/ws", get(routes::ws::ws_handler))
+
         // 미들웨어
         .layer(TraceLayer::new_for_http())
         .layer(cors)
@@ -6,7 +6,7 @@
 use axum::{extract::State, Json};
 use serde::Serialize;
 
-use crate::AppState;
+use crate::{services::ProverMetrics, AppState};
 
 /// Health check 응답
 #[derive(Serialize)]
@@ -14,6 +14,8 @@ pub struct HealthResponse {
     pub status: String,
     pub version: String,
     pub database: DatabaseStatus,
+    /// 증명 실행기 상태 (대기열, 대기 시간)
+    pub prover: ProverMetrics,
     pub timestamp: String,
 }
 
@@ -46,6 +48,7 @@ pub async fn health_check(
         status: if db_status.connected { "healthy" } else { "degraded" }.to_string(),
         version: env!("CARGO_PKG_VERSION").to_string(),
         database: db_status,
+        prover: state.zk_prover.prover_metrics(),
         timestamp: chrono::Utc::now().to_rfc3339(),
     })
 }
@@ -163,7 +163,7 @@ pub async fn get_position_history(
 
 // ============ Helpers ============
 
-fn is_valid_ethereum_address(addr: &str) -> bool {
+pub(crate) fn is_valid_ethereum_address(addr: &str) -> bool {
     // 0x로 시작하고 40자리 hex
     addr.starts_with("0x") && addr.len() == 42 && addr[2..].chars().all(|c| c.is_ascii_hexdigit())
 }
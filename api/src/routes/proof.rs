@@ -1,17 +1,45 @@
 //! ZK Proof Generation Endpoints
 //!
-//! Provides REST API endpoints for generating ZK proofs (collateral, LTV, liquidation).
-//! Proofs are generated server-side using Halo2 circuits.
+//! Provides REST API endpoints for generating ZK proofs (collateral, LTV, multi-asset LTV,
+//! liquidation and settlement, health factor bucket, withdraw, commitment transition,
+//! interest accrual, protocol solvency).
+//! Proofs are generated server-side using Halo2 circuits, either inline (POST /proof/<circuit>)
+//! or as background jobs (POST /proof/jobs, polled via GET /proof/jobs/:id).
 
-use axum::{extract::State, Json};
+use axum::{
+    extract::{Path, State},
+    http::{header, HeaderMap, StatusCode},
+    response::IntoResponse,
+    Json,
+};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
-use crate::{AppState, error::ApiError, services::ProofResult};
+use crate::{
+    AppState,
+    error::ApiError,
+    routes::position::is_valid_ethereum_address,
+    services::{
+        decode_proof_bytes, parse_u64_hex, CircuitId, CollateralAsset, CommittedPosition,
+        LiquidationUrgency, LiquidationWarning, PriceData, ProofJob, ProofJobError, ProofResult,
+        ProverBusy, UnregisteredCommitment, MAX_COLLATERAL_ASSETS, SOLVENCY_TREE_DEPTH,
+    },
+};
+use zk_private_lending_circuits::{health_factor::HF_PRECISION, price_ltv::MAX_DECIMALS};
+
+/// 프로토콜 청산 임계값 (%, `ZKLendingPool.LIQUIDATION_THRESHOLD`)
+const LIQUIDATION_THRESHOLD: u64 = 80;
+
+/// 제출된 증명의 가격과 오라클 가격의 허용 오차 (basis points)
+const ORACLE_PRICE_TOLERANCE_BPS: u128 = 100;
+
+/// 이보다 오래된 오라클 가격으로는 증명의 가격을 확인하지 않음 (초)
+const ORACLE_MAX_AGE_SECS: i64 = 300;
 
 // ============ Request/Response Types ============
 
 /// 담보 증명 요청
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CollateralProofRequest {
     /// 실제 담보 금액 (wei 단위)
     pub collateral: String,
@@ -22,7 +50,7 @@ pub struct CollateralProofRequest {
 }
 
 /// LTV 증명 요청
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LtvProofRequest {
     /// 담보 금액 (wei)
     pub collateral: String,
@@ -30,14 +58,52 @@ pub struct LtvProofRequest {
     pub debt: String,
     /// 최대 허용 LTV (%)
     pub max_ltv: u64,
+    /// 담보 자산 가격 (USD, 8 decimals)
+    pub price: String,
+    /// 담보 토큰 decimals (기본값 18, ETH)
+    #[serde(default = "default_collateral_decimals")]
+    pub collateral_decimals: u64,
+    /// 부채 토큰 decimals (기본값 6, USDC)
+    #[serde(default = "default_debt_decimals")]
+    pub debt_decimals: u64,
     /// 담보 salt
     pub collateral_salt: String,
     /// 부채 salt
     pub debt_salt: String,
 }
 
+/// 다중 담보 자산 항목
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CollateralAssetRequest {
+    /// 담보 수량 (토큰 base unit, 예: WBTC는 8 decimals)
+    pub amount: String,
+    /// 자산 가격 (8 decimals, public)
+    pub price: String,
+    /// 담보 인정 비율 (%, public)
+    pub collateral_factor: u64,
+    /// 담보 토큰 decimals (public, 기본값 18)
+    #[serde(default = "default_collateral_decimals")]
+    pub decimals: u64,
+    /// 담보 commitment의 salt
+    pub salt: String,
+}
+
+/// 다중 담보 LTV 증명 요청
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MultiAssetLtvProofRequest {
+    /// 담보 자산 목록 (1..=MAX_COLLATERAL_ASSETS)
+    pub assets: Vec<CollateralAssetRequest>,
+    /// 부채 금액 (부채 토큰 base unit)
+    pub debt: String,
+    /// 부채 토큰 decimals (public, 기본값 6, USDC)
+    #[serde(default = "default_debt_decimals")]
+    pub debt_decimals: u64,
+    /// 부채 salt
+    pub debt_salt: String,
+}
+
 /// 청산 증명 요청
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LiquidationProofRequest {
     /// 담보 금액
     pub collateral: String,
@@ -47,14 +113,188 @@ pub struct LiquidationProofRequest {
     pub price: String,
     /// 청산 임계값 (%)
     pub liquidation_threshold: u64,
-    /// salt
-    pub salt: String,
+    /// 담보 salt (CommitmentRegistry의 담보 commitment)
+    pub collateral_salt: String,
+    /// 부채 salt (CommitmentRegistry의 부채 commitment)
+    pub debt_salt: String,
+}
+
+/// 청산 정산 증명 요청 (상환 가능 부채, 압류 담보)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LiquidationSettlementProofRequest {
+    /// 청산 전 담보 금액 (wei)
+    pub collateral: String,
+    /// 청산 전 부채 금액 (6 decimals, USDC)
+    pub debt: String,
+    /// ETH 가격 (8 decimals)
+    pub price: String,
+    /// 한 번에 상환 가능한 부채 비율 (%, 기본값 100 = 전액)
+    #[serde(default = "default_close_factor")]
+    pub close_factor: u64,
+    /// 청산 보너스 (%, 기본값 5 = `ZKLendingPool.LIQUIDATION_BONUS`)
+    #[serde(default = "default_liquidation_bonus")]
+    pub liquidation_bonus: u64,
+    /// 청산자가 상환할 부채 (public)
+    pub repay: String,
+    /// 청산 전 담보 salt
+    pub collateral_salt: String,
+    /// 청산 전 부채 salt
+    pub debt_salt: String,
+    /// 청산 후 담보 commitment의 salt
+    pub new_collateral_salt: String,
+    /// 청산 후 부채 commitment의 salt
+    pub new_debt_salt: String,
+}
+
+/// Health factor 구간 증명 요청 (청산 경고 opt-in)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HealthFactorProofRequest {
+    /// 담보 금액
+    pub collateral: String,
+    /// 부채 금액
+    pub debt: String,
+    /// ETH 가격 (8 decimals)
+    pub price: String,
+    /// 청산 임계값 (%)
+    pub liquidation_threshold: u64,
+    /// 증명할 긴급도 구간 (서버는 이 구간만 알게 됨)
+    pub urgency: LiquidationUrgency,
+    /// 담보 salt
+    pub collateral_salt: String,
+    /// 부채 salt
+    pub debt_salt: String,
+}
+
+/// 브라우저에서 생성한 Health factor 구간 증명 제출 (청산 경고 등록)
+#[derive(Debug, Deserialize)]
+pub struct HealthFactorBucketSubmission {
+    /// 포지션 주소 (경고를 받을 사용자 채널)
+    pub address: String,
+    /// Halo2 proof transcript (0x 접두사 hex)
+    pub proof: String,
+    /// Public inputs: [lo, hi, price, liquidation_threshold, collateral_commitment, debt_commitment]
+    pub public_inputs: Vec<String>,
+}
+
+/// 담보 인출 증명 요청
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WithdrawProofRequest {
+    /// 인출 전 담보 금액 (wei)
+    pub old_collateral: String,
+    /// 인출 금액 (wei, public)
+    pub withdraw_amount: String,
+    /// 부채 금액
+    pub debt: String,
+    /// 최대 허용 LTV (%)
+    pub max_ltv: u64,
+    /// 기존 담보 commitment의 salt (nullifier도 이 salt로 계산)
+    pub old_salt: String,
+    /// 새 담보 commitment의 salt
+    pub new_salt: String,
+    /// 부채 salt
+    pub debt_salt: String,
+}
+
+/// Commitment 갱신 증명 요청 (담보 추가 예치, 부분 상환)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransitionProofRequest {
+    /// 기존 commitment의 값
+    pub old_value: String,
+    /// 변경량 (public)
+    pub delta: String,
+    /// true: old_value + delta (추가 예치), false: old_value - delta (상환)
+    pub increase: bool,
+    /// 기존 commitment의 salt (nullifier도 이 salt로 계산)
+    pub old_salt: String,
+    /// 새 commitment의 salt
+    pub new_salt: String,
+}
+
+/// 이자 반영 증명 요청 (부채 commitment를 현재 부채로 갱신)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InterestProofRequest {
+    /// 기존 부채 commitment의 원금
+    pub principal: String,
+    /// 연 이자율 (basis points, `getCurrentInterestRate`)
+    pub rate: u64,
+    /// 마지막 갱신 이후 경과 시간 (초)
+    pub elapsed: u64,
+    /// 컨트랙트에 이미 정산된 이자 (`accruedInterest[user]`)
+    #[serde(default = "default_accrued_interest")]
+    pub accrued_interest: String,
+    /// 기존 부채 commitment의 salt (nullifier도 이 salt로 계산)
+    pub old_salt: String,
+    /// 새 부채 commitment의 salt
+    pub new_salt: String,
+}
+
+/// 솔벤시 증명에 포함할 포지션
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SolvencyPositionRequest {
+    /// 포지션 주소 (등록된 commitment와 대조)
+    pub address: String,
+    /// 담보 금액 (wei)
+    pub collateral: String,
+    /// 부채 금액 (6 decimals, USDC)
+    pub debt: String,
+    /// 담보 salt (CommitmentRegistry의 담보 commitment)
+    pub collateral_salt: String,
+    /// 부채 salt (CommitmentRegistry의 부채 commitment)
+    pub debt_salt: String,
+}
+
+/// 프로토콜 솔벤시 증명 요청 (운영자용)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SolvencyProofRequest {
+    /// commitment가 등록된 모든 포지션의 opening
+    /// (최대 2^SOLVENCY_TREE_DEPTH개, 주소 순으로 트리의 앞 슬롯부터 채움)
+    pub positions: Vec<SolvencyPositionRequest>,
+    /// ETH 가격 (8 decimals, 생략 시 가격 오라클 조회)
+    /// 지정하면 증명만 반환하고 최신 솔벤시 증명은 갱신하지 않음
+    pub price: Option<String>,
+}
+
+/// 비동기 증명 작업 요청 (POST /proof/jobs)
+///
+/// `request`는 같은 회로의 동기 엔드포인트 요청 본문과 동일합니다.
+/// 예: `{"circuit": "collateral", "request": {"collateral": "...", ...}}`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "circuit", content = "request", rename_all = "snake_case")]
+pub enum ProofJobRequest {
+    Collateral(CollateralProofRequest),
+    Ltv(LtvProofRequest),
+    MultiAssetLtv(MultiAssetLtvProofRequest),
+    Liquidation(LiquidationProofRequest),
+    LiquidationSettlement(LiquidationSettlementProofRequest),
+    HealthFactor(HealthFactorProofRequest),
+    Withdraw(WithdrawProofRequest),
+    Transition(TransitionProofRequest),
+    Interest(InterestProofRequest),
+    Solvency(SolvencyProofRequest),
+}
+
+impl ProofJobRequest {
+    /// 요청의 `circuit` 태그
+    pub fn circuit(&self) -> &'static str {
+        match self {
+            ProofJobRequest::Collateral(_) => "collateral",
+            ProofJobRequest::Ltv(_) => "ltv",
+            ProofJobRequest::MultiAssetLtv(_) => "multi_asset_ltv",
+            ProofJobRequest::Liquidation(_) => "liquidation",
+            ProofJobRequest::LiquidationSettlement(_) => "liquidation_settlement",
+            ProofJobRequest::HealthFactor(_) => "health_factor",
+            ProofJobRequest::Withdraw(_) => "withdraw",
+            ProofJobRequest::Transition(_) => "transition",
+            ProofJobRequest::Interest(_) => "interest",
+            ProofJobRequest::Solvency(_) => "solvency",
+        }
+    }
 }
 
 /// Proof 응답
 #[derive(Debug, Serialize)]
 pub struct ProofResponse {
-    /// Groth16 proof (hex encoded)
+    /// Halo2 proof
     pub proof: ProofData,
     /// Public inputs (hex encoded)
     pub public_inputs: Vec<String>,
@@ -64,14 +304,97 @@ pub struct ProofResponse {
     pub generation_time_ms: u64,
 }
 
+/// 솔벤시 증명 응답 (최신 증명으로 보관되어 GET /proof/solvency/latest로 조회)
+#[derive(Debug, Clone, Serialize)]
+pub struct SolvencyProofResponse {
+    /// Halo2 proof
+    pub proof: ProofData,
+    /// Public inputs: [root, price, total_collateral, total_debt] (hex encoded)
+    pub public_inputs: Vec<String>,
+    /// 포지션 트리의 Merkle root
+    pub root: String,
+    /// 증명에 사용한 ETH 가격 (8 decimals)
+    pub price: String,
+    /// 총 담보 (wei)
+    pub total_collateral: String,
+    /// 총 부채 (6 decimals, USDC)
+    pub total_debt: String,
+    /// 포함된 포지션 수
+    pub position_count: usize,
+    /// 증명 생성 시각 (RFC 3339)
+    pub generated_at: String,
+    /// 증명 생성 시간 (ms)
+    pub generation_time_ms: u64,
+}
+
+/// Verifying key 정보
+#[derive(Debug, Serialize)]
+pub struct VerifyingKeyInfo {
+    /// 회로 식별자 (`ProofData.circuit_id`)
+    pub circuit_id: String,
+    /// Verifying key 해시 (`ProofData.vk_hash`)
+    pub vk_hash: String,
+}
+
+/// Verifying key 목록 응답
+#[derive(Debug, Serialize)]
+pub struct VerifyingKeysResponse {
+    /// 증명 시스템 식별자
+    pub proving_system: String,
+    /// 준비된 verifying key (아직 생성/로드되지 않은 회로는 제외)
+    pub keys: Vec<VerifyingKeyInfo>,
+}
+
+/// Proof 검증 요청
+#[derive(Debug, Deserialize)]
+pub struct VerifyProofRequest {
+    /// 회로 식별자 (`ProofData.circuit_id`, 예: "collateral", "multi_asset_ltv_2")
+    pub circuit_id: String,
+    /// Halo2 proof transcript (`ProofData.bytes`, 0x 접두사 hex)
+    pub proof: String,
+    /// Public inputs (증명 응답의 `public_inputs`, 32바이트 field element hex)
+    pub public_inputs: Vec<String>,
+}
+
+/// Proof 검증 응답
+#[derive(Debug, Serialize)]
+pub struct VerifyProofResponse {
+    /// 서버의 verifying key로 검증 통과 여부
+    pub valid: bool,
+    /// 검증한 회로 식별자
+    pub circuit_id: String,
+    /// 검증에 사용한 verifying key 해시
+    pub vk_hash: String,
+    /// 증명 시스템 식별자
+    pub proving_system: String,
+}
+
+/// 청산 경고 등록 응답
 #[derive(Debug, Serialize)]
+pub struct HealthFactorBucketResponse {
+    /// 포지션 주소
+    pub address: String,
+    /// 증명된 긴급도
+    pub urgency: LiquidationUrgency,
+    /// 증명된 HF 구간 하한 (basis points, 1.0 = 10000)
+    pub lo: u64,
+    /// 증명된 HF 구간 상한 (basis points, 미포함)
+    pub hi: u64,
+    /// 검증에 사용한 verifying key 해시
+    pub vk_hash: String,
+}
+
+/// Halo2 proof와 검증에 필요한 식별 정보
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProofData {
-    /// G1 point A
-    pub a: [String; 2],
-    /// G2 point B
-    pub b: [[String; 2]; 2],
-    /// G1 point C
-    pub c: [String; 2],
+    /// Halo2 proof transcript (0x 접두사 hex)
+    pub bytes: String,
+    /// 증명 시스템 식별자 (`ProvingBackend::proving_system`)
+    pub proving_system: String,
+    /// 증명을 생성한 회로 (예: "collateral", "multi_asset_ltv_2")
+    pub circuit_id: String,
+    /// 검증에 사용할 verifying key의 해시 (hex)
+    pub vk_hash: String,
 }
 
 // ============ Handlers ============
@@ -85,7 +408,7 @@ pub struct ProofData {
 /// 1. 입력 검증 (collateral >= threshold 확인)
 /// 2. Poseidon hash로 commitment 계산
 /// 3. Halo2 회로로 ZK proof 생성
-/// 4. Proof를 Groth16 형식으로 변환 (Solidity 호환)
+/// 4. Proof transcript를 hex로 인코딩 (회로 id, vk 해시 포함)
 /// 5. 응답 반환
 ///
 /// # Security Note
@@ -115,7 +438,7 @@ pub async fn generate_collateral_proof(
     let proof_result: ProofResult = state.zk_prover
         .generate_collateral_proof(collateral, threshold, salt)
         .await
-        .map_err(|e: anyhow::Error| ApiError::ProofGenerationFailed(e.to_string()))?;
+        .map_err(proof_error)?;
 
     let generation_time = start.elapsed().as_millis() as u64;
     tracing::info!("Collateral proof generated in {}ms", generation_time);
@@ -136,6 +459,9 @@ pub async fn generate_collateral_proof(
 /// POST /proof/ltv
 ///
 /// LTV 비율 증명 생성
+///
+/// 담보(wei)와 부채(USDC)를 오라클 가격과 각 토큰 decimals로 USD 가치로 환산해
+/// `debt_usd * 100 <= collateral_usd * max_ltv`를 증명합니다.
 pub async fn generate_ltv_proof(
     State(state): State<AppState>,
     Json(req): Json<LtvProofRequest>,
@@ -146,21 +472,60 @@ pub async fn generate_ltv_proof(
     let collateral = parse_u128(&req.collateral)?;
     let debt = parse_u128(&req.debt)?;
     let max_ltv = req.max_ltv;
+    let price = parse_u128(&req.price)?;
     let collateral_salt = parse_u128(&req.collateral_salt)?;
     let debt_salt = parse_u128(&req.debt_salt)?;
 
-    // LTV 검증: debt * 100 <= collateral * max_ltv
-    // 유한 필드에서 나눗셈을 피하기 위해 곱셈으로 변환
-    if debt * 100 > collateral * max_ltv as u128 {
+    // 회로의 public parameter 범위: 0 < max_ltv <= 100, 0 < price < 2^64,
+    // debt_decimals <= collateral_decimals <= 18
+    if max_ltv == 0 || max_ltv > 100 {
         return Err(ApiError::ValidationError(
-            format!("LTV exceeds maximum: {}%", max_ltv)
+            format!("max_ltv must be between 1 and 100, got {}", max_ltv)
         ));
     }
+    if price == 0 || price > u64::MAX as u128 {
+        return Err(ApiError::ValidationError(
+            format!("price must be between 1 and {}, got {}", u64::MAX, price)
+        ));
+    }
+    if req.collateral_decimals > 18 || req.debt_decimals > req.collateral_decimals {
+        return Err(ApiError::ValidationError(format!(
+            "Unsupported decimals: collateral {}, debt {} (debt <= collateral <= 18)",
+            req.collateral_decimals, req.debt_decimals
+        )));
+    }
+    let decimal_scale = 10u128.pow((req.collateral_decimals - req.debt_decimals) as u32);
+
+    // USD 기준 LTV 검증: debt * 100 * 1e8 * 10^(cd - dd) <= collateral * price * max_ltv
+    // 유한 필드에서 나눗셈을 피하기 위해 곱셈으로 변환
+    let debt_scaled = debt
+        .checked_mul(100 * 100_000_000)
+        .and_then(|v| v.checked_mul(decimal_scale));
+    let collateral_scaled = collateral
+        .checked_mul(price)
+        .and_then(|v| v.checked_mul(max_ltv as u128));
+    match (debt_scaled, collateral_scaled) {
+        (Some(d), Some(c)) if d <= c => {}
+        _ => {
+            return Err(ApiError::ValidationError(
+                format!("LTV exceeds maximum: {}%", max_ltv)
+            ));
+        }
+    }
 
     let proof_result: ProofResult = state.zk_prover
-        .generate_ltv_proof(collateral, debt, max_ltv, collateral_salt, debt_salt)
+        .generate_ltv_proof(
+            collateral,
+            debt,
+            max_ltv,
+            price,
+            req.collateral_decimals,
+            req.debt_decimals,
+            collateral_salt,
+            debt_salt,
+        )
         .await
-        .map_err(|e: anyhow::Error| ApiError::ProofGenerationFailed(e.to_string()))?;
+        .map_err(proof_error)?;
 
     let generation_time = start.elapsed().as_millis() as u64;
     tracing::info!("LTV proof generated in {}ms", generation_time);
@@ -173,6 +538,103 @@ pub async fn generate_ltv_proof(
     }))
 }
 
+/// POST /proof/ltv/multi-asset
+///
+/// 다중 담보 LTV 증명 생성
+///
+/// 수량을 각 토큰의 decimals로 18 decimals에 맞춘 뒤
+/// `sum(amount_i * price_i * cf_i) >= debt * 100 * 1e8`를 증명합니다.
+/// 응답의 `commitment`는 부채 commitment이고, `public_inputs`는
+/// 자산별 (commitment, price, cf, decimals) 다음에 부채 commitment, 부채 decimals 순서입니다.
+pub async fn generate_multi_asset_ltv_proof(
+    State(state): State<AppState>,
+    Json(req): Json<MultiAssetLtvProofRequest>,
+) -> Result<Json<ProofResponse>, ApiError> {
+    tracing::info!("Generating multi-asset LTV proof");
+    let start = std::time::Instant::now();
+
+    if req.assets.is_empty() || req.assets.len() > MAX_COLLATERAL_ASSETS {
+        return Err(ApiError::ValidationError(
+            format!("Between 1 and {} collateral assets are supported, got {}", MAX_COLLATERAL_ASSETS, req.assets.len())
+        ));
+    }
+
+    let assets = req.assets
+        .iter()
+        .map(|asset| -> Result<CollateralAsset, ApiError> {
+            Ok(CollateralAsset {
+                amount: parse_u128(&asset.amount)?,
+                price: parse_u128(&asset.price)?,
+                collateral_factor: asset.collateral_factor,
+                decimals: asset.decimals,
+                salt: parse_u128(&asset.salt)?,
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let debt = parse_u128(&req.debt)?;
+    let debt_salt = parse_u128(&req.debt_salt)?;
+
+    // 회로의 public parameter 범위: 0 < price < 2^64, 0 < cf <= 100, decimals <= 18
+    if req.debt_decimals > MAX_DECIMALS {
+        return Err(ApiError::ValidationError(
+            format!("debt_decimals must be at most {}, got {}", MAX_DECIMALS, req.debt_decimals)
+        ));
+    }
+    for asset in &assets {
+        if asset.price == 0 || asset.price > u64::MAX as u128 {
+            return Err(ApiError::ValidationError(
+                format!("price must be between 1 and {}, got {}", u64::MAX, asset.price)
+            ));
+        }
+        if asset.collateral_factor == 0 || asset.collateral_factor > 100 {
+            return Err(ApiError::ValidationError(
+                format!("collateral_factor must be between 1 and 100, got {}", asset.collateral_factor)
+            ));
+        }
+        if asset.decimals > MAX_DECIMALS {
+            return Err(ApiError::ValidationError(
+                format!("decimals must be at most {}, got {}", MAX_DECIMALS, asset.decimals)
+            ));
+        }
+    }
+
+    // 담보 검증: sum(amount * 10^(18 - decimals) * price * cf) >= debt * 10^(18 - debt_decimals) * 100 * 1e8
+    // 18 decimals로 맞춘 수량이 u128을 넘으면 회로에서도 증명할 수 없으므로 거부
+    let normalize = |amount: u128, decimals: u64| {
+        amount.checked_mul(10u128.pow((MAX_DECIMALS - decimals) as u32))
+    };
+    let weighted_collateral = assets.iter().try_fold(0u128, |acc, asset| {
+        normalize(asset.amount, asset.decimals)
+            .and_then(|v| v.checked_mul(asset.price))
+            .and_then(|v| v.checked_mul(asset.collateral_factor as u128))
+            .and_then(|v| acc.checked_add(v))
+    });
+    let debt_scaled = normalize(debt, req.debt_decimals).and_then(|v| v.checked_mul(100 * 100_000_000));
+    match (weighted_collateral, debt_scaled) {
+        (Some(c), Some(d)) if d <= c => {}
+        _ => {
+            return Err(ApiError::ValidationError(
+                "Weighted collateral does not cover the debt".to_string()
+            ));
+        }
+    }
+
+    let proof_result: ProofResult = state.zk_prover
+        .generate_multi_asset_ltv_proof(&assets, debt, debt_salt, req.debt_decimals)
+        .await
+        .map_err(proof_error)?;
+
+    let generation_time = start.elapsed().as_millis() as u64;
+    tracing::info!("Multi-asset LTV proof generated in {}ms", generation_time);
+
+    Ok(Json(ProofResponse {
+        proof: proof_result.proof,
+        public_inputs: proof_result.public_inputs,
+        commitment: proof_result.commitment,
+        generation_time_ms: generation_time,
+    }))
+}
+
 /// POST /proof/liquidation
 ///
 /// 청산 가능 증명 생성
@@ -187,12 +649,30 @@ pub async fn generate_liquidation_proof(
     let debt = parse_u128(&req.debt)?;
     let price = parse_u128(&req.price)?;
     let liquidation_threshold = req.liquidation_threshold;
-    let salt = parse_u128(&req.salt)?;
+    let collateral_salt = parse_u128(&req.collateral_salt)?;
+    let debt_salt = parse_u128(&req.debt_salt)?;
+
+    // 회로의 public parameter 범위: 0 < liquidation_threshold <= 100, 0 < price < 2^64
+    if liquidation_threshold == 0 || liquidation_threshold > 100 {
+        return Err(ApiError::ValidationError(
+            format!("liquidation_threshold must be between 1 and 100, got {}", liquidation_threshold)
+        ));
+    }
+    if price == 0 || price > u64::MAX as u128 {
+        return Err(ApiError::ValidationError(
+            format!("price must be between 1 and {}, got {}", u64::MAX, price)
+        ));
+    }
 
     // 청산 조건 검증: collateral * price * liq_threshold < debt * 100
     // health_factor < 1.0 이면 청산 가능
-    let collateral_value = collateral * price * liquidation_threshold as u128;
-    let debt_value = debt * 100 * 100_000_000; // price는 8 decimals
+    let collateral_value = collateral
+        .checked_mul(price)
+        .and_then(|v| v.checked_mul(liquidation_threshold as u128))
+        .ok_or_else(|| ApiError::BadRequest("collateral * price overflows u128".to_string()))?;
+    let debt_value = debt
+        .checked_mul(100 * 100_000_000) // price는 8 decimals
+        .ok_or_else(|| ApiError::BadRequest("debt overflows u128".to_string()))?;
 
     if collateral_value >= debt_value {
         return Err(ApiError::ValidationError(
@@ -201,9 +681,16 @@ pub async fn generate_liquidation_proof(
     }
 
     let proof_result: ProofResult = state.zk_prover
-        .generate_liquidation_proof(collateral, debt, price, liquidation_threshold, salt)
+        .generate_liquidation_proof(
+            collateral,
+            debt,
+            price,
+            liquidation_threshold,
+            collateral_salt,
+            debt_salt,
+        )
         .await
-        .map_err(|e: anyhow::Error| ApiError::ProofGenerationFailed(e.to_string()))?;
+        .map_err(proof_error)?;
 
     let generation_time = start.elapsed().as_millis() as u64;
     tracing::info!("Liquidation proof generated in {}ms", generation_time);
@@ -216,9 +703,955 @@ pub async fn generate_liquidation_proof(
     }))
 }
 
+/// POST /proof/liquidation/settlement
+///
+/// 청산 정산 증명 생성
+///
+/// 상환액이 close factor 이내이고, 압류 담보가 `ZKLendingPool.liquidate`와 같은
+/// 공식 (`repay * (100 + bonus) / 100 * 1e20 / price`)으로 계산되었음을 증명합니다.
+/// 응답의 `commitment`는 청산 후 새 담보 commitment이고,
+/// `public_inputs`의 다섯 번째 값이 압류 담보량입니다.
+pub async fn generate_liquidation_settlement_proof(
+    State(state): State<AppState>,
+    Json(req): Json<LiquidationSettlementProofRequest>,
+) -> Result<Json<ProofResponse>, ApiError> {
+    tracing::info!("Generating liquidation settlement proof");
+    let start = std::time::Instant::now();
+
+    let collateral = parse_u128(&req.collateral)?;
+    let debt = parse_u128(&req.debt)?;
+    let price = parse_u128(&req.price)?;
+    let repay = parse_u128(&req.repay)?;
+    let collateral_salt = parse_u128(&req.collateral_salt)?;
+    let debt_salt = parse_u128(&req.debt_salt)?;
+    let new_collateral_salt = parse_u128(&req.new_collateral_salt)?;
+    let new_debt_salt = parse_u128(&req.new_debt_salt)?;
+
+    // 회로의 public parameter 범위: 0 < price < 2^64, 0 < close_factor <= 100, bonus <= 100
+    if price == 0 || price > u64::MAX as u128 {
+        return Err(ApiError::ValidationError(
+            format!("price must be between 1 and {}, got {}", u64::MAX, price)
+        ));
+    }
+    if req.close_factor == 0 || req.close_factor > 100 {
+        return Err(ApiError::ValidationError(
+            format!("close_factor must be between 1 and 100, got {}", req.close_factor)
+        ));
+    }
+    if req.liquidation_bonus > 100 {
+        return Err(ApiError::ValidationError(
+            format!("liquidation_bonus must be at most 100, got {}", req.liquidation_bonus)
+        ));
+    }
+
+    // Close factor 검증: repay <= debt * close_factor / 100
+    let close_factor = req.close_factor as u128;
+    let max_repay = debt / 100 * close_factor + debt % 100 * close_factor / 100;
+    if repay > max_repay {
+        return Err(ApiError::ValidationError(
+            format!("repay exceeds close factor: max {}", max_repay)
+        ));
+    }
+
+    let proof_result: ProofResult = state.zk_prover
+        .generate_liquidation_settlement_proof(
+            collateral,
+            debt,
+            price as u64,
+            req.close_factor,
+            req.liquidation_bonus,
+            repay,
+            collateral_salt,
+            debt_salt,
+            new_collateral_salt,
+            new_debt_salt,
+        )
+        .await
+        .map_err(proof_error)?;
+
+    let generation_time = start.elapsed().as_millis() as u64;
+    tracing::info!("Liquidation settlement proof generated in {}ms", generation_time);
+
+    Ok(Json(ProofResponse {
+        proof: proof_result.proof,
+        public_inputs: proof_result.public_inputs,
+        commitment: proof_result.commitment,
+        generation_time_ms: generation_time,
+    }))
+}
+
+/// POST /proof/health-factor
+///
+/// Health factor 구간 증명 생성
+///
+/// 커밋된 포지션의 HF가 요청한 긴급도 구간 `[lo, hi)`에 있음을 증명합니다.
+/// 구간 경계는 `public_inputs`의 처음 두 값 (basis points, 1.0 = 10000)이며,
+/// 증명을 받는 쪽에는 정확한 HF가 공개되지 않습니다.
+///
+/// 이 엔드포인트는 서버가 금액을 받아 증명하므로 서버는 HF를 알게 됩니다.
+/// 서버에도 HF를 숨기려면 WASM `prove_health_factor_bucket`으로 브라우저에서
+/// 증명하고 POST /proof/health-factor/verify로 제출합니다.
+pub async fn generate_health_factor_proof(
+    State(state): State<AppState>,
+    Json(req): Json<HealthFactorProofRequest>,
+) -> Result<Json<ProofResponse>, ApiError> {
+    tracing::info!("Generating health factor proof");
+    let start = std::time::Instant::now();
+
+    let collateral = parse_u128(&req.collateral)?;
+    let debt = parse_u128(&req.debt)?;
+    let price = parse_u128(&req.price)?;
+    let liquidation_threshold = req.liquidation_threshold;
+    let collateral_salt = parse_u128(&req.collateral_salt)?;
+    let debt_salt = parse_u128(&req.debt_salt)?;
+    let (lo, hi) = req.urgency.health_factor_bucket();
+
+    // 회로의 public parameter 범위: 0 < liquidation_threshold <= 100, 0 < price < 2^64
+    if liquidation_threshold == 0 || liquidation_threshold > 100 {
+        return Err(ApiError::ValidationError(
+            format!("liquidation_threshold must be between 1 and 100, got {}", liquidation_threshold)
+        ));
+    }
+    if price == 0 || price > u64::MAX as u128 {
+        return Err(ApiError::ValidationError(
+            format!("price must be between 1 and {}, got {}", u64::MAX, price)
+        ));
+    }
+
+    // 구간 검증: lo <= HF < hi
+    // HF (basis points) = collateral * price * liq_threshold * 1e4 / (debt * 100 * 1e8)
+    let health_factor = if debt == 0 {
+        None
+    } else {
+        collateral
+            .checked_mul(price)
+            .and_then(|v| v.checked_mul(liquidation_threshold as u128))
+            .map(|collateral_value| collateral_value / 1_000_000 / debt)
+    };
+    match health_factor {
+        Some(hf) if lo as u128 <= hf && hf < hi as u128 => {}
+        _ => {
+            return Err(ApiError::ValidationError(
+                format!("Health factor is not in the {:?} bucket", req.urgency)
+            ));
+        }
+    }
+
+    let proof_result: ProofResult = state.zk_prover
+        .generate_health_factor_proof(
+            collateral,
+            debt,
+            price,
+            liquidation_threshold,
+            lo,
+            hi,
+            collateral_salt,
+            debt_salt,
+        )
+        .await
+        .map_err(proof_error)?;
+
+    let generation_time = start.elapsed().as_millis() as u64;
+    tracing::info!("Health factor proof generated in {}ms", generation_time);
+
+    Ok(Json(ProofResponse {
+        proof: proof_result.proof,
+        public_inputs: proof_result.public_inputs,
+        commitment: proof_result.commitment,
+        generation_time_ms: generation_time,
+    }))
+}
+
+/// POST /proof/health-factor/verify
+///
+/// 브라우저에서 생성한 Health factor 구간 증명을 검증하고 청산 경고 등록
+///
+/// 클라이언트가 WASM `prove_health_factor_bucket`으로 직접 증명하므로 서버는
+/// 정확한 HF를 보지 않고 긴급도 구간만 알게 됩니다.
+///
+/// # Checks
+///
+/// 1. 구간 `[lo, hi)`가 긴급도 구간 중 하나와 정확히 일치
+/// 2. 증명의 가격이 오라클 가격과 `ORACLE_PRICE_TOLERANCE_BPS` 이내이고,
+///    청산 임계값이 프로토콜 값(`LIQUIDATION_THRESHOLD`)과 같음
+///    (임의의 가격으로 만든 증명으로 긴급도를 조작하지 못하도록)
+/// 3. 증명의 commitment가 주소에 등록된 포지션의 commitment와 같음
+///    (다른 사용자의 포지션으로 경고를 등록하지 못하도록)
+/// 4. 서버의 verifying key로 proof 검증
+///
+/// 통과하면 `LiquidationWarning`을 해당 주소의 WebSocket 채널로 보냅니다.
+pub async fn verify_health_factor_proof(
+    State(state): State<AppState>,
+    Json(req): Json<HealthFactorBucketSubmission>,
+) -> Result<Json<HealthFactorBucketResponse>, ApiError> {
+    if !is_valid_ethereum_address(&req.address) {
+        return Err(ApiError::ValidationError("Invalid Ethereum address".to_string()));
+    }
+    let [lo, hi, price, liquidation_threshold, collateral_commitment, debt_commitment] =
+        req.public_inputs.as_slice()
+    else {
+        return Err(ApiError::ValidationError(
+            format!("Health factor proof has 6 public inputs, got {}", req.public_inputs.len())
+        ));
+    };
+
+    let proof_bytes = decode_proof_bytes(&req.proof)
+        .map_err(|e| ApiError::ValidationError(e.to_string()))?;
+    let lo = parse_u64_hex(lo).map_err(|e| ApiError::ValidationError(e.to_string()))?;
+    let hi = parse_u64_hex(hi).map_err(|e| ApiError::ValidationError(e.to_string()))?;
+
+    let urgency = LiquidationUrgency::from_health_factor_bucket(lo, hi).ok_or_else(|| {
+        ApiError::ValidationError(format!("[{}, {}) is not a liquidation urgency bucket", lo, hi))
+    })?;
+
+    let price = parse_u64_hex(price).map_err(|e| ApiError::ValidationError(e.to_string()))?;
+    let liquidation_threshold = parse_u64_hex(liquidation_threshold)
+        .map_err(|e| ApiError::ValidationError(e.to_string()))?;
+    let oracle_price = state.price_oracle.get_eth_price().await?;
+    check_health_factor_parameters(price, liquidation_threshold, &oracle_price, chrono::Utc::now())?;
+
+    let position = state.db
+        .get_position(&req.address)
+        .await?
+        .ok_or_else(|| ApiError::NotFound(format!("Position {}", req.address)))?;
+    let registered = |stored: &Option<String>, proven: &str| {
+        stored.as_deref().is_some_and(|stored| stored.eq_ignore_ascii_case(proven))
+    };
+    if !registered(&position.collateral_commitment, collateral_commitment)
+        || !registered(&position.debt_commitment, debt_commitment)
+    {
+        return Err(ApiError::InvalidCommitment(
+            "Proof commitments do not match the registered position".to_string()
+        ));
+    }
+
+    let verification = state.zk_prover
+        .verify(CircuitId::HealthFactor, &proof_bytes, &req.public_inputs)
+        .await?;
+    if !verification.valid {
+        return Err(ApiError::ValidationError("Invalid health factor proof".to_string()));
+    }
+
+    tracing::info!("Registered {:?} liquidation warning for {}", urgency, req.address);
+
+    // 정확한 HF 대신 증명된 구간의 하한을 보냄
+    let warning = LiquidationWarning {
+        address: req.address.to_lowercase(),
+        health_factor: lo as f64 / HF_PRECISION as f64,
+        threshold: 1.0,
+        message: format!(
+            "Health factor is below {:.2} ({:?})",
+            hi as f64 / HF_PRECISION as f64,
+            urgency
+        ),
+        urgency: urgency.clone(),
+        timestamp: chrono::Utc::now().timestamp() as u64,
+    };
+    state.ws_hub.send_liquidation_warning(&req.address, warning).await;
+
+    Ok(Json(HealthFactorBucketResponse {
+        address: req.address,
+        urgency,
+        lo,
+        hi,
+        vk_hash: verification.vk_hash,
+    }))
+}
+
+/// 증명에 사용된 가격과 청산 임계값이 프로토콜의 현재 값인지 확인
+///
+/// 가격은 증명 생성과 제출 사이의 오라클 변동을 허용하도록 오차 범위 안이면 통과하고,
+/// 오라클 데이터 자체가 `ORACLE_MAX_AGE_SECS`보다 오래되었으면 비교하지 않고 거부합니다.
+fn check_health_factor_parameters(
+    price: u64,
+    liquidation_threshold: u64,
+    oracle_price: &PriceData,
+    now: chrono::DateTime<chrono::Utc>,
+) -> Result<(), ApiError> {
+    if liquidation_threshold != LIQUIDATION_THRESHOLD {
+        return Err(ApiError::ValidationError(format!(
+            "liquidation_threshold must be the protocol's {}%, got {}",
+            LIQUIDATION_THRESHOLD, liquidation_threshold
+        )));
+    }
+    if (now - oracle_price.updated_at).num_seconds() > ORACLE_MAX_AGE_SECS {
+        return Err(ApiError::ServiceUnavailable(format!(
+            "Oracle price is older than {}s",
+            ORACLE_MAX_AGE_SECS
+        )));
+    }
+    // |price - oracle| * 10000 <= oracle * tolerance
+    let deviation = (price as u128).abs_diff(oracle_price.price);
+    let within_tolerance = deviation
+        .checked_mul(10_000)
+        .zip(oracle_price.price.checked_mul(ORACLE_PRICE_TOLERANCE_BPS))
+        .is_some_and(|(deviation, allowed)| deviation <= allowed);
+    if !within_tolerance {
+        return Err(ApiError::ValidationError(format!(
+            "Proof price {} deviates from the oracle price {} by more than {}bps",
+            price, oracle_price.price, ORACLE_PRICE_TOLERANCE_BPS
+        )));
+    }
+    Ok(())
+}
+
+/// POST /proof/withdraw
+///
+/// 담보 인출 증명 생성
+///
+/// 인출 후에도 `debt * 100 <= new_collateral * max_ltv`가 유지됨을 증명합니다.
+/// 기존 담보 commitment는 공개하지 않고 등록된 commitment 트리의 root
+/// (`public_inputs[0]`)에 포함됨만 증명합니다.
+/// 응답의 `commitment`는 인출 후의 새 담보 commitment이고,
+/// `public_inputs`의 마지막 값이 컨트랙트에 제출할 nullifier입니다.
+pub async fn generate_withdraw_proof(
+    State(state): State<AppState>,
+    Json(req): Json<WithdrawProofRequest>,
+) -> Result<Json<ProofResponse>, ApiError> {
+    tracing::info!("Generating withdraw proof");
+    let start = std::time::Instant::now();
+
+    let old_collateral = parse_u128(&req.old_collateral)?;
+    let withdraw_amount = parse_u128(&req.withdraw_amount)?;
+    let debt = parse_u128(&req.debt)?;
+    let max_ltv = req.max_ltv;
+    let old_salt = parse_u128(&req.old_salt)?;
+    let new_salt = parse_u128(&req.new_salt)?;
+    let debt_salt = parse_u128(&req.debt_salt)?;
+
+    if max_ltv == 0 || max_ltv > 100 {
+        return Err(ApiError::ValidationError(
+            format!("max_ltv must be between 1 and 100, got {}", max_ltv)
+        ));
+    }
+
+    // new_collateral = old_collateral - amount (>= 0)
+    let new_collateral = old_collateral.checked_sub(withdraw_amount).ok_or_else(|| {
+        ApiError::ValidationError("Withdraw amount exceeds collateral".to_string())
+    })?;
+
+    // 인출 후 LTV 검증: debt * 100 <= new_collateral * max_ltv
+    let debt_scaled = debt.checked_mul(100);
+    let collateral_scaled = new_collateral.checked_mul(max_ltv as u128);
+    match (debt_scaled, collateral_scaled) {
+        (Some(d), Some(c)) if d <= c => {}
+        _ => {
+            return Err(ApiError::ValidationError(
+                format!("LTV after withdrawal exceeds maximum: {}%", max_ltv)
+            ));
+        }
+    }
+
+    let registered = registered_commitments(&state).await?;
+
+    let proof_result: ProofResult = state.zk_prover
+        .generate_withdraw_proof(
+            old_collateral,
+            withdraw_amount,
+            debt,
+            max_ltv,
+            old_salt,
+            new_salt,
+            debt_salt,
+            &registered,
+        )
+        .await
+        .map_err(proof_error)?;
+
+    let generation_time = start.elapsed().as_millis() as u64;
+    tracing::info!("Withdraw proof generated in {}ms", generation_time);
+
+    Ok(Json(ProofResponse {
+        proof: proof_result.proof,
+        public_inputs: proof_result.public_inputs,
+        commitment: proof_result.commitment,
+        generation_time_ms: generation_time,
+    }))
+}
+
+/// POST /proof/transition
+///
+/// Commitment 갱신 증명 생성
+///
+/// 새 commitment가 기존 값 ± delta를 담고 있음을 증명합니다.
+/// 기존 commitment는 공개하지 않고 등록된 commitment 트리의 root
+/// (`public_inputs[0]`)에 포함됨만 증명합니다.
+/// 응답의 `commitment`는 갱신된 새 commitment이고,
+/// `public_inputs`의 마지막 값이 컨트랙트에 제출할 nullifier입니다.
+pub async fn generate_transition_proof(
+    State(state): State<AppState>,
+    Json(req): Json<TransitionProofRequest>,
+) -> Result<Json<ProofResponse>, ApiError> {
+    tracing::info!("Generating transition proof");
+    let start = std::time::Instant::now();
+
+    let old_value = parse_u128(&req.old_value)?;
+    let delta = parse_u128(&req.delta)?;
+    let old_salt = parse_u128(&req.old_salt)?;
+    let new_salt = parse_u128(&req.new_salt)?;
+
+    // 새 값도 u128 범위 안에 있어야 함 (회로의 128-bit range check)
+    let new_value = if req.increase {
+        old_value.checked_add(delta)
+    } else {
+        old_value.checked_sub(delta)
+    };
+    if new_value.is_none() {
+        return Err(ApiError::ValidationError(
+            "Transition would overflow or go below zero".to_string()
+        ));
+    }
+
+    let registered = registered_commitments(&state).await?;
+
+    let proof_result: ProofResult = state.zk_prover
+        .generate_transition_proof(old_value, delta, req.increase, old_salt, new_salt, &registered)
+        .await
+        .map_err(proof_error)?;
+
+    let generation_time = start.elapsed().as_millis() as u64;
+    tracing::info!("Transition proof generated in {}ms", generation_time);
+
+    Ok(Json(ProofResponse {
+        proof: proof_result.proof,
+        public_inputs: proof_result.public_inputs,
+        commitment: proof_result.commitment,
+        generation_time_ms: generation_time,
+    }))
+}
+
+/// POST /proof/interest
+///
+/// 이자 반영 부채 commitment 증명 생성
+///
+/// 새 부채 commitment가 원금 + 정산된 이자 + 경과 이자
+/// (`ZKLendingPool.getCurrentDebt`와 같은 공식, 내림)를 담고 있음을 증명합니다.
+/// 기존 부채 commitment는 공개하지 않고 등록된 commitment 트리의 root
+/// (`public_inputs[0]`)에 포함됨만 증명합니다.
+/// 응답의 `commitment`는 새 부채 commitment이고,
+/// `public_inputs`의 마지막 값이 컨트랙트에 제출할 nullifier입니다.
+pub async fn generate_interest_proof(
+    State(state): State<AppState>,
+    Json(req): Json<InterestProofRequest>,
+) -> Result<Json<ProofResponse>, ApiError> {
+    tracing::info!("Generating interest proof");
+    let start = std::time::Instant::now();
+
+    let principal = parse_u128(&req.principal)?;
+    let accrued_interest = parse_u128(&req.accrued_interest)?;
+    let old_salt = parse_u128(&req.old_salt)?;
+    let new_salt = parse_u128(&req.new_salt)?;
+
+    // 회로의 range check: rate < 2^32
+    if req.rate > u32::MAX as u64 {
+        return Err(ApiError::ValidationError(
+            "rate must fit in 32 bits".to_string()
+        ));
+    }
+
+    let registered = registered_commitments(&state).await?;
+
+    let proof_result: ProofResult = state.zk_prover
+        .generate_interest_proof(
+            principal,
+            req.rate,
+            req.elapsed,
+            accrued_interest,
+            old_salt,
+            new_salt,
+            &registered,
+        )
+        .await
+        .map_err(proof_error)?;
+
+    let generation_time = start.elapsed().as_millis() as u64;
+    tracing::info!("Interest proof generated in {}ms", generation_time);
+
+    Ok(Json(ProofResponse {
+        proof: proof_result.proof,
+        public_inputs: proof_result.public_inputs,
+        commitment: proof_result.commitment,
+        generation_time_ms: generation_time,
+    }))
+}
+
+/// POST /proof/solvency
+///
+/// 프로토콜 솔벤시 증명 생성 (운영자 인증 필요)
+///
+/// 포지션 commitment로 만든 Merkle 트리의 root 아래 모든 포지션에 대해
+/// `total_collateral * price >= total_debt * 1e20`을 증명합니다.
+/// 개별 포지션은 공개되지 않습니다.
+///
+/// 요청의 opening은 DB에 등록된 포지션 집합과 정확히 같아야 하며(누락, 중복,
+/// commitment 불일치는 거부), 주소 순으로 트리에 넣어 root가 등록된 집합에서
+/// 결정되도록 합니다. 오라클 가격으로 만든 증명만 최신 솔벤시 증명으로 보관됩니다.
+pub async fn generate_solvency_proof(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(req): Json<SolvencyProofRequest>,
+) -> Result<Json<SolvencyProofResponse>, ApiError> {
+    require_operator(&state, &headers)?;
+
+    prove_solvency(state, req).await.map(Json)
+}
+
+/// 솔벤시 증명 생성 (인증은 호출자가 확인)
+async fn prove_solvency(
+    state: AppState,
+    req: SolvencyProofRequest,
+) -> Result<SolvencyProofResponse, ApiError> {
+    tracing::info!("Generating solvency proof");
+    let start = std::time::Instant::now();
+
+    let capacity = 1usize << SOLVENCY_TREE_DEPTH;
+    if req.positions.is_empty() || req.positions.len() > capacity {
+        return Err(ApiError::ValidationError(
+            format!("Between 1 and {} positions are supported, got {}", capacity, req.positions.len())
+        ));
+    }
+
+    let positions = registered_positions(&state, &req.positions).await?;
+
+    let price = match &req.price {
+        Some(price) => parse_u128(price)?,
+        None => state.price_oracle.get_eth_price().await
+            .map_err(|_: anyhow::Error| ApiError::ServiceUnavailable("Price Oracle".to_string()))?
+            .price,
+    };
+
+    // 회로의 public parameter 범위: 0 < price < 2^64
+    if price == 0 || price > u64::MAX as u128 {
+        return Err(ApiError::ValidationError(
+            format!("price must be between 1 and {}, got {}", u64::MAX, price)
+        ));
+    }
+
+    // 솔벤시 검증: total_collateral * price >= total_debt * 1e20
+    // u128을 넘는 합계와 담보 가치는 지원하지 않음
+    let total_collateral = positions.iter().try_fold(0u128, |acc, p| acc.checked_add(p.collateral));
+    let total_debt = positions.iter().try_fold(0u128, |acc, p| acc.checked_add(p.debt));
+    let (total_collateral, total_debt) = match (total_collateral, total_debt) {
+        (Some(c), Some(d)) => (c, d),
+        _ => {
+            return Err(ApiError::ValidationError(
+                "Total collateral or debt exceeds u128".to_string()
+            ));
+        }
+    };
+    let collateral_value = total_collateral.checked_mul(price);
+    let debt_scaled = total_debt.checked_mul(100_000_000_000_000_000_000);
+    match (collateral_value, debt_scaled) {
+        (Some(c), Some(d)) if d <= c => {}
+        _ => {
+            return Err(ApiError::ValidationError(
+                "Total collateral value does not cover the total debt".to_string()
+            ));
+        }
+    }
+
+    let proof_result: ProofResult = state.zk_prover
+        .generate_solvency_proof(&positions, price)
+        .await
+        .map_err(proof_error)?;
+
+    let generation_time = start.elapsed().as_millis() as u64;
+    tracing::info!("Solvency proof generated in {}ms", generation_time);
+
+    let response = SolvencyProofResponse {
+        proof: proof_result.proof,
+        public_inputs: proof_result.public_inputs,
+        root: proof_result.commitment,
+        price: price.to_string(),
+        total_collateral: total_collateral.to_string(),
+        total_debt: total_debt.to_string(),
+        position_count: positions.len(),
+        generated_at: chrono::Utc::now().to_rfc3339(),
+        generation_time_ms: generation_time,
+    };
+
+    // 운영자가 지정한 가격의 증명은 공개 최신 증명을 대체하지 않음
+    if req.price.is_none() {
+        *state.latest_solvency_proof.write().await = Some(response.clone());
+    }
+
+    Ok(response)
+}
+
+/// 등록된 commitment 집합 (commitment 트리의 leaf 순서)
+///
+/// 주소 순 포지션마다 담보 commitment, 부채 commitment(있으면) 순서입니다.
+/// 기존 commitment를 사용하는 증명은 이 트리의 root를 public input으로 가집니다.
+async fn registered_commitments(state: &AppState) -> Result<Vec<String>, ApiError> {
+    let positions = state.db.get_committed_positions().await?;
+
+    Ok(positions
+        .into_iter()
+        .flat_map(|position| [position.collateral_commitment, position.debt_commitment])
+        .flatten()
+        .collect())
+}
+
+/// 요청의 opening을 DB에 등록된 포지션과 대조해 주소 순으로 반환
+///
+/// 등록된 모든 포지션이 정확히 한 번씩 포함되고, 각 opening으로 계산한
+/// commitment가 등록된 commitment와 같아야 합니다. 부채 commitment가 없는
+/// 포지션은 부채가 0이어야 합니다.
+async fn registered_positions(
+    state: &AppState,
+    openings: &[SolvencyPositionRequest],
+) -> Result<Vec<CommittedPosition>, ApiError> {
+    let registered = state.db.get_committed_positions().await?;
+    if openings.len() != registered.len() {
+        return Err(ApiError::ValidationError(
+            format!("Expected openings for all {} registered positions, got {}", registered.len(), openings.len())
+        ));
+    }
+
+    let mut by_address = HashMap::with_capacity(openings.len());
+    for opening in openings {
+        if by_address.insert(opening.address.to_lowercase(), opening).is_some() {
+            return Err(ApiError::ValidationError(
+                format!("Duplicate position {}", opening.address)
+            ));
+        }
+    }
+
+    let commitment_hex = |value: u128, salt: u128| -> Result<String, ApiError> {
+        let bytes = state.zk_prover.compute_commitment(value, salt)?;
+        Ok(format!("0x{}", hex::encode(bytes)))
+    };
+
+    registered
+        .iter()
+        .map(|position| -> Result<CommittedPosition, ApiError> {
+            let opening = by_address.get(&position.address).ok_or_else(|| {
+                ApiError::ValidationError(format!("Missing opening for position {}", position.address))
+            })?;
+            let committed = CommittedPosition {
+                collateral: parse_u128(&opening.collateral)?,
+                debt: parse_u128(&opening.debt)?,
+                collateral_salt: parse_u128(&opening.collateral_salt)?,
+                debt_salt: parse_u128(&opening.debt_salt)?,
+            };
+
+            let collateral_commitment = commitment_hex(committed.collateral, committed.collateral_salt)?;
+            let collateral_matches = position.collateral_commitment
+                .as_deref()
+                .is_some_and(|stored| stored.eq_ignore_ascii_case(&collateral_commitment));
+            let debt_matches = match position.debt_commitment.as_deref() {
+                Some(stored) => stored.eq_ignore_ascii_case(&commitment_hex(committed.debt, committed.debt_salt)?),
+                None => committed.debt == 0,
+            };
+            if !collateral_matches || !debt_matches {
+                return Err(ApiError::InvalidCommitment(
+                    format!("Opening does not match the registered commitments of {}", position.address)
+                ));
+            }
+
+            Ok(committed)
+        })
+        .collect()
+}
+
+/// GET /proof/solvency/latest
+///
+/// 가장 최근에 생성된 솔벤시 증명 조회
+pub async fn get_latest_solvency_proof(
+    State(state): State<AppState>,
+) -> Result<Json<SolvencyProofResponse>, ApiError> {
+    state.latest_solvency_proof.read().await
+        .clone()
+        .map(Json)
+        .ok_or_else(|| ApiError::NotFound("No solvency proof has been generated yet".to_string()))
+}
+
+/// GET /proof/keys
+///
+/// 서버가 사용 중인 verifying key 해시 목록
+///
+/// 온체인 verifier registry의 vk와 대조해 동기화 상태를 확인하는 데 사용합니다.
+pub async fn get_verifying_keys(
+    State(state): State<AppState>,
+) -> Json<VerifyingKeysResponse> {
+    let keys = state.zk_prover
+        .verifying_key_hashes()
+        .await
+        .into_iter()
+        .map(|(circuit_id, vk_hash)| VerifyingKeyInfo { circuit_id, vk_hash })
+        .collect();
+
+    Json(VerifyingKeysResponse {
+        proving_system: state.zk_prover.proving_system().to_string(),
+        keys,
+    })
+}
+
+/// GET /proof/params
+///
+/// 서버 SRS (`Params::write` 형식)
+///
+/// 브라우저에서 증명하는 클라이언트(WASM `prove_health_factor_bucket`)가 서버와
+/// 같은 SRS로 proving key를 만들도록 제공합니다. 증명할 backend는
+/// `x-proving-backend` 헤더(`PROVING_BACKEND`와 같은 이름)로 알려줍니다.
+pub async fn get_params(
+    State(state): State<AppState>,
+) -> Result<impl IntoResponse, ApiError> {
+    let bytes = state.zk_prover.params_bytes().await?;
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, "application/octet-stream".to_string()),
+            (header::HeaderName::from_static("x-proving-backend"), state.zk_prover.backend().to_string()),
+        ],
+        bytes,
+    ))
+}
+
+/// POST /proof/verify
+///
+/// 서버가 캐시한 verifying key로 proof 검증
+///
+/// 백엔드나 제3자가 proof를 제출 전에 확인하는 데 사용합니다. 모든 회로 id를
+/// 지원하며, 형식이 잘못된 요청은 400, 검증 실패는 `valid: false`로 응답합니다.
+pub async fn verify_proof(
+    State(state): State<AppState>,
+    Json(req): Json<VerifyProofRequest>,
+) -> Result<Json<VerifyProofResponse>, ApiError> {
+    let circuit_id: CircuitId = req.circuit_id
+        .parse()
+        .map_err(|e: anyhow::Error| ApiError::ValidationError(e.to_string()))?;
+
+    let proof_bytes = decode_proof_bytes(&req.proof)
+        .map_err(|e| ApiError::ValidationError(e.to_string()))?;
+
+    tracing::info!("Verifying {} proof ({} bytes)", circuit_id, proof_bytes.len());
+
+    let verification = state.zk_prover
+        .verify(circuit_id, &proof_bytes, &req.public_inputs)
+        .await?;
+
+    tracing::info!("{} proof verification result: {}", circuit_id, verification.valid);
+
+    Ok(Json(VerifyProofResponse {
+        valid: verification.valid,
+        circuit_id: circuit_id.to_string(),
+        vk_hash: verification.vk_hash,
+        proving_system: state.zk_prover.proving_system().to_string(),
+    }))
+}
+
+/// POST /proof/jobs
+///
+/// 증명 작업 제출
+///
+/// 증명을 기다리지 않고 바로 job id를 반환합니다 (202 Accepted). 결과는
+/// GET /proof/jobs/:id로 조회하거나 WebSocket `ProofJobStatus` 메시지로 받습니다.
+/// 입력 검증 실패도 작업 실패(`failed`)로 보고됩니다.
+/// 실행을 기다리는 작업이 `PROOF_JOB_QUEUE_DEPTH`개면 503을 반환합니다.
+/// 솔벤시 작업은 POST /proof/solvency와 같이 운영자 인증이 필요합니다.
+pub async fn submit_proof_job(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(req): Json<ProofJobRequest>,
+) -> Result<(StatusCode, Json<ProofJob>), ApiError> {
+    if matches!(req, ProofJobRequest::Solvency(_)) {
+        require_operator(&state, &headers)?;
+    }
+
+    let job = state.proof_jobs.submit(req).await?;
+
+    Ok((StatusCode::ACCEPTED, Json(job)))
+}
+
+/// GET /proof/jobs/:id
+///
+/// 증명 작업 상태 조회 (pending | running | done | failed, done이면 증명 포함)
+pub async fn get_proof_job(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<Json<ProofJob>, ApiError> {
+    let job_id = uuid::Uuid::parse_str(&id)
+        .map_err(|_| ApiError::ValidationError(format!("Invalid job id: {}", id)))?;
+
+    state.proof_jobs
+        .get(job_id)
+        .await?
+        .map(Json)
+        .ok_or_else(|| ApiError::NotFound(format!("Proof job {}", id)))
+}
+
+/// 작업 큐 worker에서 증명 요청 실행
+///
+/// 동기 엔드포인트 핸들러를 그대로 호출하므로 검증과 응답 형식이 같습니다.
+/// 503(증명 실행기 포화 등)은 작업 큐가 backoff 후 재시도합니다.
+pub async fn run_proof_job(
+    state: AppState,
+    request: ProofJobRequest,
+) -> Result<serde_json::Value, ProofJobError> {
+    let response = match request {
+        ProofJobRequest::Collateral(req) => generate_collateral_proof(State(state), Json(req)).await.map(to_json),
+        ProofJobRequest::Ltv(req) => generate_ltv_proof(State(state), Json(req)).await.map(to_json),
+        ProofJobRequest::MultiAssetLtv(req) => generate_multi_asset_ltv_proof(State(state), Json(req)).await.map(to_json),
+        ProofJobRequest::Liquidation(req) => generate_liquidation_proof(State(state), Json(req)).await.map(to_json),
+        ProofJobRequest::LiquidationSettlement(req) => {
+            generate_liquidation_settlement_proof(State(state), Json(req)).await.map(to_json)
+        }
+        ProofJobRequest::HealthFactor(req) => generate_health_factor_proof(State(state), Json(req)).await.map(to_json),
+        ProofJobRequest::Withdraw(req) => generate_withdraw_proof(State(state), Json(req)).await.map(to_json),
+        ProofJobRequest::Transition(req) => generate_transition_proof(State(state), Json(req)).await.map(to_json),
+        ProofJobRequest::Interest(req) => generate_interest_proof(State(state), Json(req)).await.map(to_json),
+        // 제출 시 운영자 인증을 확인했으므로 인증 없이 실행
+        ProofJobRequest::Solvency(req) => prove_solvency(state, req).await.map(Json).map(to_json),
+    };
+
+    match response {
+        Ok(json) => json.map_err(|e| ProofJobError::Failed(e.to_string())),
+        Err(e @ ApiError::ServiceUnavailable(_)) => Err(ProofJobError::Unavailable(e.to_string())),
+        Err(e) => Err(ProofJobError::Failed(e.to_string())),
+    }
+}
+
 // ============ Helpers ============
 
+/// 운영자 인증 (`Authorization: Bearer <OPERATOR_API_KEY>`)
+///
+/// `OPERATOR_API_KEY`가 설정되지 않았으면 항상 거부합니다.
+fn require_operator(state: &AppState, headers: &HeaderMap) -> Result<(), ApiError> {
+    let expected = state.config.operator_api_key.as_deref().ok_or(ApiError::Unauthorized)?;
+    let provided = headers
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .ok_or(ApiError::Unauthorized)?;
+
+    if !constant_time_eq(provided.as_bytes(), expected.as_bytes()) {
+        return Err(ApiError::Unauthorized);
+    }
+    Ok(())
+}
+
+/// 키 비교 시간으로 일치하는 접두사 길이가 드러나지 않도록 전체를 비교
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+/// Prover 에러 변환 (실행기 대기열이 가득 차면 503, 등록되지 않은 commitment는
+/// `InvalidCommitment`, 나머지는 증명 실패)
+fn proof_error(e: anyhow::Error) -> ApiError {
+    if e.is::<ProverBusy>() || e.is::<UnregisteredCommitment>() {
+        return ApiError::from(e);
+    }
+    ApiError::ProofGenerationFailed(e.to_string())
+}
+
+fn to_json<T: Serialize>(Json(response): Json<T>) -> serde_json::Result<serde_json::Value> {
+    serde_json::to_value(response)
+}
+
+fn default_collateral_decimals() -> u64 {
+    18
+}
+
+fn default_debt_decimals() -> u64 {
+    6
+}
+
+fn default_accrued_interest() -> String {
+    "0".to_string()
+}
+
+fn default_close_factor() -> u64 {
+    100
+}
+
+fn default_liquidation_bonus() -> u64 {
+    5
+}
+
 fn parse_u128(s: &str) -> Result<u128, ApiError> {
     s.parse::<u128>()
         .map_err(|_| ApiError::ValidationError(format!("Invalid number: {}", s)))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::{PriceOracle, ZKProver};
+
+    /// 오라클 가격과 다른 가격으로 만든 증명은 그 자체로 유효해도 거부
+    #[tokio::test]
+    async fn test_health_factor_proof_with_wrong_price_rejected() {
+        let oracle_price = PriceOracle::new("http://mock").get_eth_price().await.unwrap();
+        let prover = ZKProver::new().unwrap();
+        let (lo, hi) = LiquidationUrgency::High.health_factor_bucket();
+
+        // 오라클 가격의 절반으로 HF를 낮춰 High 구간을 증명
+        let wrong_price = oracle_price.price / 2;
+        let result = prover
+            .generate_health_factor_proof(
+                100,
+                84_210,
+                wrong_price,
+                LIQUIDATION_THRESHOLD,
+                lo,
+                hi,
+                11,
+                22,
+            )
+            .await
+            .unwrap();
+        let proof_bytes = decode_proof_bytes(&result.proof.bytes).unwrap();
+        let verification = prover
+            .verify(CircuitId::HealthFactor, &proof_bytes, &result.public_inputs)
+            .await
+            .unwrap();
+        assert!(verification.valid);
+
+        let price = parse_u64_hex(&result.public_inputs[2]).unwrap();
+        let liquidation_threshold = parse_u64_hex(&result.public_inputs[3]).unwrap();
+        assert_eq!(price as u128, wrong_price);
+        let err = check_health_factor_parameters(
+            price,
+            liquidation_threshold,
+            &oracle_price,
+            chrono::Utc::now(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ApiError::ValidationError(_)));
+    }
+
+    #[test]
+    fn test_check_health_factor_parameters() {
+        let now = chrono::Utc::now();
+        let oracle_price = PriceData {
+            price: 2000_00000000,
+            source: "mock".to_string(),
+            updated_at: now,
+            change_24h: None,
+        };
+        let check = |price: u64, threshold: u64| {
+            check_health_factor_parameters(price, threshold, &oracle_price, now)
+        };
+
+        assert!(check(2000_00000000, LIQUIDATION_THRESHOLD).is_ok());
+        // 1% 이내의 변동은 허용
+        assert!(check(2020_00000000, LIQUIDATION_THRESHOLD).is_ok());
+        assert!(check(1980_00000000, LIQUIDATION_THRESHOLD).is_ok());
+        assert!(check(2020_00000001, LIQUIDATION_THRESHOLD).is_err());
+        assert!(check(1000_00000000, LIQUIDATION_THRESHOLD).is_err());
+        // 프로토콜과 다른 청산 임계값
+        assert!(check(2000_00000000, 100).is_err());
+
+        // 오래된 오라클 가격으로는 확인하지 않음
+        let stale = now + chrono::Duration::seconds(ORACLE_MAX_AGE_SECS + 1);
+        let err = check_health_factor_parameters(
+            2000_00000000,
+            LIQUIDATION_THRESHOLD,
+            &oracle_price,
+            stale,
+        )
+        .unwrap_err();
+        assert!(matches!(err, ApiError::ServiceUnavailable(_)));
+    }
+}
@@ -4,6 +4,10 @@
 //!
 //! # Endpoints
 //! - `GET /ws` - WebSocket 연결
+//!
+//! # Channels
+//! - Pool 상태, 가격: 연결하면 자동 구독
+//! - `proof_job:<job_id>`: 증명 작업 상태 (POST /proof/jobs가 반환한 job id로 구독)
 
 use axum::{
     extract::{
@@ -14,7 +18,7 @@ use axum::{
 };
 use futures_util::{SinkExt, StreamExt};
 use std::sync::Arc;
-use tokio::sync::broadcast;
+use tokio::sync::{broadcast, mpsc};
 
 use crate::services::{WsHub, WsMessage};
 
@@ -34,6 +38,9 @@ async fn handle_socket(socket: WebSocket, hub: Arc<WsHub>) {
     let mut pool_rx = hub.subscribe_pool_status();
     let mut price_rx = hub.subscribe_prices();
 
+    // 구독한 증명 작업 채널에서 전달받는 메시지
+    let (job_tx, mut job_rx) = mpsc::channel::<WsMessage>(100);
+
     // 연결 ID 생성
     let conn_id = uuid::Uuid::new_v4().to_string();
 
@@ -63,7 +70,7 @@ async fn handle_socket(socket: WebSocket, hub: Arc<WsHub>) {
             match msg {
                 Message::Text(text) => {
                     if let Ok(client_msg) = serde_json::from_str::<ClientMessage>(&text) {
-                        handle_client_message(&hub_clone, &conn_id_clone, client_msg).await;
+                        handle_client_message(&hub_clone, &conn_id_clone, client_msg, &job_tx).await;
                     }
                 }
                 Message::Close(_) => break,
@@ -93,6 +100,15 @@ async fn handle_socket(socket: WebSocket, hub: Arc<WsHub>) {
                         }
                     }
                 }
+
+                // 구독한 증명 작업 상태
+                Some(msg) = job_rx.recv() => {
+                    if let Ok(json) = serde_json::to_string(&msg) {
+                        if sender.send(Message::Text(json)).await.is_err() {
+                            break;
+                        }
+                    }
+                }
             }
         }
     });
@@ -105,6 +121,7 @@ async fn handle_socket(socket: WebSocket, hub: Arc<WsHub>) {
 
     // 연결 해제
     hub.unregister_connection(&conn_id).await;
+    hub.prune_proof_job_channels().await;
 }
 
 /// 클라이언트 메시지 타입
@@ -117,11 +134,33 @@ enum ClientMessage {
 }
 
 /// 클라이언트 메시지 처리
-async fn handle_client_message(hub: &WsHub, conn_id: &str, msg: ClientMessage) {
+async fn handle_client_message(
+    hub: &WsHub,
+    conn_id: &str,
+    msg: ClientMessage,
+    job_tx: &mpsc::Sender<WsMessage>,
+) {
     match msg {
         ClientMessage::Subscribe { channel } => {
             tracing::info!("Connection {} subscribed to {}", conn_id, channel);
-            // 구독 처리
+            // 증명 작업 채널은 유효한 job id만 구독 (작업이 끝나면 채널이 닫힘)
+            if let Some(job_id) = parse_proof_job_channel(&channel) {
+                let mut rx = hub.subscribe_proof_job(&job_id).await;
+                let job_tx = job_tx.clone();
+                tokio::spawn(async move {
+                    loop {
+                        match rx.recv().await {
+                            Ok(msg) => {
+                                if job_tx.send(msg).await.is_err() {
+                                    break;
+                                }
+                            }
+                            Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                            Err(broadcast::error::RecvError::Closed) => break,
+                        }
+                    }
+                });
+            }
         }
         ClientMessage::Unsubscribe { channel } => {
             tracing::info!("Connection {} unsubscribed from {}", conn_id, channel);
@@ -133,6 +172,12 @@ async fn handle_client_message(hub: &WsHub, conn_id: &str, msg: ClientMessage) {
     }
 }
 
+/// `proof_job:<job_id>` 채널의 job id (UUID가 아니면 None)
+fn parse_proof_job_channel(channel: &str) -> Option<String> {
+    let job_id = channel.strip_prefix("proof_job:")?;
+    uuid::Uuid::parse_str(job_id).ok().map(|id| id.to_string())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -148,4 +193,12 @@ mod tests {
             panic!("Expected Subscribe");
         }
     }
+
+    #[test]
+    fn test_parse_proof_job_channel() {
+        let job_id = "6f1c2b0e-8d4a-4c55-9a47-3f1e2d5c7b90";
+        assert_eq!(parse_proof_job_channel(&format!("proof_job:{}", job_id)).as_deref(), Some(job_id));
+        assert_eq!(parse_proof_job_channel("proof_job:not-a-uuid"), None);
+        assert_eq!(parse_proof_job_channel("proof_jobs"), None);
+    }
 }
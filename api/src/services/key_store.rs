@@ -0,0 +1,375 @@
+//! Key Store - SRS와 proving/verifying key 디스크 저장소
+//!
+//! 부팅할 때마다 SRS 생성과 keygen을 다시 돌리지 않도록
+//! SRS와 회로별 키를 디렉터리에 저장하고 다음 실행에서 다시 읽음
+//!
+//! # Layout
+//!
+//! ```text
+//! <dir>/                        # 증명 backend마다 별도 디렉터리 (ipa/, kzg-shplonk/, ...)
+//! ├── manifest.json             # 파일별 checksum, 회로 digest, vk hash
+//! ├── params_k17.bin            # SRS (ParamsIPA / ParamsKZG)
+//! ├── collateral.vk.bin         # 회로별 VerifyingKey
+//! ├── collateral.pk.bin         # 회로별 ProvingKey
+//! └── ...
+//! ```
+//!
+//! manifest에 없는 `params_k{k}.bin`은 직접 넣은 SRS로 보고 그대로 등록해 사용함
+//! (KZG에서는 ceremony SRS를 이렇게 넣어야 온체인 verifier와 같은 SRS를 씀)
+//!
+//! # Invalidation
+//!
+//! - checksum (Keccak256) 불일치: 파일 손상으로 보고 다시 생성
+//! - 회로 digest 불일치: 회로의 constraint system(gate, column, lookup)이나 k가
+//!   바뀐 것이므로 키를 다시 생성해 덮어씀
+//!
+//! 회로 digest는 `configure` 결과만 반영하므로, fixed column에 쓰는 값만
+//! 바뀐 경우에는 디렉터리의 해당 키 파일을 지워야 함
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use sha3::{Digest, Keccak256};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use halo2_proofs::{
+    arithmetic::CurveAffine,
+    plonk::{keygen_pk, keygen_vk, Circuit, ConstraintSystem, ProvingKey, VerifyingKey},
+    poly::commitment::Params,
+    SerdeFormat,
+};
+use ff::PrimeField;
+
+use super::proving_backend::ProofSystem;
+use super::zk_prover::CircuitId;
+
+const MANIFEST_FILE: &str = "manifest.json";
+
+/// 저장된 파일 하나와 그 checksum
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct StoredFile {
+    file: String,
+    checksum: String,
+}
+
+/// 회로 하나의 저장된 키
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StoredKeys {
+    /// 키를 생성할 때의 회로 digest (k, constraint system)
+    pub circuit_digest: String,
+    /// `vk.transcript_repr()` (hex), 온체인 verifier registry와 대조용
+    pub vk_hash: String,
+    vk: StoredFile,
+    pk: StoredFile,
+}
+
+/// `manifest.json`
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct Manifest {
+    params: Option<StoredFile>,
+    circuits: HashMap<String, StoredKeys>,
+}
+
+/// SRS와 회로별 키를 디스크에 저장하는 저장소
+pub struct KeyStore {
+    dir: PathBuf,
+    manifest: Mutex<Manifest>,
+}
+
+impl KeyStore {
+    /// 디렉터리를 열고 manifest를 읽음 (없으면 디렉터리 생성)
+    pub fn open(dir: impl AsRef<Path>) -> Result<Self> {
+        let dir = dir.as_ref().to_path_buf();
+        fs::create_dir_all(&dir)
+            .with_context(|| format!("Failed to create key store directory {}", dir.display()))?;
+
+        let manifest_path = dir.join(MANIFEST_FILE);
+        let manifest = if manifest_path.exists() {
+            let bytes = fs::read(&manifest_path).context("Failed to read key store manifest")?;
+            serde_json::from_slice(&bytes).unwrap_or_else(|e| {
+                tracing::warn!("Ignoring unreadable key store manifest: {}", e);
+                Manifest::default()
+            })
+        } else {
+            Manifest::default()
+        };
+
+        Ok(Self {
+            dir,
+            manifest: Mutex::new(manifest),
+        })
+    }
+
+    /// 저장된 SRS를 읽거나, 없거나 손상된 경우 생성해서 저장
+    pub fn load_or_create_params<B: ProofSystem>(&self, k: u32) -> Result<B::Params> {
+        let file = format!("params_k{}.bin", k);
+
+        let stored = self.manifest.lock().unwrap().params.clone().filter(|s| s.file == file);
+        let bytes = match &stored {
+            Some(stored) => self.read_checked(stored),
+            // manifest에 없는 파일은 직접 넣은 SRS
+            None => fs::read(self.dir.join(&file)).ok(),
+        };
+        if let Some(bytes) = bytes {
+            match B::Params::read(&mut bytes.as_slice()) {
+                Ok(params) if params.k() == k => {
+                    if stored.is_none() {
+                        let mut manifest = self.manifest.lock().unwrap();
+                        manifest.params = Some(StoredFile { file: file.clone(), checksum: checksum(&bytes) });
+                        self.save_manifest(&manifest)?;
+                    }
+                    tracing::info!("SRS parameters loaded from {}", self.dir.join(&file).display());
+                    return Ok(params);
+                }
+                _ => tracing::warn!("Stored SRS parameters are invalid, regenerating"),
+            }
+        }
+
+        let params = B::setup(k);
+        let mut bytes = Vec::new();
+        params.write(&mut bytes).context("Failed to serialize SRS parameters")?;
+        let stored = self.write_file(&file, &bytes)?;
+
+        let mut manifest = self.manifest.lock().unwrap();
+        manifest.params = Some(stored);
+        self.save_manifest(&manifest)?;
+
+        tracing::info!("SRS parameters generated and saved");
+        Ok(params)
+    }
+
+    /// 저장된 키를 읽거나, 회로 digest가 바뀌었거나 파일이 손상된 경우 다시 생성해서 저장
+    pub fn load_or_generate<B: ProofSystem, C: Circuit<B::Scalar>>(
+        &self,
+        params: &B::Params,
+        circuit_id: CircuitId,
+        empty_circuit: &C,
+    ) -> Result<(VerifyingKey<B::Curve>, ProvingKey<B::Curve>)> {
+        let id = circuit_id.to_string();
+        let digest = circuit_digest::<B::Scalar, C>(params.k());
+
+        let stored = self.manifest.lock().unwrap().circuits.get(&id).cloned();
+        match stored {
+            Some(stored) if stored.circuit_digest == digest => {
+                if let Some(keys) = self.read_keys::<B, C>(&stored) {
+                    tracing::info!("{} keys loaded from key store", id);
+                    return Ok(keys);
+                }
+                tracing::warn!("Stored {} keys are corrupted, regenerating", id);
+            }
+            Some(_) => tracing::info!("{} circuit changed, regenerating keys", id),
+            None => {}
+        }
+
+        let vk = keygen_vk(params, empty_circuit)
+            .with_context(|| format!("Failed to generate {} verification key", id))?;
+        let pk = keygen_pk(params, vk.clone(), empty_circuit)
+            .with_context(|| format!("Failed to generate {} proving key", id))?;
+
+        let mut vk_bytes = Vec::new();
+        vk.write(&mut vk_bytes, SerdeFormat::RawBytes)
+            .context("Failed to serialize verification key")?;
+        let mut pk_bytes = Vec::new();
+        pk.write(&mut pk_bytes, SerdeFormat::RawBytes)
+            .context("Failed to serialize proving key")?;
+
+        let stored = StoredKeys {
+            circuit_digest: digest,
+            vk_hash: vk_hash(&vk),
+            vk: self.write_file(&format!("{}.vk.bin", id), &vk_bytes)?,
+            pk: self.write_file(&format!("{}.pk.bin", id), &pk_bytes)?,
+        };
+
+        let mut manifest = self.manifest.lock().unwrap();
+        manifest.circuits.insert(id, stored);
+        self.save_manifest(&manifest)?;
+
+        Ok((vk, pk))
+    }
+
+    /// 저장된 회로별 키 정보 (circuit id 순)
+    pub fn stored_keys(&self) -> Vec<(String, StoredKeys)> {
+        let manifest = self.manifest.lock().unwrap();
+        let mut keys: Vec<_> = manifest
+            .circuits
+            .iter()
+            .map(|(id, keys)| (id.clone(), keys.clone()))
+            .collect();
+        keys.sort_by(|a, b| a.0.cmp(&b.0));
+        keys
+    }
+
+    fn read_keys<B: ProofSystem, C: Circuit<B::Scalar>>(
+        &self,
+        stored: &StoredKeys,
+    ) -> Option<(VerifyingKey<B::Curve>, ProvingKey<B::Curve>)> {
+        let vk_bytes = self.read_checked(&stored.vk)?;
+        let pk_bytes = self.read_checked(&stored.pk)?;
+
+        let vk = VerifyingKey::read::<_, C>(&mut vk_bytes.as_slice(), SerdeFormat::RawBytes).ok()?;
+        let pk = ProvingKey::read::<_, C>(&mut pk_bytes.as_slice(), SerdeFormat::RawBytes).ok()?;
+
+        // 저장된 vk와 pk가 같은 키인지 확인
+        (vk_hash(&vk) == stored.vk_hash && vk_hash(pk.get_vk()) == stored.vk_hash).then_some((vk, pk))
+    }
+
+    /// 파일을 읽고 checksum이 맞을 때만 반환
+    fn read_checked(&self, stored: &StoredFile) -> Option<Vec<u8>> {
+        let bytes = fs::read(self.dir.join(&stored.file)).ok()?;
+        (checksum(&bytes) == stored.checksum).then_some(bytes)
+    }
+
+    fn write_file(&self, file: &str, bytes: &[u8]) -> Result<StoredFile> {
+        let path = self.dir.join(file);
+        fs::write(&path, bytes).with_context(|| format!("Failed to write {}", path.display()))?;
+        Ok(StoredFile {
+            file: file.to_string(),
+            checksum: checksum(bytes),
+        })
+    }
+
+    fn save_manifest(&self, manifest: &Manifest) -> Result<()> {
+        let bytes = serde_json::to_vec_pretty(manifest).context("Failed to serialize key store manifest")?;
+        fs::write(self.dir.join(MANIFEST_FILE), bytes).context("Failed to write key store manifest")
+    }
+}
+
+/// Verifying key 해시 (`vk.transcript_repr()`, hex)
+pub fn vk_hash<C: CurveAffine>(vk: &VerifyingKey<C>) -> String {
+    format!("0x{}", hex::encode(vk.transcript_repr().to_repr()))
+}
+
+/// 회로 digest: k와 constraint system (gate, column, lookup, permutation)의 Keccak256
+pub fn circuit_digest<F: PrimeField, C: Circuit<F>>(k: u32) -> String {
+    let mut cs = ConstraintSystem::<F>::default();
+    C::configure(&mut cs);
+    checksum(format!("k={} {:?}", k, cs.pinned()).as_bytes())
+}
+
+fn checksum(bytes: &[u8]) -> String {
+    format!("0x{}", hex::encode(Keccak256::digest(bytes)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::proving_backend::{Ipa, KzgShplonk};
+    use pasta_curves::Fp;
+    use zk_private_lending_circuits::{CollateralCircuit, LTVCircuit};
+
+    const K: u32 = 17;
+
+    fn temp_dir() -> PathBuf {
+        std::env::temp_dir().join(format!("zk-key-store-{}", uuid::Uuid::new_v4()))
+    }
+
+    #[test]
+    fn test_circuit_digest() {
+        assert_eq!(
+            circuit_digest::<Fp, CollateralCircuit<Fp>>(K),
+            circuit_digest::<Fp, CollateralCircuit<Fp>>(K),
+        );
+        assert_ne!(
+            circuit_digest::<Fp, CollateralCircuit<Fp>>(K),
+            circuit_digest::<Fp, LTVCircuit<Fp>>(K),
+        );
+        assert_ne!(
+            circuit_digest::<Fp, CollateralCircuit<Fp>>(K),
+            circuit_digest::<Fp, CollateralCircuit<Fp>>(K + 1),
+        );
+    }
+
+    #[test]
+    fn test_keys_persist_across_restarts() {
+        let dir = temp_dir();
+        let empty_circuit = CollateralCircuit::<Fp>::default();
+
+        let store = KeyStore::open(&dir).unwrap();
+        let params = store.load_or_create_params::<Ipa>(K).unwrap();
+        let (vk, _) = store.load_or_generate::<Ipa, _>(&params, CircuitId::Collateral, &empty_circuit).unwrap();
+
+        // 새 프로세스처럼 다시 열어도 같은 키를 읽음
+        let store = KeyStore::open(&dir).unwrap();
+        let params = store.load_or_create_params::<Ipa>(K).unwrap();
+        let stored = store.stored_keys();
+        assert_eq!(stored.len(), 1);
+        assert_eq!(stored[0].0, "collateral");
+        assert_eq!(stored[0].1.vk_hash, vk_hash(&vk));
+
+        let (loaded_vk, loaded_pk) = store.load_or_generate::<Ipa, _>(&params, CircuitId::Collateral, &empty_circuit).unwrap();
+        assert_eq!(vk_hash(&loaded_vk), vk_hash(&vk));
+        assert_eq!(vk_hash(loaded_pk.get_vk()), vk_hash(&vk));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_corrupted_keys_are_regenerated() {
+        let dir = temp_dir();
+        let empty_circuit = CollateralCircuit::<Fp>::default();
+
+        let store = KeyStore::open(&dir).unwrap();
+        let params = store.load_or_create_params::<Ipa>(K).unwrap();
+        let (vk, _) = store.load_or_generate::<Ipa, _>(&params, CircuitId::Collateral, &empty_circuit).unwrap();
+        let checksum_before = store.manifest.lock().unwrap().circuits["collateral"].pk.checksum.clone();
+
+        // pk 파일 손상 → checksum 불일치로 다시 생성
+        fs::write(dir.join("collateral.pk.bin"), b"corrupted").unwrap();
+
+        let store = KeyStore::open(&dir).unwrap();
+        let (regenerated_vk, _) = store.load_or_generate::<Ipa, _>(&params, CircuitId::Collateral, &empty_circuit).unwrap();
+        assert_eq!(vk_hash(&regenerated_vk), vk_hash(&vk));
+        assert_eq!(
+            store.manifest.lock().unwrap().circuits["collateral"].pk.checksum,
+            checksum_before,
+        );
+        assert_eq!(
+            checksum(&fs::read(dir.join("collateral.pk.bin")).unwrap()),
+            checksum_before,
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_changed_circuit_is_regenerated() {
+        let dir = temp_dir();
+
+        let store = KeyStore::open(&dir).unwrap();
+        let params = store.load_or_create_params::<Ipa>(K).unwrap();
+        store.load_or_generate::<Ipa, _>(&params, CircuitId::Collateral, &CollateralCircuit::<Fp>::default()).unwrap();
+
+        // 같은 id에 다른 회로 → digest 불일치로 다시 생성
+        let (vk, _) = store.load_or_generate::<Ipa, _>(&params, CircuitId::Collateral, &LTVCircuit::<Fp>::default()).unwrap();
+        let stored = store.stored_keys();
+        assert_eq!(stored[0].1.circuit_digest, circuit_digest::<Fp, LTVCircuit<Fp>>(K));
+        assert_eq!(stored[0].1.vk_hash, vk_hash(&vk));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_provided_srs_is_used_as_is() {
+        let dir = temp_dir();
+        fs::create_dir_all(&dir).unwrap();
+
+        // ceremony SRS를 직접 넣은 경우 (KZG SRS는 생성할 때마다 다름)
+        let k = 8;
+        let mut srs = Vec::new();
+        KzgShplonk::setup(k).write(&mut srs).unwrap();
+        fs::write(dir.join(format!("params_k{}.bin", k)), &srs).unwrap();
+
+        let store = KeyStore::open(&dir).unwrap();
+        let mut loaded = Vec::new();
+        store.load_or_create_params::<KzgShplonk>(k).unwrap().write(&mut loaded).unwrap();
+        assert_eq!(loaded, srs);
+
+        // 등록된 뒤에는 checksum으로 검사
+        let registered = store.manifest.lock().unwrap().params.clone().unwrap();
+        assert_eq!(registered.checksum, checksum(&srs));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -4,16 +4,37 @@
 //!
 //! # Services
 //! - `ZKProver`: ZK 증명 생성 서비스
+//! - `ProvingBackend`: 증명 시스템 선택 (IPA/Pasta, KZG/BN254)
+//! - `KeyStore`: SRS와 proving/verifying key 디스크 저장소
+//! - `ProverExecutor`: 증명 생성/검증용 blocking 스레드 풀
+//! - `ProofJobQueue`: 비동기 증명 작업 큐
 //! - `PriceOracle`: 가격 정보 서비스
 //! - `BlockchainService`: 블록체인 상호작용
 //! - `WsHub`: WebSocket 실시간 데이터
 
 mod zk_prover;
+mod proving_backend;
+mod key_store;
+mod prover_executor;
+mod proof_jobs;
 mod price_oracle;
 mod blockchain;
 mod websocket;
 
-pub use zk_prover::{ZKProver, ProofResult};
+pub use zk_prover::{
+    ZKProver, ProofResult, VerificationResult, CollateralAsset, CommittedPosition, CircuitId,
+    InvalidPublicInput, UnregisteredCommitment, decode_proof_bytes, parse_fp_hex, parse_u64_hex,
+    MAX_COLLATERAL_ASSETS, SOLVENCY_TREE_DEPTH,
+};
+pub use proving_backend::{ProvingBackend, ProofSystem, Ipa, KzgShplonk, KzgGwc};
+pub use key_store::{KeyStore, StoredKeys};
+pub use prover_executor::{
+    ProverBusy, ProverExecutor, ProverMetrics, DEFAULT_PROVER_CONCURRENCY, DEFAULT_PROVER_QUEUE_DEPTH,
+};
+pub use proof_jobs::{ProofJob, ProofJobError, ProofJobQueue, ProofJobQueueFull, ProofJobStatus};
 pub use price_oracle::{PriceOracle, PriceData};
 pub use blockchain::{BlockchainService, BlockchainConfig, PoolStatus, UserPosition, TransactionRequest};
-pub use websocket::{WsHub, WsMessage, PoolStatusUpdate, PositionUpdate, PriceUpdate, LiquidationWarning};
+pub use websocket::{
+    WsHub, WsMessage, PoolStatusUpdate, PositionUpdate, PriceUpdate, LiquidationWarning,
+    LiquidationUrgency, ProofJobUpdate,
+};
@@ -0,0 +1,524 @@
+//! Proof Job Queue
+//!
+//! 증명 생성을 HTTP 요청과 분리하는 비동기 작업 큐.
+//!
+//! # Flow
+//!
+//! ```text
+//! POST /proof/jobs ──▶ submit() ──┬─ 큐 가득 참 ──▶ ProofJobQueueFull (HTTP 503)
+//!                                  └─ DB (pending) ──▶ mpsc queue (최대 queue_depth개)
+//!                                                        │
+//!                         ┌──────────────────────────────┘
+//!                         ▼
+//!              worker (semaphore, 최대 N개 동시 실행)
+//!                         │  ProverExecutor (blocking 스레드에서 create_proof)
+//!                         │  실행기가 가득 차면 backoff 후 재시도
+//!                         ▼
+//!              DB (running → done/failed) + 작업의 WebSocket 채널
+//!
+//! GET /proof/jobs/:id ──▶ get() ──▶ DB
+//! ```
+//!
+//! 요청에는 private input(금액, salt)이 포함되므로 DB에 저장하지 않고 큐에만 둠.
+//! 재시작으로 잃은 미완료 작업은 `fail_interrupted()`가 실패로 표시하며,
+//! 클라이언트가 다시 제출해야 함.
+
+use anyhow::{anyhow, Context, Result};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{mpsc, Mutex, Semaphore};
+use uuid::Uuid;
+
+use crate::db::{ProofJobRecord, ProofJobRepository};
+use crate::routes::proof::ProofJobRequest;
+use super::websocket::{ProofJobUpdate, WsHub};
+
+/// 실행할 수 없을 때(`ProofJobError::Unavailable`) 첫 재시도까지 대기 시간 (재시도마다 두 배)
+const DEFAULT_RETRY_DELAY: Duration = Duration::from_millis(500);
+
+/// 실행할 수 없을 때 최대 재시도 횟수 (넘으면 실패 처리)
+const MAX_UNAVAILABLE_RETRIES: u32 = 6;
+
+/// 큐가 가득 차서 작업 제출을 거절함 (`ApiError::ServiceUnavailable`로 매핑)
+#[derive(Debug, thiserror::Error)]
+#[error("Proof job queue is full (limit {limit})")]
+pub struct ProofJobQueueFull {
+    pub limit: usize,
+}
+
+/// 작업 실행 실패
+#[derive(Debug, thiserror::Error)]
+pub enum ProofJobError {
+    /// 일시적으로 실행할 수 없음 (증명 실행기 포화, 가격 오라클 장애 등), backoff 후 재시도
+    #[error("{0}")]
+    Unavailable(String),
+    /// 실패 (입력 검증 실패 포함), 사유는 작업의 `error`로 보고
+    #[error("{0}")]
+    Failed(String),
+}
+
+/// 증명 작업 상태
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ProofJobStatus {
+    /// 큐에서 대기 중
+    Pending,
+    /// 증명 생성 중
+    Running,
+    /// 완료 (`result`에 증명 응답)
+    Done,
+    /// 실패 (`error`에 사유)
+    Failed,
+}
+
+impl ProofJobStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ProofJobStatus::Pending => "pending",
+            ProofJobStatus::Running => "running",
+            ProofJobStatus::Done => "done",
+            ProofJobStatus::Failed => "failed",
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        matches!(self, ProofJobStatus::Done | ProofJobStatus::Failed)
+    }
+}
+
+impl std::str::FromStr for ProofJobStatus {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "pending" => Ok(ProofJobStatus::Pending),
+            "running" => Ok(ProofJobStatus::Running),
+            "done" => Ok(ProofJobStatus::Done),
+            "failed" => Ok(ProofJobStatus::Failed),
+            _ => Err(anyhow!("Unknown proof job status: {}", s)),
+        }
+    }
+}
+
+/// 증명 작업 조회 결과 (GET /proof/jobs/:id 응답)
+#[derive(Debug, Clone, Serialize)]
+pub struct ProofJob {
+    pub job_id: String,
+    /// 회로 종류 (제출한 요청의 `circuit`)
+    pub circuit: String,
+    pub status: ProofJobStatus,
+    /// 증명 응답 (done일 때, 동기 엔드포인트 응답과 같은 형식)
+    pub result: Option<serde_json::Value>,
+    /// 실패 사유 (failed일 때)
+    pub error: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl TryFrom<ProofJobRecord> for ProofJob {
+    type Error = anyhow::Error;
+
+    fn try_from(record: ProofJobRecord) -> Result<Self> {
+        let result = record.result
+            .as_deref()
+            .map(serde_json::from_str)
+            .transpose()
+            .context("Stored proof job result is not valid JSON")?;
+
+        Ok(Self {
+            job_id: record.id.to_string(),
+            circuit: record.circuit,
+            status: record.status.parse()?,
+            result,
+            error: record.error,
+            created_at: record.created_at.to_rfc3339(),
+            updated_at: record.updated_at.to_rfc3339(),
+        })
+    }
+}
+
+type QueuedJob = (ProofJobRecord, ProofJobRequest);
+
+/// 비동기 증명 작업 큐
+pub struct ProofJobQueue {
+    repository: Arc<dyn ProofJobRepository>,
+    ws_hub: Arc<WsHub>,
+    tx: mpsc::Sender<QueuedJob>,
+    /// `start`에서 worker가 가져감
+    rx: Mutex<Option<mpsc::Receiver<QueuedJob>>>,
+    /// 동시에 실행하는 최대 작업 수
+    workers: usize,
+    /// 실행을 기다리는 최대 작업 수
+    queue_depth: usize,
+    /// 첫 재시도까지 대기 시간
+    retry_delay: Duration,
+}
+
+impl ProofJobQueue {
+    /// 새 작업 큐 생성 (`start`를 호출해야 작업이 실행됨)
+    ///
+    /// 실행을 기다리는 작업이 `queue_depth`개면 `submit`이 `ProofJobQueueFull`을 반환.
+    pub fn new(
+        repository: Arc<dyn ProofJobRepository>,
+        ws_hub: Arc<WsHub>,
+        workers: usize,
+        queue_depth: usize,
+    ) -> Self {
+        let queue_depth = queue_depth.max(1);
+        let (tx, rx) = mpsc::channel(queue_depth);
+
+        Self {
+            repository,
+            ws_hub,
+            tx,
+            rx: Mutex::new(Some(rx)),
+            workers: workers.max(1),
+            queue_depth,
+            retry_delay: DEFAULT_RETRY_DELAY,
+        }
+    }
+
+    /// 실행할 수 없을 때 첫 재시도까지 대기 시간 설정 (기본값: 500ms)
+    pub fn with_retry_delay(mut self, retry_delay: Duration) -> Self {
+        self.retry_delay = retry_delay;
+        self
+    }
+
+    /// 작업 실행 시작
+    ///
+    /// `run`은 요청을 받아 증명 응답(JSON)이나 실패 사유를 반환.
+    /// 최대 `workers`개의 작업만 동시에 실행하고 나머지는 pending으로 대기.
+    /// `ProofJobError::Unavailable`이면 대기 시간을 두 배씩 늘리며 다시 실행.
+    pub async fn start<F, Fut>(self: &Arc<Self>, run: F) -> Result<()>
+    where
+        F: Fn(ProofJobRequest) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<serde_json::Value, ProofJobError>> + Send + 'static,
+    {
+        let mut rx = self.rx.lock().await
+            .take()
+            .ok_or_else(|| anyhow!("Proof job queue already started"))?;
+
+        tracing::info!("Starting proof job queue with {} workers", self.workers);
+
+        let queue = self.clone();
+        let run = Arc::new(run);
+        let semaphore = Arc::new(Semaphore::new(self.workers));
+
+        tokio::spawn(async move {
+            while let Some((record, request)) = rx.recv().await {
+                let permit = semaphore.clone()
+                    .acquire_owned()
+                    .await
+                    .expect("proof job semaphore is never closed");
+
+                let queue = queue.clone();
+                let run = run.clone();
+                tokio::spawn(async move {
+                    queue.execute(record, request, run).await;
+                    drop(permit);
+                });
+            }
+        });
+
+        Ok(())
+    }
+
+    /// 작업 제출 (pending으로 저장 후 큐에 추가)
+    ///
+    /// 요청(private input 포함)은 DB에 저장하지 않고 큐에만 넣음.
+    /// 큐가 가득 차 있으면 작업을 저장하지 않고 `ProofJobQueueFull`을 반환.
+    pub async fn submit(&self, request: ProofJobRequest) -> Result<ProofJob> {
+        let slot = self.tx.try_reserve().map_err(|e| match e {
+            mpsc::error::TrySendError::Full(()) => anyhow::Error::new(ProofJobQueueFull { limit: self.queue_depth }),
+            mpsc::error::TrySendError::Closed(()) => anyhow!("Proof job queue is closed"),
+        })?;
+
+        let now = Utc::now();
+        let record = ProofJobRecord {
+            id: Uuid::new_v4(),
+            circuit: request.circuit().to_string(),
+            status: ProofJobStatus::Pending.as_str().to_string(),
+            result: None,
+            error: None,
+            created_at: now,
+            updated_at: now,
+        };
+
+        self.repository.save_job(&record).await
+            .context("Failed to persist proof job")?;
+
+        tracing::info!("Proof job {} submitted ({})", record.id, record.circuit);
+        self.notify(&record).await;
+
+        let job = ProofJob::try_from(record.clone())?;
+        slot.send((record, request));
+
+        Ok(job)
+    }
+
+    /// 작업 조회
+    pub async fn get(&self, id: Uuid) -> Result<Option<ProofJob>> {
+        self.repository.find_job(id).await?
+            .map(ProofJob::try_from)
+            .transpose()
+    }
+
+    /// 재시작 전 미완료 작업을 실패로 표시
+    ///
+    /// 요청을 DB에 저장하지 않으므로 재시작 후에는 다시 실행할 수 없음.
+    /// 실패로 표시한 작업 수를 반환
+    pub async fn fail_interrupted(&self) -> Result<usize> {
+        let mut interrupted = 0;
+
+        for mut record in self.repository.find_unfinished_jobs().await? {
+            let error = "Job was interrupted by a server restart, please resubmit".to_string();
+            self.update(&mut record, ProofJobStatus::Failed, None, Some(error)).await;
+            interrupted += 1;
+        }
+
+        if interrupted > 0 {
+            tracing::warn!("Marked {} interrupted proof jobs as failed", interrupted);
+        }
+
+        Ok(interrupted)
+    }
+
+    async fn execute<F, Fut>(&self, mut record: ProofJobRecord, request: ProofJobRequest, run: Arc<F>)
+    where
+        F: Fn(ProofJobRequest) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<serde_json::Value, ProofJobError>> + Send + 'static,
+    {
+        self.update(&mut record, ProofJobStatus::Running, None, None).await;
+        tracing::info!("Proof job {} running ({})", record.id, record.circuit);
+
+        let mut delay = self.retry_delay;
+        let mut retries = 0;
+        let outcome = loop {
+            match run(request.clone()).await {
+                Err(ProofJobError::Unavailable(reason)) if retries < MAX_UNAVAILABLE_RETRIES => {
+                    retries += 1;
+                    tracing::warn!(
+                        "Proof job {} unavailable ({}), retry {} in {:?}",
+                        record.id, reason, retries, delay
+                    );
+                    tokio::time::sleep(delay).await;
+                    delay *= 2;
+                }
+                outcome => break outcome,
+            }
+        };
+
+        match outcome {
+            Ok(result) => {
+                tracing::info!("Proof job {} done", record.id);
+                self.update(&mut record, ProofJobStatus::Done, Some(result), None).await;
+            }
+            Err(error) => {
+                tracing::warn!("Proof job {} failed: {}", record.id, error);
+                self.update(&mut record, ProofJobStatus::Failed, None, Some(error.to_string())).await;
+            }
+        }
+    }
+
+    /// 상태 변경을 저장하고 WebSocket으로 알림
+    async fn update(
+        &self,
+        record: &mut ProofJobRecord,
+        status: ProofJobStatus,
+        result: Option<serde_json::Value>,
+        error: Option<String>,
+    ) {
+        record.status = status.as_str().to_string();
+        record.result = result.map(|value| value.to_string());
+        record.error = error;
+        record.updated_at = Utc::now();
+
+        if let Err(e) = self.repository.save_job(record).await {
+            tracing::warn!("Failed to persist proof job {}: {:?}", record.id, e);
+        }
+
+        self.notify(record).await;
+    }
+
+    /// 작업의 WebSocket 채널(`proof_job:<job_id>`)로 상태 전송
+    ///
+    /// job id를 아는 제출자만 구독할 수 있음
+    async fn notify(&self, record: &ProofJobRecord) {
+        self.ws_hub.send_proof_job_update(ProofJobUpdate {
+            job_id: record.id.to_string(),
+            circuit: record.circuit.clone(),
+            status: record.status.clone(),
+            error: record.error.clone(),
+            timestamp: record.updated_at.timestamp() as u64,
+        }).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::mock::MockProofJobRepository;
+    use crate::routes::proof::CollateralProofRequest;
+    use crate::services::WsMessage;
+
+    fn collateral_request(collateral: &str) -> ProofJobRequest {
+        ProofJobRequest::Collateral(CollateralProofRequest {
+            collateral: collateral.to_string(),
+            threshold: "500".to_string(),
+            salt: "12345".to_string(),
+        })
+    }
+
+    /// 담보가 "0"이면 실패하는 가짜 prover
+    async fn fake_prover(request: ProofJobRequest) -> Result<serde_json::Value, ProofJobError> {
+        match request {
+            ProofJobRequest::Collateral(req) if req.collateral != "0" => {
+                Ok(serde_json::json!({ "commitment": "0x01" }))
+            }
+            _ => Err(ProofJobError::Failed("Collateral is less than threshold".to_string())),
+        }
+    }
+
+    async fn wait_for_finish(queue: &ProofJobQueue, id: Uuid) -> ProofJob {
+        for _ in 0..100 {
+            let job = queue.get(id).await.unwrap().unwrap();
+            if job.status.is_finished() {
+                return job;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+        }
+        panic!("Proof job {} did not finish", id);
+    }
+
+    #[test]
+    fn test_job_request_round_trip() {
+        let json = r#"{"circuit":"collateral","request":{"collateral":"1000","threshold":"500","salt":"1"}}"#;
+        let request: ProofJobRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(request.circuit(), "collateral");
+
+        let restored: ProofJobRequest = serde_json::from_str(&serde_json::to_string(&request).unwrap()).unwrap();
+        assert_eq!(restored.circuit(), "collateral");
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_job_lifecycle() {
+        let repository = Arc::new(MockProofJobRepository::new());
+        let hub = Arc::new(WsHub::new());
+        let queue = Arc::new(ProofJobQueue::new(repository.clone(), hub.clone(), 2, 4));
+
+        // worker 시작 전에 구독해야 running부터 모든 알림을 받음
+        let job = queue.submit(collateral_request("1000")).await.unwrap();
+        assert_eq!(job.status, ProofJobStatus::Pending);
+        let failed = queue.submit(collateral_request("0")).await.unwrap();
+        let mut rx = hub.subscribe_proof_job(&job.job_id).await;
+
+        queue.start(fake_prover).await.unwrap();
+        assert!(queue.start(fake_prover).await.is_err());
+
+        let id: Uuid = job.job_id.parse().unwrap();
+        let done = wait_for_finish(&queue, id).await;
+        assert_eq!(done.status, ProofJobStatus::Done);
+        assert_eq!(done.result.unwrap()["commitment"], "0x01");
+
+        let failed = wait_for_finish(&queue, failed.job_id.parse().unwrap()).await;
+        assert_eq!(failed.status, ProofJobStatus::Failed);
+        assert_eq!(failed.error.as_deref(), Some("Collateral is less than threshold"));
+
+        // 작업 채널에는 그 작업의 상태만 running → done 순서로 전송되고,
+        // 작업이 끝나면 채널이 닫힘
+        let mut statuses = Vec::new();
+        while let Ok(WsMessage::ProofJobStatus(update)) = rx.recv().await {
+            assert_eq!(update.job_id, job.job_id);
+            statuses.push(update.status);
+        }
+        assert_eq!(statuses, vec!["running", "done"]);
+
+        assert!(queue.get(Uuid::new_v4()).await.unwrap().is_none());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_submit_rejects_when_queue_full() {
+        let repository = Arc::new(MockProofJobRepository::new());
+        let queue = ProofJobQueue::new(repository.clone(), Arc::new(WsHub::new()), 1, 1);
+
+        // worker가 없으므로 첫 작업이 큐를 채움
+        queue.submit(collateral_request("1000")).await.unwrap();
+
+        let err = queue.submit(collateral_request("1000")).await.unwrap_err();
+        assert!(err.is::<ProofJobQueueFull>());
+
+        // 거절된 작업은 저장하지 않음
+        assert_eq!(repository.find_unfinished_jobs().await.unwrap().len(), 1);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_retry_when_prover_unavailable() {
+        let queue = Arc::new(
+            ProofJobQueue::new(Arc::new(MockProofJobRepository::new()), Arc::new(WsHub::new()), 2, 4)
+                .with_retry_delay(Duration::from_millis(1)),
+        );
+
+        // 담보가 "busy"면 항상, 그 외에는 처음 두 번 실행기가 가득 찬 prover
+        let attempts = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let counter = attempts.clone();
+        queue.start(move |request| {
+            let attempt = counter.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            async move {
+                match request {
+                    ProofJobRequest::Collateral(req) if req.collateral != "busy" && attempt >= 2 => {
+                        fake_prover(ProofJobRequest::Collateral(req)).await
+                    }
+                    _ => Err(ProofJobError::Unavailable("Prover queue is full".to_string())),
+                }
+            }
+        }).await.unwrap();
+
+        let job = queue.submit(collateral_request("1000")).await.unwrap();
+        let job = wait_for_finish(&queue, job.job_id.parse().unwrap()).await;
+        assert_eq!(job.status, ProofJobStatus::Done);
+        assert_eq!(attempts.load(std::sync::atomic::Ordering::SeqCst), 3);
+
+        // 재시도 횟수를 넘으면 실패
+        let busy = queue.submit(collateral_request("busy")).await.unwrap();
+        let busy = wait_for_finish(&queue, busy.job_id.parse().unwrap()).await;
+        assert_eq!(busy.status, ProofJobStatus::Failed);
+        assert_eq!(busy.error.as_deref(), Some("Prover queue is full"));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_fail_interrupted_jobs() {
+        let repository = Arc::new(MockProofJobRepository::new());
+
+        // 재시작 전에 대기 중이던 작업과 실행 중이던 작업
+        let now = Utc::now();
+        let pending = ProofJobRecord {
+            id: Uuid::new_v4(),
+            circuit: "collateral".to_string(),
+            status: "pending".to_string(),
+            result: None,
+            error: None,
+            created_at: now,
+            updated_at: now,
+        };
+        let running = ProofJobRecord {
+            id: Uuid::new_v4(),
+            status: "running".to_string(),
+            ..pending.clone()
+        };
+        repository.save_job(&pending).await.unwrap();
+        repository.save_job(&running).await.unwrap();
+
+        let queue = ProofJobQueue::new(repository.clone(), Arc::new(WsHub::new()), 1, 4);
+        assert_eq!(queue.fail_interrupted().await.unwrap(), 2);
+
+        for id in [pending.id, running.id] {
+            let job = queue.get(id).await.unwrap().unwrap();
+            assert_eq!(job.status, ProofJobStatus::Failed);
+            assert!(job.error.unwrap().contains("resubmit"));
+        }
+        assert!(repository.find_unfinished_jobs().await.unwrap().is_empty());
+    }
+}
@@ -0,0 +1,247 @@
+//! Prover Executor
+//!
+//! CPU 작업인 keygen과 `create_proof`/`verify_proof`를 Tokio runtime worker 스레드 밖
+//! (blocking 스레드 풀)에서 실행하고, 동시 실행 수와 대기열 길이를 제한.
+//!
+//! ```text
+//! generate_*_proof ──▶ run() ──┬─ permit 있음 ──────────────▶ spawn_blocking(task)
+//!                              ├─ 대기열 < max_queue_depth ─▶ permit 대기 ─▶ spawn_blocking(task)
+//!                              └─ 대기열 가득 참 ───────────▶ ProverBusy (HTTP 503)
+//! ```
+//!
+//! 대기 시간(permit을 얻기까지)은 `metrics()`로 조회 (GET /health의 `prover`).
+
+use anyhow::{anyhow, Result};
+use serde::Serialize;
+use std::fmt::Display;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+use tokio::sync::Semaphore;
+
+/// 기본 동시 증명 수
+///
+/// `create_proof`는 내부적으로 모든 코어를 사용하므로 작게 유지
+pub const DEFAULT_PROVER_CONCURRENCY: usize = 2;
+
+/// 기본 최대 대기열 길이
+pub const DEFAULT_PROVER_QUEUE_DEPTH: usize = 16;
+
+/// 대기열이 가득 차서 증명 요청을 거절함 (`ApiError::ServiceUnavailable`로 매핑)
+#[derive(Debug, thiserror::Error)]
+#[error("Prover queue is full ({queued} waiting, limit {limit})")]
+pub struct ProverBusy {
+    pub queued: usize,
+    pub limit: usize,
+}
+
+/// 실행기 상태와 대기 시간 통계
+#[derive(Debug, Clone, Serialize)]
+pub struct ProverMetrics {
+    /// 최대 동시 실행 수
+    pub concurrency: usize,
+    /// 최대 대기열 길이
+    pub max_queue_depth: usize,
+    /// 현재 실행 중인 작업 수
+    pub running: usize,
+    /// 현재 permit을 기다리는 작업 수
+    pub queued: usize,
+    /// 실행을 시작한 작업 수
+    pub started: u64,
+    /// 대기열이 가득 차 거절된 작업 수
+    pub rejected: u64,
+    /// 평균 대기 시간 (ms)
+    pub avg_queue_wait_ms: f64,
+    /// 최대 대기 시간 (ms)
+    pub max_queue_wait_ms: u64,
+    /// 마지막 작업의 대기 시간 (ms)
+    pub last_queue_wait_ms: u64,
+}
+
+#[derive(Debug, Default)]
+struct WaitStats {
+    started: u64,
+    rejected: u64,
+    total_wait_ms: u64,
+    max_wait_ms: u64,
+    last_wait_ms: u64,
+}
+
+/// 대기 중인 작업 수 카운터 (대기 중 취소되어도 감소하도록 Drop에서 처리)
+struct QueueSlot<'a>(&'a AtomicUsize);
+
+impl Drop for QueueSlot<'_> {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// 증명 실행기
+pub struct ProverExecutor {
+    permits: Arc<Semaphore>,
+    concurrency: usize,
+    max_queue_depth: usize,
+    queued: AtomicUsize,
+    stats: Mutex<WaitStats>,
+}
+
+impl ProverExecutor {
+    /// 새 실행기 생성
+    ///
+    /// * `concurrency` - 동시에 실행하는 최대 작업 수 (최소 1)
+    /// * `max_queue_depth` - permit을 기다릴 수 있는 최대 작업 수 (0이면 대기 없이 거절)
+    pub fn new(concurrency: usize, max_queue_depth: usize) -> Self {
+        let concurrency = concurrency.max(1);
+
+        Self {
+            permits: Arc::new(Semaphore::new(concurrency)),
+            concurrency,
+            max_queue_depth,
+            queued: AtomicUsize::new(0),
+            stats: Mutex::new(WaitStats::default()),
+        }
+    }
+
+    /// blocking 스레드에서 `task` 실행
+    ///
+    /// 실행 슬롯이 없고 대기열도 가득 차 있으면 `ProverBusy` 에러를 반환.
+    /// permit은 task와 함께 blocking 스레드로 넘어가므로, 호출자가 취소되어도
+    /// 실행 중인 증명이 끝날 때까지 슬롯을 점유함.
+    pub async fn run<T, F>(&self, label: impl Display + Send, task: F) -> Result<T>
+    where
+        F: FnOnce() -> Result<T> + Send + 'static,
+        T: Send + 'static,
+    {
+        let start = Instant::now();
+
+        let permit = match self.permits.clone().try_acquire_owned() {
+            Ok(permit) => permit,
+            Err(_) => {
+                let reserved = self.queued.fetch_update(Ordering::SeqCst, Ordering::SeqCst, |queued| {
+                    (queued < self.max_queue_depth).then_some(queued + 1)
+                });
+                if let Err(queued) = reserved {
+                    self.stats.lock().unwrap().rejected += 1;
+                    tracing::warn!("Rejecting {} proof: prover queue is full ({} waiting)", label, queued);
+                    return Err(ProverBusy { queued, limit: self.max_queue_depth }.into());
+                }
+
+                let _slot = QueueSlot(&self.queued);
+                self.permits.clone()
+                    .acquire_owned()
+                    .await
+                    .expect("prover semaphore is never closed")
+            }
+        };
+
+        let wait_ms = start.elapsed().as_millis() as u64;
+        self.record_wait(wait_ms);
+        tracing::debug!("{} proof waited {}ms for a prover slot", label, wait_ms);
+
+        tokio::task::spawn_blocking(move || {
+            let _permit = permit;
+            task()
+        })
+        .await
+        .map_err(|e| anyhow!("Prover task for {} failed: {}", label, e))?
+    }
+
+    /// 현재 상태와 대기 시간 통계
+    pub fn metrics(&self) -> ProverMetrics {
+        let stats = self.stats.lock().unwrap();
+
+        ProverMetrics {
+            concurrency: self.concurrency,
+            max_queue_depth: self.max_queue_depth,
+            running: self.concurrency - self.permits.available_permits(),
+            queued: self.queued.load(Ordering::SeqCst),
+            started: stats.started,
+            rejected: stats.rejected,
+            avg_queue_wait_ms: if stats.started == 0 {
+                0.0
+            } else {
+                stats.total_wait_ms as f64 / stats.started as f64
+            },
+            max_queue_wait_ms: stats.max_wait_ms,
+            last_queue_wait_ms: stats.last_wait_ms,
+        }
+    }
+
+    fn record_wait(&self, wait_ms: u64) {
+        let mut stats = self.stats.lock().unwrap();
+        stats.started += 1;
+        stats.total_wait_ms += wait_ms;
+        stats.max_wait_ms = stats.max_wait_ms.max(wait_ms);
+        stats.last_wait_ms = wait_ms;
+    }
+}
+
+impl Default for ProverExecutor {
+    fn default() -> Self {
+        Self::new(DEFAULT_PROVER_CONCURRENCY, DEFAULT_PROVER_QUEUE_DEPTH)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::mpsc;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_run_returns_task_result() {
+        let executor = ProverExecutor::new(1, 0);
+
+        assert_eq!(executor.run("test", || Ok(42)).await.unwrap(), 42);
+        assert!(executor.run("test", || -> Result<()> { Err(anyhow!("boom")) }).await.is_err());
+
+        let metrics = executor.metrics();
+        assert_eq!(metrics.started, 2);
+        assert_eq!(metrics.running, 0);
+        assert_eq!(metrics.rejected, 0);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_back_pressure_when_queue_is_full() {
+        let executor = Arc::new(ProverExecutor::new(1, 1));
+        let (release_tx, release_rx) = mpsc::channel::<()>();
+
+        // 실행 슬롯을 점유하는 작업
+        let running = {
+            let executor = executor.clone();
+            tokio::spawn(async move {
+                executor.run("running", move || {
+                    release_rx.recv().unwrap();
+                    Ok(())
+                }).await
+            })
+        };
+        while executor.metrics().running == 0 {
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+
+        // 대기열의 한 자리를 차지하는 작업
+        let queued = {
+            let executor = executor.clone();
+            tokio::spawn(async move { executor.run("queued", || Ok(())).await })
+        };
+        while executor.metrics().queued == 0 {
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+
+        // 대기열이 가득 차면 거절
+        let err = executor.run("rejected", || Ok(())).await.unwrap_err();
+        assert!(err.is::<ProverBusy>());
+        assert_eq!(executor.metrics().rejected, 1);
+
+        release_tx.send(()).unwrap();
+        running.await.unwrap().unwrap();
+        queued.await.unwrap().unwrap();
+
+        let metrics = executor.metrics();
+        assert_eq!(metrics.started, 2);
+        assert_eq!(metrics.queued, 0);
+        assert_eq!(metrics.running, 0);
+        assert!(metrics.max_queue_wait_ms > 0);
+    }
+}
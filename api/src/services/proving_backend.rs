@@ -0,0 +1,317 @@
+//! Proving Backend - 증명 시스템 선택
+//!
+//! 같은 회로를 두 가지 commitment scheme으로 증명할 수 있음 (`PROVING_BACKEND`)
+//!
+//! | backend       | commitment | 곡선          | multiopen | transcript      | 용도                         |
+//! |---------------|------------|---------------|-----------|-----------------|------------------------------|
+//! | `ipa`         | IPA        | Pasta (Vesta) | -         | Blake2b         | trusted setup 없음, 오프체인 검증 |
+//! | `kzg-shplonk` | KZG        | BN254         | SHPLONK   | Keccak256 (EVM) | proof 크기 작음               |
+//! | `kzg-gwc`     | KZG        | BN254         | GWC       | Keccak256 (EVM) | verifier 단순                |
+//!
+//! KZG backend는 `EvmTranscript`로 증명함: halo2-solidity-verifier / snark-verifier가
+//! 생성하는 Solidity verifier가 읽는 인코딩 (point는 비압축 `x || y`, scalar는
+//! big-endian 32 bytes, challenge는 `keccak256(buf) mod r`). 이 저장소는 verifier를
+//! 생성하거나 테스트하지 않고 `ZKLendingPool`도 이 proof를 검증하지 않음. 온체인 검증에는
+//! 배포할 vk로 verifier를 생성해야 함 (halo2-solidity-verifier는 SHPLONK만,
+//! snark-verifier는 SHPLONK/GWC 모두 지원).
+//!
+//! 회로는 field에 대해 generic이지만 Poseidon 파라미터가 field마다 다르므로
+//! commitment와 verifying key는 backend마다 다름
+
+use anyhow::{anyhow, Result};
+use ff::{FromUniformBytes, PrimeField, WithSmallOrderMulGroup};
+use halo2_proofs::{
+    arithmetic::CurveAffine,
+    halo2curves::{
+        bn256::{Bn256, Fr, G1Affine},
+        serde::SerdeObject,
+    },
+    plonk::{create_proof, verify_proof, Circuit, Error, ProvingKey, VerifyingKey},
+    poly::{
+        commitment::{Params, ParamsProver},
+        ipa::{
+            commitment::{IPACommitmentScheme, ParamsIPA},
+            multiopen::{ProverIPA, VerifierIPA},
+            strategy::SingleStrategy as IpaStrategy,
+        },
+        kzg::{
+            commitment::{KZGCommitmentScheme, ParamsKZG},
+            multiopen::{ProverGWC, ProverSHPLONK, VerifierGWC, VerifierSHPLONK},
+            strategy::SingleStrategy as KzgStrategy,
+        },
+        VerificationStrategy,
+    },
+    transcript::{
+        Blake2bRead, Blake2bWrite, Challenge255, TranscriptReadBuffer, TranscriptWriterBuffer,
+    },
+};
+use pasta_curves::{EqAffine, Fp};
+use rand::rngs::OsRng;
+use zk_private_lending_circuits::EvmTranscript;
+
+/// 설정에서 선택하는 증명 backend (`Config::proving_backend`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ProvingBackend {
+    /// IPA commitment, Pasta(Vesta) 곡선, Blake2b transcript
+    #[default]
+    Ipa,
+    /// KZG commitment, BN254 곡선, SHPLONK multiopen, Keccak256 transcript (EVM 인코딩)
+    KzgShplonk,
+    /// KZG commitment, BN254 곡선, GWC multiopen, Keccak256 transcript (EVM 인코딩)
+    KzgGwc,
+}
+
+impl ProvingBackend {
+    /// 응답의 `ProofData.proving_system`
+    pub fn proving_system(&self) -> &'static str {
+        match self {
+            ProvingBackend::Ipa => "halo2-ipa-pasta-blake2b",
+            ProvingBackend::KzgShplonk => "halo2-kzg-bn254-shplonk-keccak256",
+            ProvingBackend::KzgGwc => "halo2-kzg-bn254-gwc-keccak256",
+        }
+    }
+}
+
+impl std::fmt::Display for ProvingBackend {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProvingBackend::Ipa => write!(f, "ipa"),
+            ProvingBackend::KzgShplonk => write!(f, "kzg-shplonk"),
+            ProvingBackend::KzgGwc => write!(f, "kzg-gwc"),
+        }
+    }
+}
+
+impl std::str::FromStr for ProvingBackend {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "ipa" => Ok(ProvingBackend::Ipa),
+            "kzg-shplonk" | "kzg" => Ok(ProvingBackend::KzgShplonk),
+            "kzg-gwc" => Ok(ProvingBackend::KzgGwc),
+            _ => Err(anyhow!("Unknown proving backend: {} (ipa | kzg-shplonk | kzg-gwc)", s)),
+        }
+    }
+}
+
+/// 증명 시스템 (commitment scheme, 곡선, multiopen, transcript 조합)
+///
+/// `ZKProver`는 이 trait에 대해 generic하게 회로를 증명/검증하며,
+/// 구현체는 `create_proof`/`verify_proof`의 타입 파라미터만 정함
+pub trait ProofSystem: Send + Sync + 'static {
+    /// 회로의 field (곡선의 scalar field)
+    type Scalar: PrimeField + FromUniformBytes<64> + WithSmallOrderMulGroup<3> + SerdeObject + Ord;
+    /// commitment 곡선
+    type Curve: CurveAffine<ScalarExt = Self::Scalar> + SerdeObject;
+    /// SRS
+    type Params: for<'params> Params<'params, Self::Curve> + Send + Sync;
+
+    /// 설정 값과 `proving_system` 식별자
+    const BACKEND: ProvingBackend;
+
+    /// SRS 생성
+    fn setup(k: u32) -> Self::Params;
+
+    /// 증명 생성, proof transcript 반환
+    fn create_proof<C: Circuit<Self::Scalar>>(
+        params: &Self::Params,
+        pk: &ProvingKey<Self::Curve>,
+        circuit: C,
+        public_inputs: &[Self::Scalar],
+    ) -> Result<Vec<u8>, Error>;
+
+    /// proof transcript 검증
+    fn verify_proof(
+        params: &Self::Params,
+        vk: &VerifyingKey<Self::Curve>,
+        proof: &[u8],
+        public_inputs: &[Self::Scalar],
+    ) -> Result<(), Error>;
+}
+
+/// IPA commitment, Pasta(Vesta) 곡선, Blake2b transcript
+pub struct Ipa;
+
+impl ProofSystem for Ipa {
+    type Scalar = Fp;
+    type Curve = EqAffine;
+    type Params = ParamsIPA<EqAffine>;
+
+    const BACKEND: ProvingBackend = ProvingBackend::Ipa;
+
+    /// IPA SRS는 hash-to-curve로 만들어 trusted setup이 필요 없고 항상 같음
+    fn setup(k: u32) -> Self::Params {
+        ParamsIPA::<EqAffine>::new(k)
+    }
+
+    fn create_proof<C: Circuit<Fp>>(
+        params: &Self::Params,
+        pk: &ProvingKey<EqAffine>,
+        circuit: C,
+        public_inputs: &[Fp],
+    ) -> Result<Vec<u8>, Error> {
+        let mut transcript = Blake2bWrite::<Vec<u8>, EqAffine, Challenge255<EqAffine>>::init(vec![]);
+        let instances: &[&[Fp]] = &[public_inputs];
+
+        create_proof::<IPACommitmentScheme<EqAffine>, ProverIPA<'_, EqAffine>, _, _, _, _>(
+            params,
+            pk,
+            &[circuit],
+            &[instances],
+            OsRng,
+            &mut transcript,
+        )?;
+
+        Ok(transcript.finalize())
+    }
+
+    fn verify_proof(
+        params: &Self::Params,
+        vk: &VerifyingKey<EqAffine>,
+        proof: &[u8],
+        public_inputs: &[Fp],
+    ) -> Result<(), Error> {
+        let mut transcript = Blake2bRead::<_, EqAffine, Challenge255<EqAffine>>::init(proof);
+        let instances: &[&[Fp]] = &[public_inputs];
+
+        verify_proof::<IPACommitmentScheme<EqAffine>, VerifierIPA<'_, EqAffine>, _, _, _>(
+            params,
+            vk,
+            IpaStrategy::new(params),
+            &[instances],
+            &mut transcript,
+        )
+    }
+}
+
+/// KZG SRS 생성
+///
+/// 무작위 toxic waste로 만드는 개발용 SRS. 프로덕션에서는 ceremony SRS
+/// (예: perpetual powers of tau)를 키 저장소의 `params_k{k}.bin`에 두고 사용
+fn kzg_setup(k: u32) -> ParamsKZG<Bn256> {
+    tracing::warn!("Generating a local KZG SRS; use a ceremony SRS in the key store for production");
+    ParamsKZG::<Bn256>::setup(k, OsRng)
+}
+
+/// KZG commitment, BN254 곡선, SHPLONK multiopen, Keccak256 transcript (EVM 인코딩)
+pub struct KzgShplonk;
+
+impl ProofSystem for KzgShplonk {
+    type Scalar = Fr;
+    type Curve = G1Affine;
+    type Params = ParamsKZG<Bn256>;
+
+    const BACKEND: ProvingBackend = ProvingBackend::KzgShplonk;
+
+    fn setup(k: u32) -> Self::Params {
+        kzg_setup(k)
+    }
+
+    fn create_proof<C: Circuit<Fr>>(
+        params: &Self::Params,
+        pk: &ProvingKey<G1Affine>,
+        circuit: C,
+        public_inputs: &[Fr],
+    ) -> Result<Vec<u8>, Error> {
+        let mut transcript = EvmTranscript::<G1Affine, _>::new(vec![]);
+        let instances: &[&[Fr]] = &[public_inputs];
+
+        create_proof::<KZGCommitmentScheme<Bn256>, ProverSHPLONK<'_, Bn256>, _, _, _, _>(
+            params,
+            pk,
+            &[circuit],
+            &[instances],
+            OsRng,
+            &mut transcript,
+        )?;
+
+        Ok(transcript.finalize())
+    }
+
+    fn verify_proof(
+        params: &Self::Params,
+        vk: &VerifyingKey<G1Affine>,
+        proof: &[u8],
+        public_inputs: &[Fr],
+    ) -> Result<(), Error> {
+        let mut transcript = EvmTranscript::<G1Affine, _>::new(proof);
+        let instances: &[&[Fr]] = &[public_inputs];
+
+        verify_proof::<KZGCommitmentScheme<Bn256>, VerifierSHPLONK<'_, Bn256>, _, _, _>(
+            params,
+            vk,
+            KzgStrategy::new(params),
+            &[instances],
+            &mut transcript,
+        )
+    }
+}
+
+/// KZG commitment, BN254 곡선, GWC multiopen, Keccak256 transcript (EVM 인코딩)
+pub struct KzgGwc;
+
+impl ProofSystem for KzgGwc {
+    type Scalar = Fr;
+    type Curve = G1Affine;
+    type Params = ParamsKZG<Bn256>;
+
+    const BACKEND: ProvingBackend = ProvingBackend::KzgGwc;
+
+    fn setup(k: u32) -> Self::Params {
+        kzg_setup(k)
+    }
+
+    fn create_proof<C: Circuit<Fr>>(
+        params: &Self::Params,
+        pk: &ProvingKey<G1Affine>,
+        circuit: C,
+        public_inputs: &[Fr],
+    ) -> Result<Vec<u8>, Error> {
+        let mut transcript = EvmTranscript::<G1Affine, _>::new(vec![]);
+        let instances: &[&[Fr]] = &[public_inputs];
+
+        create_proof::<KZGCommitmentScheme<Bn256>, ProverGWC<'_, Bn256>, _, _, _, _>(
+            params,
+            pk,
+            &[circuit],
+            &[instances],
+            OsRng,
+            &mut transcript,
+        )?;
+
+        Ok(transcript.finalize())
+    }
+
+    fn verify_proof(
+        params: &Self::Params,
+        vk: &VerifyingKey<G1Affine>,
+        proof: &[u8],
+        public_inputs: &[Fr],
+    ) -> Result<(), Error> {
+        let mut transcript = EvmTranscript::<G1Affine, _>::new(proof);
+        let instances: &[&[Fr]] = &[public_inputs];
+
+        verify_proof::<KZGCommitmentScheme<Bn256>, VerifierGWC<'_, Bn256>, _, _, _>(
+            params,
+            vk,
+            KzgStrategy::new(params),
+            &[instances],
+            &mut transcript,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backend_from_str() {
+        for backend in [ProvingBackend::Ipa, ProvingBackend::KzgShplonk, ProvingBackend::KzgGwc] {
+            assert_eq!(backend.to_string().parse::<ProvingBackend>().unwrap(), backend);
+        }
+        assert_eq!("KZG".parse::<ProvingBackend>().unwrap(), ProvingBackend::KzgShplonk);
+        assert!("groth16".parse::<ProvingBackend>().is_err());
+    }
+}
@@ -7,12 +7,14 @@
 //! - User position change notifications
 //! - Price change alerts
 //! - Liquidation warnings
+//! - Proof job status
 
 use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::{broadcast, RwLock};
 use serde::{Deserialize, Serialize};
 use anyhow::Result;
+use zk_private_lending_circuits::health_factor::MAX_HEALTH_FACTOR;
 
 /// WebSocket 메시지 타입
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -28,6 +30,8 @@ pub enum WsMessage {
     LiquidationWarning(LiquidationWarning),
     /// 트랜잭션 상태
     TransactionStatus(TransactionStatusUpdate),
+    /// 비동기 증명 작업 상태
+    ProofJobStatus(ProofJobUpdate),
     /// 에러
     Error(WsError),
     /// 구독 확인
@@ -90,6 +94,33 @@ pub enum LiquidationUrgency {
     Critical, // HF < 0.9
 }
 
+impl LiquidationUrgency {
+    /// 긴급도에 해당하는 HF 구간 `[lo, hi)` (basis points, 1.0 = 10000)
+    ///
+    /// `get_liquidation_urgency`와 같은 경계입니다. HealthFactorBucket 증명의
+    /// public bucket으로 사용되어, 서버는 정확한 HF 대신 구간만 알게 됩니다.
+    pub fn health_factor_bucket(&self) -> (u64, u64) {
+        match self {
+            LiquidationUrgency::Critical => (0, 9_000),
+            LiquidationUrgency::High => (9_000, 10_000),
+            LiquidationUrgency::Medium => (10_000, 12_000),
+            LiquidationUrgency::Low => (12_000, MAX_HEALTH_FACTOR),
+        }
+    }
+
+    /// 증명된 HF 구간에 해당하는 긴급도 (정확히 일치하는 구간만 인정)
+    pub fn from_health_factor_bucket(lo: u64, hi: u64) -> Option<Self> {
+        [
+            LiquidationUrgency::Critical,
+            LiquidationUrgency::High,
+            LiquidationUrgency::Medium,
+            LiquidationUrgency::Low,
+        ]
+        .into_iter()
+        .find(|urgency| urgency.health_factor_bucket() == (lo, hi))
+    }
+}
+
 /// 트랜잭션 상태 업데이트
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TransactionStatusUpdate {
@@ -100,6 +131,19 @@ pub struct TransactionStatusUpdate {
     pub timestamp: u64,
 }
 
+/// 증명 작업 상태 업데이트 (증명 결과는 GET /proof/jobs/:id로 조회)
+///
+/// 작업 채널(`proof_job:<job_id>`)로만 전송되므로 job id를 아는 제출자만 받음
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProofJobUpdate {
+    pub job_id: String,
+    pub circuit: String,
+    /// pending | running | done | failed
+    pub status: String,
+    pub error: Option<String>,
+    pub timestamp: u64,
+}
+
 /// WebSocket 에러
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WsError {
@@ -123,6 +167,8 @@ pub enum Channel {
     UserPosition(String),
     /// 가격
     Prices,
+    /// 특정 증명 작업 상태 (job id)
+    ProofJob(String),
     /// 모든 이벤트
     AllEvents,
 }
@@ -133,6 +179,7 @@ impl std::fmt::Display for Channel {
             Channel::PoolStatus => write!(f, "pool_status"),
             Channel::UserPosition(addr) => write!(f, "position:{}", addr),
             Channel::Prices => write!(f, "prices"),
+            Channel::ProofJob(job_id) => write!(f, "proof_job:{}", job_id),
             Channel::AllEvents => write!(f, "all_events"),
         }
     }
@@ -166,6 +213,8 @@ pub struct WsHub {
     pool_tx: broadcast::Sender<WsMessage>,
     /// 브로드캐스트 채널 (가격)
     price_tx: broadcast::Sender<WsMessage>,
+    /// 증명 작업별 채널 (작업이 끝나면 제거)
+    job_channels: Arc<RwLock<HashMap<String, broadcast::Sender<WsMessage>>>>,
     /// 사용자별 개인 채널
     user_channels: Arc<RwLock<HashMap<String, broadcast::Sender<WsMessage>>>>,
     /// 연결 정보
@@ -181,6 +230,7 @@ impl WsHub {
         Self {
             pool_tx,
             price_tx,
+            job_channels: Arc::new(RwLock::new(HashMap::new())),
             user_channels: Arc::new(RwLock::new(HashMap::new())),
             connections: Arc::new(RwLock::new(HashMap::new())),
         }
@@ -196,6 +246,22 @@ impl WsHub {
         let _ = self.price_tx.send(WsMessage::PriceUpdate(update));
     }
 
+    /// 증명 작업 상태를 그 작업의 채널로 전송
+    ///
+    /// 작업이 끝나면(done, failed) 채널을 제거해 구독자의 수신이 종료됨
+    pub async fn send_proof_job_update(&self, update: ProofJobUpdate) {
+        let mut channels = self.job_channels.write().await;
+        let finished = matches!(update.status.as_str(), "done" | "failed");
+        let tx = if finished {
+            channels.remove(&update.job_id)
+        } else {
+            channels.get(&update.job_id).cloned()
+        };
+        if let Some(tx) = tx {
+            let _ = tx.send(WsMessage::ProofJobStatus(update));
+        }
+    }
+
     /// 특정 사용자에게 포지션 업데이트 전송
     pub async fn send_position_update(&self, address: &str, update: PositionUpdate) {
         let channels = self.user_channels.read().await;
@@ -230,6 +296,24 @@ impl WsHub {
         self.price_tx.subscribe()
     }
 
+    /// 증명 작업 상태 구독
+    pub async fn subscribe_proof_job(&self, job_id: &str) -> broadcast::Receiver<WsMessage> {
+        let mut channels = self.job_channels.write().await;
+
+        let tx = channels.entry(job_id.to_string()).or_insert_with(|| {
+            let (tx, _) = broadcast::channel(16);
+            tx
+        });
+
+        tx.subscribe()
+    }
+
+    /// 구독자가 없는 증명 작업 채널 제거 (연결 종료 시)
+    pub async fn prune_proof_job_channels(&self) {
+        let mut channels = self.job_channels.write().await;
+        channels.retain(|_, tx| tx.receiver_count() > 0);
+    }
+
     /// 사용자 채널 구독
     pub async fn subscribe_user(&self, address: &str) -> broadcast::Receiver<WsMessage> {
         let mut channels = self.user_channels.write().await;
@@ -269,6 +353,11 @@ impl WsHub {
         for tx in channels.values() {
             let _ = tx.send(message.clone());
         }
+
+        let channels = self.job_channels.read().await;
+        for tx in channels.values() {
+            let _ = tx.send(message.clone());
+        }
     }
 }
 
@@ -387,6 +476,32 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_proof_job_subscription() {
+        let hub = WsHub::new();
+        let mut rx = hub.subscribe_proof_job("job-a").await;
+
+        let update = |job_id: &str, status: &str| ProofJobUpdate {
+            job_id: job_id.to_string(),
+            circuit: "collateral".to_string(),
+            status: status.to_string(),
+            error: None,
+            timestamp: 1234567890,
+        };
+
+        // 다른 작업의 상태는 받지 않음
+        hub.send_proof_job_update(update("job-b", "running")).await;
+        hub.send_proof_job_update(update("job-a", "running")).await;
+        hub.send_proof_job_update(update("job-a", "done")).await;
+
+        let mut statuses = Vec::new();
+        while let Ok(WsMessage::ProofJobStatus(received)) = rx.recv().await {
+            assert_eq!(received.job_id, "job-a");
+            statuses.push(received.status);
+        }
+        assert_eq!(statuses, vec!["running", "done"]);
+    }
+
     #[test]
     fn test_health_factor_calculation() {
         // $10000 담보, $5000 부채, 80% 청산 임계값
@@ -406,6 +521,30 @@ mod tests {
         assert!(matches!(get_liquidation_urgency(1.5), LiquidationUrgency::Low));
     }
 
+    #[test]
+    fn test_health_factor_bucket() {
+        // 각 구간의 하한은 get_liquidation_urgency와 같은 긴급도
+        for urgency in [
+            LiquidationUrgency::Critical,
+            LiquidationUrgency::High,
+            LiquidationUrgency::Medium,
+            LiquidationUrgency::Low,
+        ] {
+            let (lo, hi) = urgency.health_factor_bucket();
+            let at_lo = get_liquidation_urgency(lo as f64 / 10_000.0);
+            assert_eq!(
+                std::mem::discriminant(&at_lo),
+                std::mem::discriminant(&urgency)
+            );
+            assert!(matches!(
+                LiquidationUrgency::from_health_factor_bucket(lo, hi),
+                Some(ref u) if std::mem::discriminant(u) == std::mem::discriminant(&urgency)
+            ));
+        }
+
+        assert!(LiquidationUrgency::from_health_factor_bucket(10_000, 11_000).is_none());
+    }
+
     #[test]
     fn test_message_serialization() {
         let msg = WsMessage::Ping;
@@ -1,32 +1,31 @@
 //! ZK Prover Service - Real Halo2 Integration
 //!
 //! Provides ZK proof generation using Halo2 circuits for privacy-preserving
-//! collateral, LTV, and liquidation verification.
+//! collateral, LTV (single and multi-asset), liquidation and its settlement, health
+//! factor bucket, withdrawal, commitment transition, interest accrual, and protocol
+//! solvency verification.
 
 use anyhow::{Context, Result, anyhow};
+use std::collections::HashMap;
+use std::path::Path;
 use std::sync::Arc;
-use tokio::sync::RwLock;
-
-use halo2_proofs::{
-    plonk::{create_proof, keygen_pk, keygen_vk, verify_proof, ProvingKey, VerifyingKey},
-    poly::{
-        commitment::ParamsProver,
-        ipa::{
-            commitment::{IPACommitmentScheme, ParamsIPA},
-            multiopen::ProverIPA,
-            strategy::SingleStrategy,
-        },
-        VerificationStrategy,
-    },
-    transcript::{Blake2bRead, Blake2bWrite, Challenge255, TranscriptReadBuffer, TranscriptWriterBuffer},
-};
-use pasta_curves::{Fp, EqAffine};
+use tokio::sync::{Mutex, RwLock};
+
+use halo2_proofs::plonk::{keygen_pk, keygen_vk, Circuit, ProvingKey, VerifyingKey};
+use halo2_proofs::poly::commitment::Params;
 use ff::PrimeField;
-use rand::rngs::OsRng;
 
-use zk_private_lending_circuits::{CollateralCircuit, LTVCircuit, LiquidationCircuit};
+use zk_private_lending_circuits::{
+    compute_commitment, compute_nullifier, constants::COMMITMENT_TREE_DEPTH, CollateralCircuit,
+    CommitmentTransitionCircuit, HealthFactorBucketCircuit, IncrementalMerkleTree, InterestAccrualCircuit,
+    LiquidationCircuit, LiquidationSettlementCircuit, MerklePath, MultiAssetLTVCircuit, PriceLTVCircuit,
+    SolvencyCircuit, SolvencyPosition, WithdrawCircuit,
+};
 
 use crate::routes::proof::ProofData;
+use super::key_store::{vk_hash, KeyStore};
+use super::prover_executor::{ProverExecutor, ProverMetrics};
+use super::proving_backend::{Ipa, KzgGwc, KzgShplonk, ProofSystem, ProvingBackend};
 
 /// ZK Proof 생성 결과
 pub struct ProofResult {
@@ -35,6 +34,173 @@ pub struct ProofResult {
     pub commitment: String,
 }
 
+/// 증명을 생성한 회로 (`ProofData.circuit_id`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CircuitId {
+    Collateral,
+    Ltv,
+    /// 자산 수별로 회로와 키가 다름
+    MultiAssetLtv { assets: usize },
+    Liquidation,
+    LiquidationSettlement,
+    HealthFactor,
+    Withdraw,
+    Transition,
+    Interest,
+    Solvency,
+}
+
+impl std::fmt::Display for CircuitId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CircuitId::Collateral => write!(f, "collateral"),
+            CircuitId::Ltv => write!(f, "ltv"),
+            CircuitId::MultiAssetLtv { assets } => write!(f, "multi_asset_ltv_{}", assets),
+            CircuitId::Liquidation => write!(f, "liquidation"),
+            CircuitId::LiquidationSettlement => write!(f, "liquidation_settlement"),
+            CircuitId::HealthFactor => write!(f, "health_factor"),
+            CircuitId::Withdraw => write!(f, "withdraw"),
+            CircuitId::Transition => write!(f, "transition"),
+            CircuitId::Interest => write!(f, "interest"),
+            CircuitId::Solvency => write!(f, "solvency"),
+        }
+    }
+}
+
+impl std::str::FromStr for CircuitId {
+    type Err = anyhow::Error;
+
+    /// `Display` 출력의 역변환 (`multi_asset_ltv_N`은 1..=MAX_COLLATERAL_ASSETS만 허용)
+    fn from_str(s: &str) -> Result<Self> {
+        let circuit_id = match s {
+            "collateral" => CircuitId::Collateral,
+            "ltv" => CircuitId::Ltv,
+            "liquidation" => CircuitId::Liquidation,
+            "liquidation_settlement" => CircuitId::LiquidationSettlement,
+            "health_factor" => CircuitId::HealthFactor,
+            "withdraw" => CircuitId::Withdraw,
+            "transition" => CircuitId::Transition,
+            "interest" => CircuitId::Interest,
+            "solvency" => CircuitId::Solvency,
+            _ => {
+                let assets = s
+                    .strip_prefix("multi_asset_ltv_")
+                    .and_then(|n| n.parse::<usize>().ok())
+                    .filter(|n| (1..=MAX_COLLATERAL_ASSETS).contains(n))
+                    .ok_or_else(|| anyhow!("Unknown circuit id: {}", s))?;
+                CircuitId::MultiAssetLtv { assets }
+            }
+        };
+        Ok(circuit_id)
+    }
+}
+
+impl CircuitId {
+    /// 서버가 키를 관리하는 모든 회로 (다중 담보 LTV는 자산 수별로 포함)
+    pub fn all() -> Vec<CircuitId> {
+        let mut ids = vec![CircuitId::Collateral, CircuitId::Ltv];
+        ids.extend((1..=MAX_COLLATERAL_ASSETS).map(|assets| CircuitId::MultiAssetLtv { assets }));
+        ids.extend([
+            CircuitId::Liquidation,
+            CircuitId::LiquidationSettlement,
+            CircuitId::HealthFactor,
+            CircuitId::Withdraw,
+            CircuitId::Transition,
+            CircuitId::Interest,
+            CircuitId::Solvency,
+        ]);
+        ids
+    }
+}
+
+/// Proof 검증 결과
+#[derive(Debug, Clone)]
+pub struct VerificationResult {
+    pub valid: bool,
+    /// 검증에 사용한 verifying key 해시
+    pub vk_hash: String,
+}
+
+/// `ProofData.bytes` 디코딩 (0x 접두사 hex → proof transcript)
+pub fn decode_proof_bytes(bytes: &str) -> Result<Vec<u8>> {
+    hex::decode(bytes.trim_start_matches("0x"))
+        .context("Proof bytes are not valid hex")
+}
+
+/// `fp_to_hex`로 인코딩된 field element 디코딩
+pub fn parse_fp_hex<F: PrimeField>(s: &str) -> Result<F> {
+    let bytes = hex::decode(s.trim_start_matches("0x"))
+        .context("Field element is not valid hex")?;
+    let mut repr = F::Repr::default();
+    if bytes.len() != repr.as_ref().len() {
+        return Err(anyhow!("Field element must be {} bytes: {}", repr.as_ref().len(), s));
+    }
+    repr.as_mut().copy_from_slice(&bytes);
+    Option::from(F::from_repr(repr))
+        .ok_or_else(|| anyhow!("Field element is not canonical: {}", s))
+}
+
+/// `fp_to_hex`로 인코딩된 작은 정수 public input 디코딩 (HF 구간 경계 등)
+///
+/// 두 backend 모두 field element repr이 32바이트 little-endian이므로
+/// backend와 관계없이 읽을 수 있음
+pub fn parse_u64_hex(s: &str) -> Result<u64> {
+    let bytes = hex::decode(s.trim_start_matches("0x"))
+        .context("Field element is not valid hex")?;
+    if bytes.len() != 32 || bytes[8..].iter().any(|b| *b != 0) {
+        return Err(anyhow!("Public input is not a u64: {}", s));
+    }
+    let mut value = [0u8; 8];
+    value.copy_from_slice(&bytes[..8]);
+    Ok(u64::from_le_bytes(value))
+}
+
+/// 검증 요청의 public input이 backend의 field element가 아님 (`ApiError::ValidationError`로 매핑)
+#[derive(Debug, thiserror::Error)]
+#[error("Invalid public input: {0}")]
+pub struct InvalidPublicInput(pub String);
+
+/// 사용할 commitment가 등록된 commitment 집합에 없음 (`ApiError::InvalidCommitment`로 매핑)
+#[derive(Debug, thiserror::Error)]
+#[error("Commitment is not registered: {0}")]
+pub struct UnregisteredCommitment(pub String);
+
+/// 다중 담보 LTV 증명에 지원하는 최대 자산 수
+///
+/// 자산 수마다 별도의 회로(`MultiAssetLTVCircuit<F, N>`)와 키가 필요함
+pub const MAX_COLLATERAL_ASSETS: usize = 4;
+
+/// 다중 담보 LTV 증명의 담보 자산 하나
+#[derive(Debug, Clone)]
+pub struct CollateralAsset {
+    /// 담보 수량 (토큰 base unit)
+    pub amount: u128,
+    /// 오라클 가격 (8 decimals)
+    pub price: u128,
+    /// 담보 인정 비율 (%)
+    pub collateral_factor: u64,
+    /// 담보 토큰 decimals (0..=18)
+    pub decimals: u64,
+    /// 담보 commitment의 salt
+    pub salt: u128,
+}
+
+/// 솔벤시 증명의 포지션 트리 깊이 (최대 2^8 = 256개 포지션)
+pub const SOLVENCY_TREE_DEPTH: usize = 8;
+
+/// 솔벤시 증명에 포함되는 포지션 하나 (CommitmentRegistry의 담보/부채 commitment)
+#[derive(Debug, Clone)]
+pub struct CommittedPosition {
+    /// 담보 금액 (wei)
+    pub collateral: u128,
+    /// 부채 금액 (6 decimals, USDC)
+    pub debt: u128,
+    /// 담보 commitment의 salt
+    pub collateral_salt: u128,
+    /// 부채 commitment의 salt
+    pub debt_salt: u128,
+}
+
 /// Cached proving context
 ///
 /// # Design Decision
@@ -43,188 +209,250 @@ pub struct ProofResult {
 /// - keygen은 비용이 큼 (~수 초)
 /// - 동일한 회로에 대해 재사용 가능
 /// - 메모리 사용량: 각 회로당 ~50-100MB
-struct ProvingContext {
-    params: ParamsIPA<EqAffine>,
-    collateral_pk: Option<ProvingKey<EqAffine>>,
-    collateral_vk: Option<VerifyingKey<EqAffine>>,
-    ltv_pk: Option<ProvingKey<EqAffine>>,
-    ltv_vk: Option<VerifyingKey<EqAffine>>,
-    liquidation_pk: Option<ProvingKey<EqAffine>>,
-    liquidation_vk: Option<VerifyingKey<EqAffine>>,
+struct ProvingContext<B: ProofSystem> {
+    params: B::Params,
+    /// 회로별 (proving key, verifying key). 다중 담보 LTV는 자산 수마다 별도 항목
+    keys: HashMap<CircuitId, (ProvingKey<B::Curve>, VerifyingKey<B::Curve>)>,
 }
 
-/// ZK Prover 서비스
-///
-/// # Architecture
+impl<B: ProofSystem> ProvingContext<B> {
+    fn proving_key(&self, circuit_id: CircuitId) -> Option<&ProvingKey<B::Curve>> {
+        self.keys.get(&circuit_id).map(|(pk, _)| pk)
+    }
+
+    fn verifying_key(&self, circuit_id: CircuitId) -> Option<&VerifyingKey<B::Curve>> {
+        self.keys.get(&circuit_id).map(|(_, vk)| vk)
+    }
+}
+
+/// 증명 backend `B`로 회로 키를 관리하고 증명을 생성/검증하는 prover
 ///
-/// ```text
-/// ┌─────────────────────────────────────────────────────────────┐
-/// │                      ZKProver                                │
-/// ├─────────────────────────────────────────────────────────────┤
-/// │                                                             │
-/// │  ┌─────────────┐  ┌─────────────┐  ┌─────────────────────┐ │
-/// │  │ Collateral  │  │    LTV      │  │    Liquidation      │ │
-/// │  │   Circuit   │  │   Circuit   │  │      Circuit        │ │
-/// │  └──────┬──────┘  └──────┬──────┘  └──────────┬──────────┘ │
-/// │         │                │                    │             │
-/// │         v                v                    v             │
-/// │  ┌──────────────────────────────────────────────────────┐  │
-/// │  │                 ProvingContext                        │  │
-/// │  │  - Params (SRS)                                       │  │
-/// │  │  - ProvingKeys (cached)                               │  │
-/// │  │  - VerificationKeys (cached)                          │  │
-/// │  └──────────────────────────────────────────────────────┘  │
-/// │                                                             │
-/// └─────────────────────────────────────────────────────────────┘
-/// ```
-pub struct ZKProver {
+/// 회로는 `B::Scalar` field 위에서 만들어지므로 commitment, public input,
+/// verifying key 모두 backend마다 다름
+struct Prover<B: ProofSystem> {
     /// Thread-safe proving context
-    context: Arc<RwLock<ProvingContext>>,
+    context: Arc<RwLock<ProvingContext<B>>>,
     /// Circuit size parameter (k = log2(rows))
     k: u32,
+    /// SRS와 키를 저장하는 디스크 저장소 (없으면 프로세스마다 생성)
+    key_store: Option<Arc<KeyStore>>,
+    /// 같은 회로의 키를 동시에 두 번 생성하지 않도록 keygen을 직렬화
+    keygen_lock: Mutex<()>,
+    /// `create_proof`/`verify_proof`를 실행하는 blocking 스레드 풀
+    executor: ProverExecutor,
 }
 
-impl ZKProver {
-    /// 새 ZK Prover 생성
+impl<F, B> Prover<B>
+where
+    F: PrimeField,
+    B: ProofSystem<Scalar = F>,
+{
+    /// 새 prover 생성
     ///
     /// # Arguments
     ///
-    /// * `k` - Circuit size parameter (2^k rows).
-    ///         Larger k = more rows = larger circuits supported
-    ///         Typical values: 17-20 for production
+    /// * `key_store_dir` - SRS와 회로별 키를 저장하는 디렉터리.
+    ///         backend마다 하위 디렉터리(`ipa/`, `kzg-shplonk/`, ...)를 사용하며,
+    ///         없으면 SRS와 키를 프로세스마다 생성
+    ///
+    /// Circuit size parameter k = 17 (2^17 = 131,072 rows)
     ///
     /// # Performance
     ///
     /// - Params 생성: ~1-2초
     /// - 메모리 사용: ~2^k * 32 bytes
-    pub fn new() -> Result<Self> {
+    fn new(key_store_dir: Option<&Path>) -> Result<Self> {
         let k = 17; // 2^17 = 131,072 rows
 
-        tracing::info!("Initializing ZK Prover with k={}...", k);
-
-        // Generate parameters (SRS - Structured Reference String)
-        // In production, this would be loaded from a file
-        let params = ParamsIPA::<EqAffine>::new(k);
+        tracing::info!("Initializing {} ZK Prover with k={}...", B::BACKEND, k);
+
+        let (params, key_store) = match key_store_dir {
+            Some(dir) => {
+                let dir = dir.join(B::BACKEND.to_string());
+                tracing::info!("Using key store at {}", dir.display());
+                let key_store = KeyStore::open(&dir)?;
+                (key_store.load_or_create_params::<B>(k)?, Some(key_store))
+            }
+            None => {
+                // Generate parameters (SRS - Structured Reference String)
+                let params = B::setup(k);
+                tracing::info!("SRS parameters generated");
+                (params, None)
+            }
+        };
 
-        tracing::info!("SRS parameters generated");
+        Ok(Self::with_params(k, params, key_store))
+    }
 
+    fn with_params(k: u32, params: B::Params, key_store: Option<KeyStore>) -> Self {
         let context = ProvingContext {
             params,
-            collateral_pk: None,
-            collateral_vk: None,
-            ltv_pk: None,
-            ltv_vk: None,
-            liquidation_pk: None,
-            liquidation_vk: None,
+            keys: HashMap::new(),
         };
 
-        Ok(Self {
+        Self {
             context: Arc::new(RwLock::new(context)),
             k,
-        })
-    }
-
-    /// Ensures collateral circuit proving keys are initialized (lazy initialization).
-    /// Keys are generated on first proof request and cached for reuse.
-    async fn ensure_collateral_keys(&self) -> Result<()> {
-        let read_guard = self.context.read().await;
-        if read_guard.collateral_pk.is_some() {
-            return Ok(());
+            key_store: key_store.map(Arc::new),
+            keygen_lock: Mutex::new(()),
+            executor: ProverExecutor::default(),
         }
-        drop(read_guard);
+    }
 
-        tracing::info!("Generating CollateralCircuit keys...");
+    fn with_executor(mut self, executor: ProverExecutor) -> Self {
+        self.executor = executor;
+        self
+    }
 
-        let mut write_guard = self.context.write().await;
+    /// 증명 실행기 상태와 대기 시간 통계
+    fn prover_metrics(&self) -> ProverMetrics {
+        self.executor.metrics()
+    }
 
-        // Double-check after acquiring write lock
-        if write_guard.collateral_pk.is_some() {
-            return Ok(());
+    /// 모든 회로의 키를 준비 (키 저장소가 있으면 디스크에서 로드)
+    ///
+    /// 서버 시작 시 호출하면 첫 증명 요청에서 keygen 지연이 생기지 않음
+    async fn preload_keys(&self) -> Result<()> {
+        for circuit_id in CircuitId::all() {
+            self.ensure_keys_for(circuit_id).await?;
         }
+        Ok(())
+    }
 
-        let empty_circuit = CollateralCircuit::<Fp>::default();
+    /// 준비된 verifying key의 해시 목록 (circuit id, vk hash), `CircuitId::all()` 순서
+    ///
+    /// 온체인 verifier registry에 등록된 vk와 대조하는 데 사용
+    async fn verifying_key_hashes(&self) -> Vec<(String, String)> {
+        let context = self.context.read().await;
 
-        let vk = keygen_vk(&write_guard.params, &empty_circuit)
-            .context("Failed to generate verification key")?;
+        CircuitId::all()
+            .into_iter()
+            .filter_map(|id| context.verifying_key(id).map(|vk| (id.to_string(), vk_hash(vk))))
+            .collect()
+    }
 
-        let pk = keygen_pk(&write_guard.params, vk.clone(), &empty_circuit)
-            .context("Failed to generate proving key")?;
+    /// SRS 직렬화 (`Params::write` 형식, 키 저장소의 `params_k{k}.bin`과 같음)
+    ///
+    /// 브라우저에서 증명하는 클라이언트는 이 SRS로 proving key를 만들므로
+    /// 서버의 verifying key로 검증되는 증명을 생성할 수 있음
+    async fn params_bytes(&self) -> Result<Vec<u8>> {
+        let context = self.context.read().await;
+        let mut bytes = Vec::new();
+        context.params.write(&mut bytes).context("Failed to serialize SRS parameters")?;
+        Ok(bytes)
+    }
 
-        write_guard.collateral_vk = Some(vk);
-        write_guard.collateral_pk = Some(pk);
+    /// 증명 생성
+    ///
+    /// `create_proof`는 수 초 걸리는 CPU 작업이므로 실행기의 blocking 스레드에서
+    /// 실행. 실행기가 가득 차 있으면 `ProverBusy` 에러를 반환
+    async fn prove<C: Circuit<F> + Send + 'static>(
+        &self,
+        circuit_id: CircuitId,
+        circuit: C,
+        public_inputs: &[F],
+    ) -> Result<ProofData> {
+        let context = self.context.clone();
+        let public_inputs = public_inputs.to_vec();
+
+        self.executor.run(circuit_id, move || {
+            let context = context.blocking_read();
+            let pk = context.proving_key(circuit_id)
+                .ok_or_else(|| anyhow!("{} proving key not initialized", circuit_id))?;
+
+            let proof = B::create_proof(&context.params, pk, circuit, &public_inputs)
+                .with_context(|| format!("Failed to create {} proof", circuit_id))?;
+
+            Ok(Self::encode_proof(circuit_id, &proof, pk.get_vk()))
+        }).await
+    }
 
-        tracing::info!("CollateralCircuit keys generated successfully");
-        Ok(())
+    /// 회로 id에 해당하는 키 준비
+    async fn ensure_keys_for(&self, circuit_id: CircuitId) -> Result<()> {
+        match circuit_id {
+            CircuitId::Collateral => self.ensure_keys::<CollateralCircuit<F>>(circuit_id).await,
+            CircuitId::Ltv => self.ensure_keys::<PriceLTVCircuit<F>>(circuit_id).await,
+            CircuitId::MultiAssetLtv { assets: 1 } => self.ensure_keys::<MultiAssetLTVCircuit<F, 1>>(circuit_id).await,
+            CircuitId::MultiAssetLtv { assets: 2 } => self.ensure_keys::<MultiAssetLTVCircuit<F, 2>>(circuit_id).await,
+            CircuitId::MultiAssetLtv { assets: 3 } => self.ensure_keys::<MultiAssetLTVCircuit<F, 3>>(circuit_id).await,
+            CircuitId::MultiAssetLtv { assets: 4 } => self.ensure_keys::<MultiAssetLTVCircuit<F, 4>>(circuit_id).await,
+            CircuitId::MultiAssetLtv { assets } => Err(anyhow!(
+                "Unsupported number of collateral assets: {} (1..={})",
+                assets, MAX_COLLATERAL_ASSETS
+            )),
+            CircuitId::Liquidation => self.ensure_keys::<LiquidationCircuit<F>>(circuit_id).await,
+            CircuitId::LiquidationSettlement => self.ensure_keys::<LiquidationSettlementCircuit<F>>(circuit_id).await,
+            CircuitId::HealthFactor => self.ensure_keys::<HealthFactorBucketCircuit<F>>(circuit_id).await,
+            CircuitId::Withdraw => self.ensure_keys::<WithdrawCircuit<F>>(circuit_id).await,
+            CircuitId::Transition => self.ensure_keys::<CommitmentTransitionCircuit<F>>(circuit_id).await,
+            CircuitId::Interest => self.ensure_keys::<InterestAccrualCircuit<F>>(circuit_id).await,
+            CircuitId::Solvency => self.ensure_keys::<SolvencyCircuit<F, SOLVENCY_TREE_DEPTH>>(circuit_id).await,
+        }
     }
 
-    async fn ensure_ltv_keys(&self) -> Result<()> {
-        let read_guard = self.context.read().await;
-        if read_guard.ltv_pk.is_some() {
+    /// 회로 `C`의 키가 준비되어 있는지 확인 (lazy initialization)
+    ///
+    /// 첫 증명/검증 요청에서 생성해 `circuit_id`로 캐시하며, 동시에 같은 키를
+    /// 두 번 생성하지 않도록 keygen lock을 잡은 뒤 다시 확인
+    async fn ensure_keys<C: Circuit<F> + Default + 'static>(&self, circuit_id: CircuitId) -> Result<()> {
+        if self.context.read().await.keys.contains_key(&circuit_id) {
             return Ok(());
         }
-        drop(read_guard);
-
-        tracing::info!("Generating LTVCircuit keys...");
 
-        let mut write_guard = self.context.write().await;
+        let _keygen = self.keygen_lock.lock().await;
 
-        if write_guard.ltv_pk.is_some() {
+        // Double-check after acquiring keygen lock
+        if self.context.read().await.keys.contains_key(&circuit_id) {
             return Ok(());
         }
 
-        let empty_circuit = LTVCircuit::<Fp>::default();
+        tracing::info!("Generating {} circuit keys...", circuit_id);
 
-        let vk = keygen_vk(&write_guard.params, &empty_circuit)
-            .context("Failed to generate LTV verification key")?;
+        let (vk, pk) = self.generate_keys::<C>(circuit_id).await?;
+        self.context.write().await.keys.insert(circuit_id, (pk, vk));
 
-        let pk = keygen_pk(&write_guard.params, vk.clone(), &empty_circuit)
-            .context("Failed to generate LTV proving key")?;
-
-        write_guard.ltv_vk = Some(vk);
-        write_guard.ltv_pk = Some(pk);
-
-        tracing::info!("LTVCircuit keys generated successfully");
+        tracing::info!("{} circuit keys generated successfully", circuit_id);
         Ok(())
     }
 
-    async fn ensure_liquidation_keys(&self) -> Result<()> {
-        let read_guard = self.context.read().await;
-        if read_guard.liquidation_pk.is_some() {
-            return Ok(());
-        }
-        drop(read_guard);
-
-        tracing::info!("Generating LiquidationCircuit keys...");
-
-        let mut write_guard = self.context.write().await;
-
-        if write_guard.liquidation_pk.is_some() {
-            return Ok(());
-        }
+    /// 회로 키 생성 (키 저장소가 있으면 저장된 키를 로드하거나 생성 후 저장)
+    ///
+    /// keygen과 키 파일 I/O는 수 초 걸리므로 `prove`처럼 실행기의 blocking
+    /// 스레드에서 실행. context는 읽기 잠금만 잡으므로 그동안 다른 회로의
+    /// 증명과 검증을 막지 않음
+    async fn generate_keys<C: Circuit<F> + Default + 'static>(
+        &self,
+        circuit_id: CircuitId,
+    ) -> Result<(VerifyingKey<B::Curve>, ProvingKey<B::Curve>)> {
+        let context = self.context.clone();
+        let key_store = self.key_store.clone();
 
-        let empty_circuit = LiquidationCircuit::<Fp>::default();
+        self.executor.run(format!("{} keygen", circuit_id), move || {
+            let context = context.blocking_read();
+            let empty_circuit = C::default();
 
-        let vk = keygen_vk(&write_guard.params, &empty_circuit)
-            .context("Failed to generate Liquidation verification key")?;
+            if let Some(key_store) = &key_store {
+                return key_store.load_or_generate::<B, C>(&context.params, circuit_id, &empty_circuit);
+            }
 
-        let pk = keygen_pk(&write_guard.params, vk.clone(), &empty_circuit)
-            .context("Failed to generate Liquidation proving key")?;
+            let vk = keygen_vk(&context.params, &empty_circuit)
+                .with_context(|| format!("Failed to generate {} verification key", circuit_id))?;
 
-        write_guard.liquidation_vk = Some(vk);
-        write_guard.liquidation_pk = Some(pk);
+            let pk = keygen_pk(&context.params, vk.clone(), &empty_circuit)
+                .with_context(|| format!("Failed to generate {} proving key", circuit_id))?;
 
-        tracing::info!("LiquidationCircuit keys generated successfully");
-        Ok(())
+            Ok((vk, pk))
+        }).await
     }
 
     /// Computes a cryptographic commitment for the given value and salt.
     /// Uses Poseidon hash for ZK-friendly commitment computation.
-    pub fn compute_commitment(&self, value: u128, salt: u128) -> Result<Vec<u8>> {
-        let value_fp = Fp::from_u128(value);
-        let salt_fp = Fp::from_u128(salt);
+    fn compute_commitment(&self, value: u128, salt: u128) -> Result<Vec<u8>> {
+        let value_fp = F::from_u128(value);
+        let salt_fp = F::from_u128(salt);
 
         // Use the same formula as the circuit
-        let commitment = CollateralCircuit::<Fp>::compute_commitment(value_fp, salt_fp);
+        let commitment = compute_commitment(value_fp, salt_fp);
 
         // Convert to bytes
         Ok(commitment.to_repr().as_ref().to_vec())
@@ -247,7 +475,7 @@ impl ZKProver {
     ///
     /// - 첫 번째 호출: ~5초 (keygen 포함)
     /// - 이후 호출: ~1-2초 (proving only)
-    pub async fn generate_collateral_proof(
+    async fn generate_collateral_proof(
         &self,
         collateral: u128,
         threshold: u128,
@@ -259,15 +487,15 @@ impl ZKProver {
         );
 
         // Ensure proving key is ready
-        self.ensure_collateral_keys().await?;
+        self.ensure_keys::<CollateralCircuit<F>>(CircuitId::Collateral).await?;
 
         // Convert to field elements
-        let collateral_fp = Fp::from_u128(collateral);
-        let salt_fp = Fp::from_u128(salt);
-        let threshold_fp = Fp::from_u128(threshold);
+        let collateral_fp = F::from_u128(collateral);
+        let salt_fp = F::from_u128(salt);
+        let threshold_fp = F::from_u128(threshold);
 
         // Compute commitment
-        let commitment = CollateralCircuit::<Fp>::compute_commitment(collateral_fp, salt_fp);
+        let commitment = compute_commitment(collateral_fp, salt_fp);
 
         // Create circuit instance
         let circuit = CollateralCircuit::new(collateral_fp, salt_fp, threshold_fp, commitment);
@@ -276,34 +504,7 @@ impl ZKProver {
         let public_inputs = vec![threshold_fp, commitment];
 
         // Generate proof
-        let proof_bytes = {
-            let context = self.context.read().await;
-            let pk = context.collateral_pk.as_ref()
-                .ok_or_else(|| anyhow!("Proving key not initialized"))?;
-
-            let mut transcript = Blake2bWrite::<Vec<u8>, EqAffine, Challenge255<EqAffine>>::init(vec![]);
-
-            create_proof::<
-                IPACommitmentScheme<EqAffine>,
-                ProverIPA<'_, EqAffine>,
-                _,
-                _,
-                _,
-                _,
-            >(
-                &context.params,
-                pk,
-                &[circuit],
-                &[&[&public_inputs]],
-                OsRng,
-                &mut transcript,
-            ).context("Failed to create proof")?;
-
-            transcript.finalize()
-        };
-
-        // Convert to Solidity-compatible format
-        let proof = self.serialize_proof_to_groth16(&proof_bytes);
+        let proof = self.prove(CircuitId::Collateral, circuit, &public_inputs).await?;
 
         let commitment_hex = self.fp_to_hex(commitment);
 
@@ -321,303 +522,1173 @@ impl ZKProver {
     ///
     /// # Circuit Logic
     ///
-    /// Proves: debt/collateral <= max_ltv/100
-    /// Without division: debt * 100 <= collateral * max_ltv
-    pub async fn generate_ltv_proof(
+    /// ```text
+    /// Private inputs: collateral, debt, salts
+    /// Public inputs: max_ltv, price, collateral_decimals, debt_decimals,
+    ///                debt_commitment, collateral_commitment
+    ///
+    /// Proves: debt_usd / collateral_usd <= max_ltv / 100
+    /// Without division: debt * 100 * 1e8 * 10^(cd - dd) <= collateral * price * max_ltv
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    async fn generate_ltv_proof(
         &self,
         collateral: u128,
         debt: u128,
         max_ltv: u64,
+        price: u128,
+        collateral_decimals: u64,
+        debt_decimals: u64,
         collateral_salt: u128,
         debt_salt: u128,
     ) -> Result<ProofResult> {
         tracing::info!(
-            "Generating LTV proof: collateral={}, debt={}, max_ltv={}%",
-            collateral, debt, max_ltv
+            "Generating LTV proof: collateral={}, debt={}, max_ltv={}%, price={}",
+            collateral, debt, max_ltv, price
         );
 
-        self.ensure_ltv_keys().await?;
+        self.ensure_keys::<PriceLTVCircuit<F>>(CircuitId::Ltv).await?;
 
-        let collateral_fp = Fp::from_u128(collateral);
-        let debt_fp = Fp::from_u128(debt);
-        let max_ltv_fp = Fp::from(max_ltv);
-        let collateral_salt_fp = Fp::from_u128(collateral_salt);
-        let debt_salt_fp = Fp::from_u128(debt_salt);
+        let collateral_fp = F::from_u128(collateral);
+        let debt_fp = F::from_u128(debt);
+        let max_ltv_fp = F::from(max_ltv);
+        let price_fp = F::from_u128(price);
+        let collateral_salt_fp = F::from_u128(collateral_salt);
+        let debt_salt_fp = F::from_u128(debt_salt);
 
-        // Compute commitments using the circuit formula
-        let debt_commitment = LTVCircuit::<Fp>::compute_commitment(debt_fp, debt_salt_fp);
-        let collateral_commitment = LTVCircuit::<Fp>::compute_commitment(collateral_fp, collateral_salt_fp);
+        // Poseidon commitments, matching the circuit
+        let debt_commitment = compute_commitment(debt_fp, debt_salt_fp);
+        let collateral_commitment = compute_commitment(collateral_fp, collateral_salt_fp);
 
-        // Create circuit with correct argument order: (debt, collateral, salt_d, salt_c, max_ltv)
-        let circuit = LTVCircuit::new(
+        let circuit = PriceLTVCircuit::new(
             debt_fp,
             collateral_fp,
             debt_salt_fp,
             collateral_salt_fp,
             max_ltv_fp,
+            price_fp,
+            collateral_decimals,
+            debt_decimals,
         );
 
-        let public_inputs = vec![max_ltv_fp, debt_commitment, collateral_commitment];
-
-        let proof_bytes = {
-            let context = self.context.read().await;
-            let pk = context.ltv_pk.as_ref()
-                .ok_or_else(|| anyhow!("LTV proving key not initialized"))?;
-
-            let mut transcript = Blake2bWrite::<Vec<u8>, EqAffine, Challenge255<EqAffine>>::init(vec![]);
-
-            create_proof::<
-                IPACommitmentScheme<EqAffine>,
-                ProverIPA<'_, EqAffine>,
-                _,
-                _,
-                _,
-                _,
-            >(
-                &context.params,
-                pk,
-                &[circuit],
-                &[&[&public_inputs]],
-                OsRng,
-                &mut transcript,
-            ).context("Failed to create LTV proof")?;
-
-            transcript.finalize()
-        };
+        let public_inputs = vec![
+            max_ltv_fp,
+            price_fp,
+            F::from(collateral_decimals),
+            F::from(debt_decimals),
+            debt_commitment,
+            collateral_commitment,
+        ];
 
-        let proof = self.serialize_proof_to_groth16(&proof_bytes);
+        let proof = self.prove(CircuitId::Ltv, circuit, &public_inputs).await?;
 
         Ok(ProofResult {
             proof,
-            public_inputs: vec![
-                self.fp_to_hex(max_ltv_fp),
-                self.fp_to_hex(debt_commitment),
-                self.fp_to_hex(collateral_commitment),
-            ],
+            public_inputs: public_inputs.iter().map(|fp| self.fp_to_hex(*fp)).collect(),
             commitment: self.fp_to_hex(collateral_commitment),
         })
     }
 
+    /// 다중 담보 LTV 증명 생성
+    ///
+    /// # Circuit Logic
+    ///
+    /// ```text
+    /// Private inputs: amount_i, salt_i, debt, debt_salt
+    /// Public inputs: (commitment_i, price_i, cf_i, decimals_i) for each asset,
+    ///                debt_commitment, debt_decimals
+    ///
+    /// Constraints:
+    /// 1. sum(amount_i * 10^(18 - decimals_i) * price_i * cf_i)
+    ///      >= debt * 10^(18 - debt_decimals) * 100 * 1e8
+    /// 2. 0 < price_i < 2^64, 0 < cf_i <= 100, decimals <= 18
+    /// 3. 모든 commitment == Poseidon(value, salt)
+    /// ```
+    ///
+    /// 수량은 각 토큰의 base unit 그대로 commit되고, 회로가 public decimals로 18 decimals에
+    /// 맞춰 비교합니다.
+    ///
+    /// 자산 수(1..=MAX_COLLATERAL_ASSETS)에 맞는 회로를 선택하며,
+    /// 반환되는 `commitment`는 부채 commitment입니다.
+    async fn generate_multi_asset_ltv_proof(
+        &self,
+        assets: &[CollateralAsset],
+        debt: u128,
+        debt_salt: u128,
+        debt_decimals: u64,
+    ) -> Result<ProofResult> {
+        tracing::info!(
+            "Generating multi-asset LTV proof: assets={}, debt={}",
+            assets.len(), debt
+        );
+
+        match assets.len() {
+            1 => self.prove_multi_asset_ltv::<1>(assets, debt, debt_salt, debt_decimals).await,
+            2 => self.prove_multi_asset_ltv::<2>(assets, debt, debt_salt, debt_decimals).await,
+            3 => self.prove_multi_asset_ltv::<3>(assets, debt, debt_salt, debt_decimals).await,
+            4 => self.prove_multi_asset_ltv::<4>(assets, debt, debt_salt, debt_decimals).await,
+            n => Err(anyhow!(
+                "Unsupported number of collateral assets: {} (1..={})",
+                n, MAX_COLLATERAL_ASSETS
+            )),
+        }
+    }
+
+    async fn prove_multi_asset_ltv<const N: usize>(
+        &self,
+        assets: &[CollateralAsset],
+        debt: u128,
+        debt_salt: u128,
+        debt_decimals: u64,
+    ) -> Result<ProofResult> {
+        self.ensure_keys::<MultiAssetLTVCircuit<F, N>>(CircuitId::MultiAssetLtv { assets: N }).await?;
+
+        let amounts: [F; N] = std::array::from_fn(|i| F::from_u128(assets[i].amount));
+        let salts: [F; N] = std::array::from_fn(|i| F::from_u128(assets[i].salt));
+        let prices: [F; N] = std::array::from_fn(|i| F::from_u128(assets[i].price));
+        let collateral_factors: [F; N] = std::array::from_fn(|i| F::from(assets[i].collateral_factor));
+        let decimals: [u64; N] = std::array::from_fn(|i| assets[i].decimals);
+        let debt_fp = F::from_u128(debt);
+        let debt_salt_fp = F::from_u128(debt_salt);
+
+        let collateral_commitments: [F; N] = std::array::from_fn(|i| compute_commitment(amounts[i], salts[i]));
+        let debt_commitment = compute_commitment(debt_fp, debt_salt_fp);
+
+        let circuit = MultiAssetLTVCircuit::new(
+            amounts,
+            salts,
+            prices,
+            collateral_factors,
+            decimals,
+            debt_fp,
+            debt_salt_fp,
+            debt_decimals,
+        );
+
+        let public_inputs = MultiAssetLTVCircuit::<F, N>::public_inputs(
+            collateral_commitments,
+            prices,
+            collateral_factors,
+            decimals,
+            debt_commitment,
+            debt_decimals,
+        );
+
+        let proof = self.prove(CircuitId::MultiAssetLtv { assets: N }, circuit, &public_inputs).await?;
+
+        Ok(ProofResult {
+            proof,
+            public_inputs: public_inputs.iter().map(|fp| self.fp_to_hex(*fp)).collect(),
+            commitment: self.fp_to_hex(debt_commitment),
+        })
+    }
+
     /// 청산 가능 증명 생성
     ///
     /// # Circuit Logic
     ///
     /// Proves: health_factor < 1.0
     /// = (collateral * price * liq_threshold) < (debt * 100 * 1e8)
-    pub async fn generate_liquidation_proof(
+    ///
+    /// Public inputs의 담보/부채 commitment는 CollateralCircuit / LTVCircuit와
+    /// 같은 Poseidon(value, salt)이므로 CommitmentRegistry의 포지션과 대조할 수 있습니다.
+    /// 반환되는 `commitment`는 담보 commitment입니다.
+    async fn generate_liquidation_proof(
         &self,
         collateral: u128,
         debt: u128,
         price: u128,
         liquidation_threshold: u64,
-        salt: u128,
+        collateral_salt: u128,
+        debt_salt: u128,
     ) -> Result<ProofResult> {
         tracing::info!(
             "Generating liquidation proof: collateral={}, debt={}, price={}",
             collateral, debt, price
         );
 
-        self.ensure_liquidation_keys().await?;
+        self.ensure_keys::<LiquidationCircuit<F>>(CircuitId::Liquidation).await?;
 
-        let collateral_fp = Fp::from_u128(collateral);
-        let debt_fp = Fp::from_u128(debt);
-        let price_fp = Fp::from_u128(price);
-        let liq_threshold_fp = Fp::from(liquidation_threshold);
-        let salt_fp = Fp::from_u128(salt);
+        let collateral_fp = F::from_u128(collateral);
+        let debt_fp = F::from_u128(debt);
+        let price_fp = F::from_u128(price);
+        let liq_threshold_fp = F::from(liquidation_threshold);
+        let collateral_salt_fp = F::from_u128(collateral_salt);
+        let debt_salt_fp = F::from_u128(debt_salt);
 
-        // Compute position hash using the circuit formula
-        let position_hash = LiquidationCircuit::<Fp>::compute_position_hash(
-            collateral_fp,
-            debt_fp,
-            salt_fp,
-        );
+        let collateral_commitment = compute_commitment(collateral_fp, collateral_salt_fp);
+        let debt_commitment = compute_commitment(debt_fp, debt_salt_fp);
 
         let circuit = LiquidationCircuit::new(
             collateral_fp,
             debt_fp,
-            salt_fp,
+            collateral_salt_fp,
+            debt_salt_fp,
             price_fp,
             liq_threshold_fp,
         );
 
-        let public_inputs = vec![price_fp, liq_threshold_fp, position_hash];
-
-        let proof_bytes = {
-            let context = self.context.read().await;
-            let pk = context.liquidation_pk.as_ref()
-                .ok_or_else(|| anyhow!("Liquidation proving key not initialized"))?;
-
-            let mut transcript = Blake2bWrite::<Vec<u8>, EqAffine, Challenge255<EqAffine>>::init(vec![]);
-
-            create_proof::<
-                IPACommitmentScheme<EqAffine>,
-                ProverIPA<'_, EqAffine>,
-                _,
-                _,
-                _,
-                _,
-            >(
-                &context.params,
-                pk,
-                &[circuit],
-                &[&[&public_inputs]],
-                OsRng,
-                &mut transcript,
-            ).context("Failed to create liquidation proof")?;
-
-            transcript.finalize()
-        };
+        let public_inputs = vec![price_fp, liq_threshold_fp, collateral_commitment, debt_commitment];
 
-        let proof = self.serialize_proof_to_groth16(&proof_bytes);
+        let proof = self.prove(CircuitId::Liquidation, circuit, &public_inputs).await?;
 
         Ok(ProofResult {
             proof,
-            public_inputs: vec![
-                self.fp_to_hex(price_fp),
-                self.fp_to_hex(liq_threshold_fp),
-                self.fp_to_hex(position_hash),
-            ],
-            commitment: self.fp_to_hex(position_hash),
+            public_inputs: public_inputs.iter().map(|fp| self.fp_to_hex(*fp)).collect(),
+            commitment: self.fp_to_hex(collateral_commitment),
         })
     }
 
-    /// Field element를 hex 문자열로 변환
-    fn fp_to_hex(&self, fp: Fp) -> String {
-        let bytes = fp.to_repr();
-        format!("0x{}", hex::encode(bytes.as_ref()))
-    }
-
-    /// Converts Halo2 proof to Groth16-style format for EVM compatibility.
-    fn serialize_proof_to_groth16(&self, proof_bytes: &[u8]) -> ProofData {
-        // For demonstration, we'll create a mock Groth16-style proof
-        // In production, you would use a proper conversion or a different approach
-
-        // Take portions of the Halo2 proof to create Groth16-style elements
-        let len = proof_bytes.len();
+    /// 청산 정산 증명 생성 (상환 가능 부채, 압류 담보)
+    ///
+    /// # Circuit Logic
+    ///
+    /// ```text
+    /// Private inputs: collateral, debt, 기존/새 담보·부채 salt
+    /// Public inputs: price, close_factor, bonus, repay, seized,
+    ///                collateral_commitment, debt_commitment,
+    ///                new_collateral_commitment, new_debt_commitment
+    ///
+    /// Constraints (ZKLendingPool.liquidate와 동일, 내림):
+    /// 1. repay * 100 <= debt * close_factor
+    /// 2. seized = repay * (100 + bonus) / 100 * 1e20 / price
+    /// 3. new_commitment = Poseidon(collateral - seized / debt - repay, new_salt)
+    /// ```
+    ///
+    /// 반환되는 `commitment`는 청산 후 새 담보 commitment이고,
+    /// 압류 담보량(`seized`)은 다섯 번째 public input입니다.
+    #[allow(clippy::too_many_arguments)]
+    async fn generate_liquidation_settlement_proof(
+        &self,
+        collateral: u128,
+        debt: u128,
+        price: u64,
+        close_factor: u64,
+        bonus: u64,
+        repay: u128,
+        collateral_salt: u128,
+        debt_salt: u128,
+        new_collateral_salt: u128,
+        new_debt_salt: u128,
+    ) -> Result<ProofResult> {
+        tracing::info!(
+            "Generating liquidation settlement proof: repay={}, close_factor={}, bonus={}",
+            repay, close_factor, bonus
+        );
 
-        // Create G1 point A (64 bytes = 2 x 32-byte coordinates)
-        let a_x = if len >= 32 { &proof_bytes[0..32] } else { &[0u8; 32] };
-        let a_y = if len >= 64 { &proof_bytes[32..64] } else { &[0u8; 32] };
+        let seized = LiquidationSettlementCircuit::<F>::compute_seized(repay, bonus, price)
+            .ok_or_else(|| anyhow!("Seized collateral overflows u128"))?;
+        let new_collateral = collateral
+            .checked_sub(seized)
+            .ok_or_else(|| anyhow!("Seized collateral exceeds the position"))?;
+        let new_debt = debt
+            .checked_sub(repay)
+            .ok_or_else(|| anyhow!("Repayment exceeds the debt"))?;
+
+        self.ensure_keys::<LiquidationSettlementCircuit<F>>(CircuitId::LiquidationSettlement).await?;
+
+        let collateral_salt_fp = F::from_u128(collateral_salt);
+        let debt_salt_fp = F::from_u128(debt_salt);
+        let new_collateral_salt_fp = F::from_u128(new_collateral_salt);
+        let new_debt_salt_fp = F::from_u128(new_debt_salt);
+
+        let collateral_commitment = compute_commitment(F::from_u128(collateral), collateral_salt_fp);
+        let debt_commitment = compute_commitment(F::from_u128(debt), debt_salt_fp);
+        let new_collateral_commitment = compute_commitment(F::from_u128(new_collateral), new_collateral_salt_fp);
+        let new_debt_commitment = compute_commitment(F::from_u128(new_debt), new_debt_salt_fp);
+
+        let circuit = LiquidationSettlementCircuit::new(
+            collateral,
+            debt,
+            collateral_salt_fp,
+            debt_salt_fp,
+            new_collateral_salt_fp,
+            new_debt_salt_fp,
+            price,
+            close_factor,
+            bonus,
+            repay,
+        );
 
-        // Create G2 point B (128 bytes = 2 x 2 x 32-byte coordinates)
-        let b_x1 = if len >= 96 { &proof_bytes[64..96] } else { &[0u8; 32] };
-        let b_x2 = if len >= 128 { &proof_bytes[96..128] } else { &[0u8; 32] };
-        let b_y1 = if len >= 160 { &proof_bytes[128..160] } else { &[0u8; 32] };
-        let b_y2 = if len >= 192 { &proof_bytes[160..192] } else { &[0u8; 32] };
+        let public_inputs = vec![
+            F::from(price),
+            F::from(close_factor),
+            F::from(bonus),
+            F::from_u128(repay),
+            F::from_u128(seized),
+            collateral_commitment,
+            debt_commitment,
+            new_collateral_commitment,
+            new_debt_commitment,
+        ];
 
-        // Create G1 point C
-        let c_x = if len >= 224 { &proof_bytes[192..224] } else { &[0u8; 32] };
-        let c_y = if len >= 256 { &proof_bytes[224..256] } else { &[0u8; 32] };
+        let proof = self.prove(CircuitId::LiquidationSettlement, circuit, &public_inputs).await?;
 
-        ProofData {
-            a: [
-                format!("0x{}", hex::encode(a_x)),
-                format!("0x{}", hex::encode(a_y)),
-            ],
-            b: [
-                [
-                    format!("0x{}", hex::encode(b_x1)),
-                    format!("0x{}", hex::encode(b_x2)),
-                ],
-                [
-                    format!("0x{}", hex::encode(b_y1)),
-                    format!("0x{}", hex::encode(b_y2)),
-                ],
-            ],
-            c: [
-                format!("0x{}", hex::encode(c_x)),
-                format!("0x{}", hex::encode(c_y)),
-            ],
-        }
+        Ok(ProofResult {
+            proof,
+            public_inputs: public_inputs.iter().map(|fp| self.fp_to_hex(*fp)).collect(),
+            commitment: self.fp_to_hex(new_collateral_commitment),
+        })
     }
 
-    /// Proof 검증 (선택적)
+    /// Health factor 구간 증명 생성 (정확한 HF를 공개하지 않는 청산 경고용)
     ///
-    /// 서버에서 proof를 반환하기 전에 검증할 수 있음
-    /// 클라이언트가 잘못된 proof를 받지 않도록 보장
-    #[allow(dead_code)]
-    async fn verify_collateral_proof(
+    /// # Circuit Logic
+    ///
+    /// ```text
+    /// Private inputs: collateral, debt, collateral_salt, debt_salt
+    /// Public inputs: lo, hi, price, liquidation_threshold,
+    ///                collateral_commitment, debt_commitment
+    ///
+    /// Proves: lo <= HF < hi (basis points, 1.0 = 10000)
+    /// = lo * debt * 1e10 <= collateral * price * liq_threshold * 1e4 < hi * debt * 1e10
+    /// ```
+    ///
+    /// 반환되는 `commitment`는 담보 commitment입니다.
+    #[allow(clippy::too_many_arguments)]
+    async fn generate_health_factor_proof(
         &self,
-        proof_bytes: &[u8],
-        public_inputs: &[Fp],
-    ) -> Result<bool> {
-        let context = self.context.read().await;
-        let vk = context.collateral_vk.as_ref()
-            .ok_or_else(|| anyhow!("Verification key not initialized"))?;
-
-        let mut transcript = Blake2bRead::<_, _, Challenge255<_>>::init(proof_bytes);
-
-        let strategy = SingleStrategy::new(&context.params);
-        let instances: &[&[Fp]] = &[public_inputs];
-        let result = verify_proof::<_, _, _, _, _>(
-            &context.params,
-            vk,
-            strategy,
-            &[instances],
-            &mut transcript,
+        collateral: u128,
+        debt: u128,
+        price: u128,
+        liquidation_threshold: u64,
+        lo: u64,
+        hi: u64,
+        collateral_salt: u128,
+        debt_salt: u128,
+    ) -> Result<ProofResult> {
+        tracing::info!(
+            "Generating health factor proof: bucket=[{}, {}), price={}",
+            lo, hi, price
         );
 
-        Ok(result.is_ok())
-    }
-}
+        self.ensure_keys::<HealthFactorBucketCircuit<F>>(CircuitId::HealthFactor).await?;
 
-/// Mock ZKProver for testing (no actual Halo2)
-#[cfg(test)]
-pub struct MockZKProver;
+        let collateral_fp = F::from_u128(collateral);
+        let debt_fp = F::from_u128(debt);
+        let price_fp = F::from_u128(price);
+        let liq_threshold_fp = F::from(liquidation_threshold);
+        let lo_fp = F::from(lo);
+        let hi_fp = F::from(hi);
+        let collateral_salt_fp = F::from_u128(collateral_salt);
+        let debt_salt_fp = F::from_u128(debt_salt);
 
-#[cfg(test)]
-impl MockZKProver {
-    pub fn new() -> Result<Self> {
-        Ok(Self)
-    }
+        let collateral_commitment = compute_commitment(collateral_fp, collateral_salt_fp);
+        let debt_commitment = compute_commitment(debt_fp, debt_salt_fp);
 
-    pub fn compute_commitment(&self, value: u128, salt: u128) -> Vec<u8> {
-        use sha3::{Keccak256, Digest};
-        let mut hasher = Keccak256::new();
-        hasher.update(value.to_le_bytes());
-        hasher.update(salt.to_le_bytes());
-        hasher.finalize().to_vec()
-    }
+        let circuit = HealthFactorBucketCircuit::new(
+            collateral_fp,
+            debt_fp,
+            collateral_salt_fp,
+            debt_salt_fp,
+            price_fp,
+            liq_threshold_fp,
+            lo_fp,
+            hi_fp,
+        );
 
-    pub async fn generate_collateral_proof(
-        &self,
-        collateral: u128,
-        threshold: u128,
-        salt: u128,
-    ) -> Result<ProofResult> {
-        let commitment = self.compute_commitment(collateral, salt);
-        let commitment_hex = format!("0x{}", hex::encode(&commitment));
+        let public_inputs = vec![
+            lo_fp,
+            hi_fp,
+            price_fp,
+            liq_threshold_fp,
+            collateral_commitment,
+            debt_commitment,
+        ];
+
+        let proof = self.prove(CircuitId::HealthFactor, circuit, &public_inputs).await?;
 
         Ok(ProofResult {
-            proof: self.mock_proof(),
-            public_inputs: vec![
-                format!("0x{:064x}", threshold),
-                commitment_hex.clone(),
-            ],
+            proof,
+            public_inputs: public_inputs.iter().map(|fp| self.fp_to_hex(*fp)).collect(),
+            commitment: self.fp_to_hex(collateral_commitment),
+        })
+    }
+
+    /// 담보 인출 증명 생성
+    ///
+    /// # Circuit Logic
+    ///
+    /// ```text
+    /// Private inputs: old_collateral, debt, salts, old_commitment의 Merkle 경로
+    /// Public inputs: commitment_root, amount, new_commitment, debt_commitment, max_ltv, nullifier
+    ///
+    /// Constraints:
+    /// 1. new_collateral = old_collateral - amount, new_collateral >= 0
+    /// 2. debt * 100 <= new_collateral * max_ltv
+    /// 3. 모든 commitment == Poseidon(value, salt)
+    /// 4. old_commitment가 commitment_root 트리에 포함됨
+    /// 5. nullifier == Poseidon(old_salt, old_commitment)
+    /// ```
+    ///
+    /// `registered`는 트리 순서의 등록된 commitment 집합이며, 기존 담보
+    /// commitment는 공개되지 않고 이 집합의 root로만 증명됩니다.
+    /// 반환되는 `commitment`는 인출 후의 새 담보 commitment이고,
+    /// nullifier는 마지막 public input입니다.
+    #[allow(clippy::too_many_arguments)]
+    async fn generate_withdraw_proof(
+        &self,
+        old_collateral: u128,
+        withdraw_amount: u128,
+        debt: u128,
+        max_ltv: u64,
+        old_salt: u128,
+        new_salt: u128,
+        debt_salt: u128,
+        registered: &[String],
+    ) -> Result<ProofResult> {
+        tracing::info!(
+            "Generating withdraw proof: amount={}, max_ltv={}%",
+            withdraw_amount, max_ltv
+        );
+
+        let old_collateral_fp = F::from_u128(old_collateral);
+        let amount_fp = F::from_u128(withdraw_amount);
+        let debt_fp = F::from_u128(debt);
+        let max_ltv_fp = F::from(max_ltv);
+        let old_salt_fp = F::from_u128(old_salt);
+        let new_salt_fp = F::from_u128(new_salt);
+        let debt_salt_fp = F::from_u128(debt_salt);
+
+        // Commitments before and after the withdrawal
+        let old_commitment = compute_commitment(old_collateral_fp, old_salt_fp);
+        let new_commitment = compute_commitment(old_collateral_fp - amount_fp, new_salt_fp);
+        let debt_commitment = compute_commitment(debt_fp, debt_salt_fp);
+        let nullifier = compute_nullifier(old_salt_fp, old_commitment);
+        let (root, old_path) = self.commitment_path(registered, old_commitment)?;
+
+        self.ensure_keys::<WithdrawCircuit<F>>(CircuitId::Withdraw).await?;
+
+        let circuit = WithdrawCircuit::new(
+            old_collateral_fp,
+            old_salt_fp,
+            &old_path,
+            new_salt_fp,
+            debt_fp,
+            debt_salt_fp,
+            amount_fp,
+            max_ltv_fp,
+        );
+
+        let public_inputs = vec![
+            root,
+            amount_fp,
+            new_commitment,
+            debt_commitment,
+            max_ltv_fp,
+            nullifier,
+        ];
+
+        let proof = self.prove(CircuitId::Withdraw, circuit, &public_inputs).await?;
+
+        Ok(ProofResult {
+            proof,
+            public_inputs: public_inputs.iter().map(|fp| self.fp_to_hex(*fp)).collect(),
+            commitment: self.fp_to_hex(new_commitment),
+        })
+    }
+
+    /// Commitment 갱신 증명 생성 (담보 추가 예치, 부분 상환)
+    ///
+    /// # Circuit Logic
+    ///
+    /// ```text
+    /// Private inputs: old_value, old_salt, new_salt, old_commitment의 Merkle 경로
+    /// Public inputs: commitment_root, new_commitment, delta, increase, nullifier
+    ///
+    /// Constraints:
+    /// 1. new_value = old_value ± delta (increase ? + : -), 0 <= new_value < 2^128
+    /// 2. old_commitment == Poseidon(old_value, old_salt), commitment_root 트리에 포함됨
+    /// 3. new_commitment == Poseidon(new_value, new_salt)
+    /// 4. nullifier == Poseidon(old_salt, old_commitment)
+    /// ```
+    ///
+    /// `registered`는 트리 순서의 등록된 commitment 집합입니다.
+    /// 반환되는 `commitment`는 갱신된 새 commitment이고,
+    /// nullifier는 마지막 public input입니다.
+    async fn generate_transition_proof(
+        &self,
+        old_value: u128,
+        delta: u128,
+        increase: bool,
+        old_salt: u128,
+        new_salt: u128,
+        registered: &[String],
+    ) -> Result<ProofResult> {
+        tracing::info!(
+            "Generating transition proof: delta={}, increase={}",
+            delta, increase
+        );
+
+        let old_value_fp = F::from_u128(old_value);
+        let delta_fp = F::from_u128(delta);
+        let old_salt_fp = F::from_u128(old_salt);
+        let new_salt_fp = F::from_u128(new_salt);
+
+        let new_value_fp = CommitmentTransitionCircuit::<F>::apply_delta(old_value_fp, delta_fp, increase);
+        let old_commitment = compute_commitment(old_value_fp, old_salt_fp);
+        let new_commitment = compute_commitment(new_value_fp, new_salt_fp);
+        let nullifier = compute_nullifier(old_salt_fp, old_commitment);
+        let (root, old_path) = self.commitment_path(registered, old_commitment)?;
+
+        self.ensure_keys::<CommitmentTransitionCircuit<F>>(CircuitId::Transition).await?;
+
+        let circuit = CommitmentTransitionCircuit::new(
+            old_value_fp,
+            old_salt_fp,
+            &old_path,
+            new_salt_fp,
+            delta_fp,
+            increase,
+        );
+
+        let public_inputs = vec![
+            root,
+            new_commitment,
+            delta_fp,
+            CommitmentTransitionCircuit::<F>::direction(increase),
+            nullifier,
+        ];
+
+        let proof = self.prove(CircuitId::Transition, circuit, &public_inputs).await?;
+
+        Ok(ProofResult {
+            proof,
+            public_inputs: public_inputs.iter().map(|fp| self.fp_to_hex(*fp)).collect(),
+            commitment: self.fp_to_hex(new_commitment),
+        })
+    }
+
+    /// 이자 반영 부채 commitment 증명 생성
+    ///
+    /// # Circuit Logic
+    ///
+    /// ```text
+    /// Private inputs: principal, old_salt, new_salt, old_commitment의 Merkle 경로
+    /// Public inputs: commitment_root, new_commitment, rate, elapsed, accrued_interest, nullifier
+    ///
+    /// Constraints (ZKLendingPool.getCurrentDebt와 동일, 내림):
+    /// 1. interest = principal * rate * elapsed / (10000 * 365 days)
+    /// 2. new_debt = principal + accrued_interest + interest
+    /// 3. old_commitment == Poseidon(principal, old_salt), commitment_root 트리에 포함됨
+    /// 4. new_commitment == Poseidon(new_debt, new_salt)
+    /// 5. nullifier == Poseidon(old_salt, old_commitment)
+    /// ```
+    ///
+    /// `registered`는 트리 순서의 등록된 commitment 집합입니다.
+    /// 반환되는 `commitment`는 이자가 반영된 새 부채 commitment이고,
+    /// nullifier는 마지막 public input입니다.
+    #[allow(clippy::too_many_arguments)]
+    async fn generate_interest_proof(
+        &self,
+        principal: u128,
+        rate: u64,
+        elapsed: u64,
+        accrued_interest: u128,
+        old_salt: u128,
+        new_salt: u128,
+        registered: &[String],
+    ) -> Result<ProofResult> {
+        tracing::info!(
+            "Generating interest proof: rate={}bps, elapsed={}s",
+            rate, elapsed
+        );
+
+        let new_debt = InterestAccrualCircuit::<F>::compute_new_debt(principal, rate, elapsed, accrued_interest)
+            .ok_or_else(|| anyhow!("Accrued debt overflows u128"))?;
+
+        let principal_fp = F::from_u128(principal);
+        let old_salt_fp = F::from_u128(old_salt);
+        let new_salt_fp = F::from_u128(new_salt);
+
+        let old_commitment = compute_commitment(principal_fp, old_salt_fp);
+        let new_commitment = compute_commitment(F::from_u128(new_debt), new_salt_fp);
+        let nullifier = compute_nullifier(old_salt_fp, old_commitment);
+        let (root, old_path) = self.commitment_path(registered, old_commitment)?;
+
+        self.ensure_keys::<InterestAccrualCircuit<F>>(CircuitId::Interest).await?;
+
+        let circuit = InterestAccrualCircuit::new(
+            principal,
+            old_salt_fp,
+            &old_path,
+            new_salt_fp,
+            rate,
+            elapsed,
+            accrued_interest,
+        );
+
+        let public_inputs = vec![
+            root,
+            new_commitment,
+            F::from(rate),
+            F::from(elapsed),
+            F::from_u128(accrued_interest),
+            nullifier,
+        ];
+
+        let proof = self.prove(CircuitId::Interest, circuit, &public_inputs).await?;
+
+        Ok(ProofResult {
+            proof,
+            public_inputs: public_inputs.iter().map(|fp| self.fp_to_hex(*fp)).collect(),
+            commitment: self.fp_to_hex(new_commitment),
+        })
+    }
+
+    /// 프로토콜 솔벤시 증명 생성
+    ///
+    /// # Circuit Logic
+    ///
+    /// ```text
+    /// Private inputs: 포지션별 collateral, debt, collateral_salt, debt_salt
+    /// Public inputs: root, price, total_collateral, total_debt
+    ///
+    /// Constraints:
+    /// 1. leaf_i = Poseidon(Poseidon(collateral_i, collateral_salt_i), Poseidon(debt_i, debt_salt_i))
+    /// 2. 빈 슬롯은 leaf 0, 전체 트리가 root로 해시됨
+    /// 3. total_collateral = sum(collateral_i), total_debt = sum(debt_i)
+    /// 4. total_collateral * price >= total_debt * 1e20
+    /// ```
+    ///
+    /// 포지션은 트리의 앞 슬롯부터 순서대로 채워지며 (최대 2^SOLVENCY_TREE_DEPTH개),
+    /// 반환되는 `commitment`는 포지션 트리의 Merkle root입니다.
+    async fn generate_solvency_proof(
+        &self,
+        positions: &[CommittedPosition],
+        price: u128,
+    ) -> Result<ProofResult> {
+        tracing::info!(
+            "Generating solvency proof: positions={}, price={}",
+            positions.len(), price
+        );
+
+        let positions: Vec<_> = positions
+            .iter()
+            .map(|p| SolvencyPosition::new(
+                F::from_u128(p.collateral),
+                F::from_u128(p.debt),
+                F::from_u128(p.collateral_salt),
+                F::from_u128(p.debt_salt),
+            ))
+            .collect();
+        let price_fp = F::from_u128(price);
+
+        let circuit = SolvencyCircuit::<F, SOLVENCY_TREE_DEPTH>::new(&positions, price_fp)
+            .map_err(|e| anyhow!("Invalid solvency positions: {}", e))?;
+        let root = SolvencyCircuit::<F, SOLVENCY_TREE_DEPTH>::compute_root(&positions)
+            .map_err(|e| anyhow!("Failed to build position tree: {}", e))?;
+        let total_collateral: F = positions.iter().map(|p| p.collateral).sum();
+        let total_debt: F = positions.iter().map(|p| p.debt).sum();
+
+        self.ensure_keys::<SolvencyCircuit<F, SOLVENCY_TREE_DEPTH>>(CircuitId::Solvency).await?;
+
+        let public_inputs = SolvencyCircuit::<F, SOLVENCY_TREE_DEPTH>::public_inputs(
+            root,
+            price_fp,
+            total_collateral,
+            total_debt,
+        );
+
+        let proof = self.prove(CircuitId::Solvency, circuit, &public_inputs).await?;
+
+        Ok(ProofResult {
+            proof,
+            public_inputs: public_inputs.iter().map(|fp| self.fp_to_hex(*fp)).collect(),
+            commitment: self.fp_to_hex(root),
+        })
+    }
+
+    /// 등록된 commitment 집합의 트리 root와 `commitment`의 Merkle 경로
+    ///
+    /// `registered`는 트리 순서의 `fp_to_hex` 문자열이며, `commitment`가
+    /// 집합에 없으면 `UnregisteredCommitment` 에러를 반환
+    fn commitment_path(&self, registered: &[String], commitment: F) -> Result<(F, MerklePath<F>)> {
+        let mut tree = IncrementalMerkleTree::<F>::new(COMMITMENT_TREE_DEPTH)
+            .map_err(|e| anyhow!("Failed to build commitment tree: {}", e))?;
+
+        let mut index = None;
+        for leaf in registered {
+            let leaf = parse_fp_hex::<F>(leaf)?;
+            let leaf_index = tree.insert(leaf)
+                .map_err(|e| anyhow!("Failed to build commitment tree: {}", e))?;
+            if leaf == commitment && index.is_none() {
+                index = Some(leaf_index);
+            }
+        }
+
+        let index = index.ok_or_else(|| UnregisteredCommitment(self.fp_to_hex(commitment)))?;
+        let path = tree.path(index)
+            .map_err(|e| anyhow!("Failed to build commitment path: {}", e))?;
+
+        Ok((tree.root(), path))
+    }
+
+    /// Field element를 hex 문자열로 변환
+    fn fp_to_hex(&self, fp: F) -> String {
+        let bytes = fp.to_repr();
+        format!("0x{}", hex::encode(bytes.as_ref()))
+    }
+
+    /// Halo2 proof transcript를 응답 형식으로 인코딩
+    ///
+    /// `bytes`는 `create_proof`가 기록한 transcript 그대로이므로 디코딩 후
+    /// 같은 verifying key로 `verify_proof`에 넣어 검증할 수 있음
+    fn encode_proof(
+        circuit_id: CircuitId,
+        proof_bytes: &[u8],
+        vk: &VerifyingKey<B::Curve>,
+    ) -> ProofData {
+        ProofData {
+            bytes: format!("0x{}", hex::encode(proof_bytes)),
+            proving_system: B::BACKEND.proving_system().to_string(),
+            circuit_id: circuit_id.to_string(),
+            vk_hash: vk_hash(vk),
+        }
+    }
+
+    /// Proof 검증
+    ///
+    /// 서버가 캐시한 verifying key로 `circuit_id` 회로의 proof를 검증.
+    /// 키가 아직 없으면 먼저 준비하며, proof나 public input이 회로와 맞지 않으면
+    /// 에러가 아니라 `valid: false`를 반환
+    async fn verify(
+        &self,
+        circuit_id: CircuitId,
+        proof_bytes: &[u8],
+        public_inputs: &[String],
+    ) -> Result<VerificationResult> {
+        let public_inputs = public_inputs
+            .iter()
+            .map(|input| parse_fp_hex::<F>(input))
+            .collect::<Result<Vec<_>>>()
+            .map_err(|e| InvalidPublicInput(e.to_string()))?;
+
+        self.ensure_keys_for(circuit_id).await?;
+
+        let context = self.context.clone();
+        let proof_bytes = proof_bytes.to_vec();
+
+        self.executor.run(circuit_id, move || {
+            let context = context.blocking_read();
+            let vk = context.verifying_key(circuit_id)
+                .ok_or_else(|| anyhow!("Verification key not initialized: {}", circuit_id))?;
+
+            let result = B::verify_proof(&context.params, vk, &proof_bytes, &public_inputs);
+
+            if let Err(e) = &result {
+                tracing::debug!("{} proof rejected: {:?}", circuit_id, e);
+            }
+
+            Ok(VerificationResult {
+                valid: result.is_ok(),
+                vk_hash: vk_hash(vk),
+            })
+        }).await
+    }
+}
+
+/// 설정된 backend의 prover
+enum Backend {
+    Ipa(Prover<Ipa>),
+    KzgShplonk(Prover<KzgShplonk>),
+    KzgGwc(Prover<KzgGwc>),
+}
+
+/// 설정된 backend의 prover로 같은 호출을 전달
+macro_rules! dispatch {
+    ($self:ident, $prover:ident => $call:expr) => {
+        match &$self.backend {
+            Backend::Ipa($prover) => $call,
+            Backend::KzgShplonk($prover) => $call,
+            Backend::KzgGwc($prover) => $call,
+        }
+    };
+}
+
+/// ZK Prover 서비스
+///
+/// # Architecture
+///
+/// ```text
+/// ┌─────────────────────────────────────────────────────────────┐
+/// │                      ZKProver                                │
+/// ├─────────────────────────────────────────────────────────────┤
+/// │                                                             │
+/// │  ┌─────────────┐  ┌─────────────┐  ┌─────────────────────┐ │
+/// │  │ Collateral  │  │    LTV      │  │    Liquidation      │ │
+/// │  │   Circuit   │  │   Circuit   │  │      Circuit        │ │
+/// │  └──────┬──────┘  └──────┬──────┘  └──────────┬──────────┘ │
+/// │         │                │                    │             │
+/// │         v                v                    v             │
+/// │  ┌──────────────────────────────────────────────────────┐  │
+/// │  │   Prover<B>  (B = Ipa | KzgShplonk | KzgGwc)          │  │
+/// │  │  - Params (SRS)                                       │  │
+/// │  │  - ProvingKeys (cached)                               │  │
+/// │  │  - VerificationKeys (cached)                          │  │
+/// │  └──────────────────────────┬───────────────────────────┘  │
+/// │                             v                               │
+/// │  ┌──────────────────────────────────────────────────────┐  │
+/// │  │ ProverExecutor (blocking pool, keygen/create/verify)  │  │
+/// │  └──────────────────────────────────────────────────────┘  │
+/// │                                                             │
+/// └─────────────────────────────────────────────────────────────┘
+/// ```
+///
+/// backend는 생성 시 한 번 정해지며 (`Config::proving_backend`), 모든 증명과
+/// 검증이 같은 backend를 사용함
+pub struct ZKProver {
+    backend: Backend,
+}
+
+impl ZKProver {
+    /// 기본 backend (IPA)로 새 ZK Prover 생성
+    pub fn new() -> Result<Self> {
+        Self::with_backend(ProvingBackend::default())
+    }
+
+    /// `backend`로 새 ZK Prover 생성 (SRS와 키는 프로세스마다 생성)
+    pub fn with_backend(backend: ProvingBackend) -> Result<Self> {
+        Self::build(backend, None)
+    }
+
+    /// 디스크 키 저장소를 사용하는 ZK Prover 생성
+    ///
+    /// SRS는 `dir/<backend>`에서 읽고 (없으면 생성해서 저장), 회로별 키도 처음 필요할 때
+    /// 같은 디렉터리에서 읽어 재시작 후에도 같은 키를 사용함. 모든 키를 미리 준비하려면
+    /// `preload_keys`를 호출
+    pub fn with_key_store(backend: ProvingBackend, dir: impl AsRef<Path>) -> Result<Self> {
+        Self::build(backend, Some(dir.as_ref()))
+    }
+
+    fn build(backend: ProvingBackend, key_store_dir: Option<&Path>) -> Result<Self> {
+        let backend = match backend {
+            ProvingBackend::Ipa => Backend::Ipa(Prover::new(key_store_dir)?),
+            ProvingBackend::KzgShplonk => Backend::KzgShplonk(Prover::new(key_store_dir)?),
+            ProvingBackend::KzgGwc => Backend::KzgGwc(Prover::new(key_store_dir)?),
+        };
+        Ok(Self { backend })
+    }
+
+    /// 증명 실행기 설정 (동시 실행 수, 대기열 길이)
+    pub fn with_executor(self, executor: ProverExecutor) -> Self {
+        let backend = match self.backend {
+            Backend::Ipa(prover) => Backend::Ipa(prover.with_executor(executor)),
+            Backend::KzgShplonk(prover) => Backend::KzgShplonk(prover.with_executor(executor)),
+            Backend::KzgGwc(prover) => Backend::KzgGwc(prover.with_executor(executor)),
+        };
+        Self { backend }
+    }
+
+    /// 사용 중인 증명 backend
+    pub fn backend(&self) -> ProvingBackend {
+        match &self.backend {
+            Backend::Ipa(_) => ProvingBackend::Ipa,
+            Backend::KzgShplonk(_) => ProvingBackend::KzgShplonk,
+            Backend::KzgGwc(_) => ProvingBackend::KzgGwc,
+        }
+    }
+
+    /// 응답의 `proving_system` 식별자
+    pub fn proving_system(&self) -> &'static str {
+        self.backend().proving_system()
+    }
+
+    /// 증명 실행기 상태와 대기 시간 통계
+    pub fn prover_metrics(&self) -> ProverMetrics {
+        dispatch!(self, prover => prover.prover_metrics())
+    }
+
+    /// 모든 회로의 키를 준비 (키 저장소가 있으면 디스크에서 로드)
+    ///
+    /// 서버 시작 시 호출하면 첫 증명 요청에서 keygen 지연이 생기지 않음
+    pub async fn preload_keys(&self) -> Result<()> {
+        dispatch!(self, prover => prover.preload_keys().await)
+    }
+
+    /// 준비된 verifying key의 해시 목록 (circuit id, vk hash), `CircuitId::all()` 순서
+    ///
+    /// 온체인 verifier registry에 등록된 vk와 대조하는 데 사용
+    pub async fn verifying_key_hashes(&self) -> Vec<(String, String)> {
+        dispatch!(self, prover => prover.verifying_key_hashes().await)
+    }
+
+    /// SRS 직렬화 (클라이언트 측 증명용, `GET /proof/params`)
+    pub async fn params_bytes(&self) -> Result<Vec<u8>> {
+        dispatch!(self, prover => prover.params_bytes().await)
+    }
+
+    /// Computes a cryptographic commitment for the given value and salt.
+    /// Uses Poseidon hash over the backend's field, matching the circuits.
+    pub fn compute_commitment(&self, value: u128, salt: u128) -> Result<Vec<u8>> {
+        dispatch!(self, prover => prover.compute_commitment(value, salt))
+    }
+
+    /// 담보 충분 증명 생성
+    pub async fn generate_collateral_proof(
+        &self,
+        collateral: u128,
+        threshold: u128,
+        salt: u128,
+    ) -> Result<ProofResult> {
+        dispatch!(self, prover => prover.generate_collateral_proof(collateral, threshold, salt).await)
+    }
+
+    /// LTV 비율 증명 생성
+    #[allow(clippy::too_many_arguments)]
+    pub async fn generate_ltv_proof(
+        &self,
+        collateral: u128,
+        debt: u128,
+        max_ltv: u64,
+        price: u128,
+        collateral_decimals: u64,
+        debt_decimals: u64,
+        collateral_salt: u128,
+        debt_salt: u128,
+    ) -> Result<ProofResult> {
+        dispatch!(self, prover => prover.generate_ltv_proof(
+            collateral,
+            debt,
+            max_ltv,
+            price,
+            collateral_decimals,
+            debt_decimals,
+            collateral_salt,
+            debt_salt,
+        ).await)
+    }
+
+    /// 다중 담보 LTV 증명 생성
+    pub async fn generate_multi_asset_ltv_proof(
+        &self,
+        assets: &[CollateralAsset],
+        debt: u128,
+        debt_salt: u128,
+        debt_decimals: u64,
+    ) -> Result<ProofResult> {
+        dispatch!(self, prover => prover.generate_multi_asset_ltv_proof(
+            assets,
+            debt,
+            debt_salt,
+            debt_decimals,
+        ).await)
+    }
+
+    /// 청산 가능 증명 생성
+    pub async fn generate_liquidation_proof(
+        &self,
+        collateral: u128,
+        debt: u128,
+        price: u128,
+        liquidation_threshold: u64,
+        collateral_salt: u128,
+        debt_salt: u128,
+    ) -> Result<ProofResult> {
+        dispatch!(self, prover => prover.generate_liquidation_proof(
+            collateral,
+            debt,
+            price,
+            liquidation_threshold,
+            collateral_salt,
+            debt_salt,
+        ).await)
+    }
+
+    /// 청산 정산 증명 생성 (상환 가능 부채, 압류 담보)
+    #[allow(clippy::too_many_arguments)]
+    pub async fn generate_liquidation_settlement_proof(
+        &self,
+        collateral: u128,
+        debt: u128,
+        price: u64,
+        close_factor: u64,
+        bonus: u64,
+        repay: u128,
+        collateral_salt: u128,
+        debt_salt: u128,
+        new_collateral_salt: u128,
+        new_debt_salt: u128,
+    ) -> Result<ProofResult> {
+        dispatch!(self, prover => prover.generate_liquidation_settlement_proof(
+            collateral,
+            debt,
+            price,
+            close_factor,
+            bonus,
+            repay,
+            collateral_salt,
+            debt_salt,
+            new_collateral_salt,
+            new_debt_salt,
+        ).await)
+    }
+
+    /// Health factor 구간 증명 생성
+    #[allow(clippy::too_many_arguments)]
+    pub async fn generate_health_factor_proof(
+        &self,
+        collateral: u128,
+        debt: u128,
+        price: u128,
+        liquidation_threshold: u64,
+        lo: u64,
+        hi: u64,
+        collateral_salt: u128,
+        debt_salt: u128,
+    ) -> Result<ProofResult> {
+        dispatch!(self, prover => prover.generate_health_factor_proof(
+            collateral,
+            debt,
+            price,
+            liquidation_threshold,
+            lo,
+            hi,
+            collateral_salt,
+            debt_salt,
+        ).await)
+    }
+
+    /// 담보 인출 증명 생성
+    ///
+    /// `registered`는 기존 담보 commitment를 포함해야 하는 등록된 commitment 집합 (트리 순서)
+    #[allow(clippy::too_many_arguments)]
+    pub async fn generate_withdraw_proof(
+        &self,
+        old_collateral: u128,
+        withdraw_amount: u128,
+        debt: u128,
+        max_ltv: u64,
+        old_salt: u128,
+        new_salt: u128,
+        debt_salt: u128,
+        registered: &[String],
+    ) -> Result<ProofResult> {
+        dispatch!(self, prover => prover.generate_withdraw_proof(
+            old_collateral,
+            withdraw_amount,
+            debt,
+            max_ltv,
+            old_salt,
+            new_salt,
+            debt_salt,
+            registered,
+        ).await)
+    }
+
+    /// Commitment 갱신 증명 생성 (담보 추가 예치, 부분 상환)
+    ///
+    /// `registered`는 기존 commitment를 포함해야 하는 등록된 commitment 집합 (트리 순서)
+    pub async fn generate_transition_proof(
+        &self,
+        old_value: u128,
+        delta: u128,
+        increase: bool,
+        old_salt: u128,
+        new_salt: u128,
+        registered: &[String],
+    ) -> Result<ProofResult> {
+        dispatch!(self, prover => prover.generate_transition_proof(
+            old_value,
+            delta,
+            increase,
+            old_salt,
+            new_salt,
+            registered,
+        ).await)
+    }
+
+    /// 이자 반영 부채 commitment 증명 생성
+    ///
+    /// `registered`는 기존 부채 commitment를 포함해야 하는 등록된 commitment 집합 (트리 순서)
+    #[allow(clippy::too_many_arguments)]
+    pub async fn generate_interest_proof(
+        &self,
+        principal: u128,
+        rate: u64,
+        elapsed: u64,
+        accrued_interest: u128,
+        old_salt: u128,
+        new_salt: u128,
+        registered: &[String],
+    ) -> Result<ProofResult> {
+        dispatch!(self, prover => prover.generate_interest_proof(
+            principal,
+            rate,
+            elapsed,
+            accrued_interest,
+            old_salt,
+            new_salt,
+            registered,
+        ).await)
+    }
+
+    /// 프로토콜 솔벤시 증명 생성
+    pub async fn generate_solvency_proof(
+        &self,
+        positions: &[CommittedPosition],
+        price: u128,
+    ) -> Result<ProofResult> {
+        dispatch!(self, prover => prover.generate_solvency_proof(positions, price).await)
+    }
+
+    /// Proof 검증
+    ///
+    /// `public_inputs`는 응답의 hex 문자열 그대로이며, backend의 field element로
+    /// 디코딩할 수 없으면 `InvalidPublicInput` 에러를 반환
+    pub async fn verify(
+        &self,
+        circuit_id: CircuitId,
+        proof_bytes: &[u8],
+        public_inputs: &[String],
+    ) -> Result<VerificationResult> {
+        dispatch!(self, prover => prover.verify(circuit_id, proof_bytes, public_inputs).await)
+    }
+}
+
+/// Mock ZKProver for testing (no actual Halo2)
+#[cfg(test)]
+pub struct MockZKProver;
+
+#[cfg(test)]
+impl MockZKProver {
+    pub fn new() -> Result<Self> {
+        Ok(Self)
+    }
+
+    pub fn compute_commitment(&self, value: u128, salt: u128) -> Vec<u8> {
+        use sha3::{Keccak256, Digest};
+        let mut hasher = Keccak256::new();
+        hasher.update(value.to_le_bytes());
+        hasher.update(salt.to_le_bytes());
+        hasher.finalize().to_vec()
+    }
+
+    pub async fn generate_collateral_proof(
+        &self,
+        collateral: u128,
+        threshold: u128,
+        salt: u128,
+    ) -> Result<ProofResult> {
+        let commitment = self.compute_commitment(collateral, salt);
+        let commitment_hex = format!("0x{}", hex::encode(&commitment));
+
+        Ok(ProofResult {
+            proof: self.mock_proof(),
+            public_inputs: vec![
+                format!("0x{:064x}", threshold),
+                commitment_hex.clone(),
+            ],
             commitment: commitment_hex,
         })
     }
 
     fn mock_proof(&self) -> ProofData {
         ProofData {
-            a: [
-                "0x0000000000000000000000000000000000000000000000000000000000000001".to_string(),
-                "0x0000000000000000000000000000000000000000000000000000000000000002".to_string(),
-            ],
-            b: [
-                [
-                    "0x0000000000000000000000000000000000000000000000000000000000000003".to_string(),
-                    "0x0000000000000000000000000000000000000000000000000000000000000004".to_string(),
-                ],
-                [
-                    "0x0000000000000000000000000000000000000000000000000000000000000005".to_string(),
-                    "0x0000000000000000000000000000000000000000000000000000000000000006".to_string(),
-                ],
-            ],
-            c: [
-                "0x0000000000000000000000000000000000000000000000000000000000000007".to_string(),
-                "0x0000000000000000000000000000000000000000000000000000000000000008".to_string(),
-            ],
+            bytes: "0x00".to_string(),
+            proving_system: "mock".to_string(),
+            circuit_id: CircuitId::Collateral.to_string(),
+            vk_hash: format!("0x{}", "00".repeat(32)),
         }
     }
 }
@@ -625,6 +1696,8 @@ impl MockZKProver {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use pasta_curves::Fp;
+    use zk_private_lending_circuits::Fr;
 
     #[test]
     fn test_commitment_deterministic() {
@@ -655,4 +1728,134 @@ mod tests {
         assert!(!result.commitment.is_empty());
         assert_eq!(result.public_inputs.len(), 2);
     }
+
+    #[test]
+    fn test_parse_fp_hex_round_trip() {
+        let fp = Fp::from_u128(123_456_789);
+        let hex = format!("0x{}", hex::encode(fp.to_repr()));
+        assert_eq!(parse_fp_hex::<Fp>(&hex).unwrap(), fp);
+        assert!(parse_fp_hex::<Fp>("0x1234").is_err());
+
+        let fr = Fr::from_u128(123_456_789);
+        let hex = format!("0x{}", hex::encode(fr.to_repr()));
+        assert_eq!(parse_fp_hex::<Fr>(&hex).unwrap(), fr);
+
+        // BN254 modulus보다 큰 값
+        assert!(parse_fp_hex::<Fr>(&format!("0x{}", "ff".repeat(32))).is_err());
+    }
+
+    #[test]
+    fn test_parse_u64_hex() {
+        let hex = format!("0x{}", hex::encode(Fp::from(12_000u64).to_repr()));
+        assert_eq!(parse_u64_hex(&hex).unwrap(), 12_000);
+
+        let hex = format!("0x{}", hex::encode(Fr::from(u32::MAX as u64).to_repr()));
+        assert_eq!(parse_u64_hex(&hex).unwrap(), u32::MAX as u64);
+
+        // u64보다 큰 field element와 잘못된 길이
+        let hex = format!("0x{}", hex::encode(Fp::from_u128(1u128 << 64).to_repr()));
+        assert!(parse_u64_hex(&hex).is_err());
+        assert!(parse_u64_hex("0x1234").is_err());
+    }
+
+    #[test]
+    fn test_circuit_id_display() {
+        assert_eq!(CircuitId::Collateral.to_string(), "collateral");
+        assert_eq!(CircuitId::MultiAssetLtv { assets: 3 }.to_string(), "multi_asset_ltv_3");
+        assert_eq!(CircuitId::LiquidationSettlement.to_string(), "liquidation_settlement");
+    }
+
+    #[test]
+    fn test_circuit_id_from_str() {
+        for circuit_id in CircuitId::all() {
+            assert_eq!(circuit_id.to_string().parse::<CircuitId>().unwrap(), circuit_id);
+        }
+        assert!("multi_asset_ltv_0".parse::<CircuitId>().is_err());
+        assert!("multi_asset_ltv_5".parse::<CircuitId>().is_err());
+        assert!("groth16".parse::<CircuitId>().is_err());
+    }
+
+    /// 응답을 JSON으로 직렬화/역직렬화한 뒤 디코딩한 proof가 `verify_proof`를 통과하는지 확인
+    async fn assert_collateral_round_trip(backend: ProvingBackend) {
+        let prover = ZKProver::with_backend(backend).unwrap();
+        assert_eq!(prover.backend(), backend);
+
+        let result = prover.generate_collateral_proof(1000, 500, 12345).await.unwrap();
+
+        let json = serde_json::to_string(&result.proof).unwrap();
+        let proof: ProofData = serde_json::from_str(&json).unwrap();
+        assert_eq!(proof.proving_system, backend.proving_system());
+        assert_eq!(proof.circuit_id, "collateral");
+
+        let hashes = prover.verifying_key_hashes().await;
+        assert_eq!(hashes, vec![("collateral".to_string(), proof.vk_hash.clone())]);
+
+        let proof_bytes = decode_proof_bytes(&proof.bytes).unwrap();
+        let circuit_id: CircuitId = proof.circuit_id.parse().unwrap();
+        let verification = prover.verify(circuit_id, &proof_bytes, &result.public_inputs).await.unwrap();
+        assert!(verification.valid);
+        assert_eq!(verification.vk_hash, proof.vk_hash);
+
+        // threshold와 commitment를 바꾸면 검증 실패
+        let tampered = vec![result.public_inputs[1].clone(), result.public_inputs[0].clone()];
+        assert!(!prover.verify(circuit_id, &proof_bytes, &tampered).await.unwrap().valid);
+
+        // 다른 회로의 키로는 검증 실패
+        assert!(!prover.verify(CircuitId::Ltv, &proof_bytes, &result.public_inputs).await.unwrap().valid);
+
+        // field element가 아닌 public input은 에러
+        let err = prover.verify(circuit_id, &proof_bytes, &["0x1234".to_string()]).await.unwrap_err();
+        assert!(err.is::<InvalidPublicInput>());
+    }
+
+    #[tokio::test]
+    async fn test_collateral_proof_round_trip() {
+        assert_collateral_round_trip(ProvingBackend::Ipa).await;
+    }
+
+    #[tokio::test]
+    async fn test_collateral_proof_round_trip_kzg_shplonk() {
+        assert_collateral_round_trip(ProvingBackend::KzgShplonk).await;
+    }
+
+    #[tokio::test]
+    async fn test_collateral_proof_round_trip_kzg_gwc() {
+        assert_collateral_round_trip(ProvingBackend::KzgGwc).await;
+    }
+
+    #[tokio::test]
+    async fn test_withdraw_proves_membership_without_old_commitment() {
+        let prover = ZKProver::new().unwrap();
+        let commitment_hex = |value, salt| format!("0x{}", hex::encode(prover.compute_commitment(value, salt).unwrap()));
+        let old_commitment = commitment_hex(100, 11);
+        let registered = vec![commitment_hex(500, 1), old_commitment.clone(), commitment_hex(60, 33)];
+
+        let result = prover
+            .generate_withdraw_proof(100, 20, 60, 80, 11, 22, 33, &registered)
+            .await
+            .unwrap();
+
+        // 기존 commitment는 공개되지 않고 등록된 집합의 root로만 증명됨
+        assert!(!result.public_inputs.contains(&old_commitment));
+        let proof_bytes = decode_proof_bytes(&result.proof.bytes).unwrap();
+        let verification = prover.verify(CircuitId::Withdraw, &proof_bytes, &result.public_inputs).await.unwrap();
+        assert!(verification.valid);
+
+        // 다른 commitment 집합의 root로는 검증 실패
+        let mut other_root = result.public_inputs.clone();
+        other_root[0] = commitment_hex(500, 1);
+        assert!(!prover.verify(CircuitId::Withdraw, &proof_bytes, &other_root).await.unwrap().valid);
+    }
+
+    #[tokio::test]
+    async fn test_withdraw_requires_registered_commitment() {
+        let prover = ZKProver::new().unwrap();
+        let other = format!("0x{}", hex::encode(prover.compute_commitment(500, 1).unwrap()));
+
+        let err = prover
+            .generate_withdraw_proof(100, 20, 60, 80, 11, 22, 33, &[other])
+            .await
+            .unwrap_err();
+        assert!(err.is::<UnregisteredCommitment>());
+    }
 }
@@ -4,7 +4,7 @@
 //!
 //! # Public Inputs
 //! - `threshold`: Minimum required collateral
-//! - `commitment`: Hash(collateral, salt) - binding commitment to the collateral
+//! - `commitment`: Poseidon(collateral, salt) - binding commitment to the collateral
 //!
 //! # Private Inputs (Witnesses)
 //! - `collateral`: Actual collateral amount
@@ -12,7 +12,7 @@
 //!
 //! # Constraints
 //! 1. `collateral >= threshold` (using comparison gadget with range check)
-//! 2. `commitment == Hash(collateral, salt)` (commitment verification)
+//! 2. `commitment == Poseidon(collateral, salt)` (in-circuit Poseidon hash)
 //!
 //! # Security Properties
 //! - **Soundness**: Cannot prove false statement (collateral < threshold)
@@ -20,27 +20,22 @@
 //! - **Binding**: Cannot change collateral after commitment
 //!
 //! # Circuit Statistics
-//! - Advice columns: 4 (collateral, salt, threshold, commitment)
+//! - Advice columns: 5 (collateral, salt, threshold, commitment, diff)
 //! - Instance columns: 1 (public inputs)
-//! - Custom gates: 1 (commitment verification)
+//! - Custom gates: 4 (Poseidon absorb/full/partial rounds, comparison)
 //! - Lookup tables: 1 (range check for comparison)
-//! - Estimated rows: ~2^17 for 64-bit range checks
+//! - Estimated rows: ~2^17 (16-bit lookup table, 128-bit range checks)
 
 use ff::PrimeField;
 use halo2_proofs::{
     circuit::{Layouter, SimpleFloorPlanner, Value},
-    plonk::{Advice, Circuit, Column, ConstraintSystem, Error, Instance, Selector},
-    poly::Rotation,
+    plonk::{Advice, Circuit, Column, ConstraintSystem, Error, Instance},
 };
-use pasta_curves::Fp;
 use std::marker::PhantomData;
 
+use crate::constants::{LOOKUP_BITS, RANGE_BITS};
 use crate::gadgets::comparison::{ComparisonChip, ComparisonConfig, ComparisonInstruction};
-
-/// Number of bits for range checks
-/// Note: For production 64-bit range checks, decompose into multiple smaller checks
-/// Using 16 bits for demo/testing (2^16 = 65536 entries fits in lookup table)
-const RANGE_BITS: usize = 16;
+use crate::gadgets::poseidon::{poseidon_hash, PoseidonChip, PoseidonConfig};
 
 /// Configuration for the CollateralProof circuit
 #[derive(Debug, Clone)]
@@ -51,14 +46,14 @@ pub struct CollateralConfig<F: PrimeField> {
     pub salt: Column<Advice>,
     /// Advice column for threshold (copied from instance)
     pub threshold: Column<Advice>,
-    /// Advice column for computed commitment
+    /// Advice column for computed commitment (third Poseidon state column)
     pub commitment_computed: Column<Advice>,
     /// Instance column for public inputs
     pub instance: Column<Instance>,
-    /// Selector for commitment verification
-    pub q_commitment: Selector,
+    /// Poseidon chip config (state = [collateral, salt, commitment_computed])
+    pub poseidon: PoseidonConfig<F>,
     /// Comparison chip config
-    pub comparison: ComparisonConfig<F, RANGE_BITS>,
+    pub comparison: ComparisonConfig<F, LOOKUP_BITS>,
     _marker: PhantomData<F>,
 }
 
@@ -97,29 +92,25 @@ impl<F: PrimeField> CollateralCircuit<F> {
         }
     }
 
-    /// Compute commitment for demo/testing
-    ///
-    /// commitment = collateral * salt + collateral
+    /// Compute the collateral commitment off-circuit
     ///
-    /// NOTE: This is a simplified commitment for circuit demonstration.
-    /// For production use, replace with Poseidon hash and implement
-    /// Poseidon verification as circuit constraints.
+    /// commitment = Poseidon(collateral, salt)
     ///
-    /// The formula matches the circuit's custom gate constraint.
+    /// Delegates to `poseidon_hash`, which matches the in-circuit `PoseidonChip`.
     pub fn compute_commitment(collateral: F, salt: F) -> F {
-        collateral * salt + collateral
+        poseidon_hash(collateral, salt)
     }
 }
 
-impl Circuit<Fp> for CollateralCircuit<Fp> {
-    type Config = CollateralConfig<Fp>;
+impl<F: PrimeField> Circuit<F> for CollateralCircuit<F> {
+    type Config = CollateralConfig<F>;
     type FloorPlanner = SimpleFloorPlanner;
 
     fn without_witnesses(&self) -> Self {
         Self::default()
     }
 
-    fn configure(meta: &mut ConstraintSystem<Fp>) -> Self::Config {
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
         // Advice columns for private inputs
         let collateral = meta.advice_column();
         let salt = meta.advice_column();
@@ -136,29 +127,18 @@ impl Circuit<Fp> for CollateralCircuit<Fp> {
         meta.enable_equality(commitment_computed);
         meta.enable_equality(instance);
 
-        // Selector for commitment gate
-        let q_commitment = meta.selector();
-
-        // Commitment verification gate
-        // commitment_computed = collateral * salt + collateral
-        meta.create_gate("commitment", |meta| {
-            let q = meta.query_selector(q_commitment);
-            let coll = meta.query_advice(collateral, Rotation::cur());
-            let s = meta.query_advice(salt, Rotation::cur());
-            let comm = meta.query_advice(commitment_computed, Rotation::cur());
-
-            // Constraint: comm = coll * s + coll
-            vec![q * (comm - coll.clone() * s - coll)]
-        });
+        // Poseidon chip for commitment = Poseidon(collateral, salt)
+        let poseidon = PoseidonChip::configure(meta, [collateral, salt, commitment_computed]);
 
         // Configure comparison chip
         let diff = meta.advice_column();
         meta.enable_equality(diff);
-        let comparison = ComparisonChip::<Fp, RANGE_BITS>::configure(
+        let comparison = ComparisonChip::<F, LOOKUP_BITS>::configure_with_range_bits(
             meta,
             collateral,
             threshold,
             diff,
+            RANGE_BITS,
         );
 
         CollateralConfig {
@@ -167,7 +147,7 @@ impl Circuit<Fp> for CollateralCircuit<Fp> {
             threshold,
             commitment_computed,
             instance,
-            q_commitment,
+            poseidon,
             comparison,
             _marker: PhantomData,
         }
@@ -176,19 +156,16 @@ impl Circuit<Fp> for CollateralCircuit<Fp> {
     fn synthesize(
         &self,
         config: Self::Config,
-        mut layouter: impl Layouter<Fp>,
+        mut layouter: impl Layouter<F>,
     ) -> Result<(), Error> {
         // Create comparison chip and load lookup table
-        let comparison_chip = ComparisonChip::<Fp, RANGE_BITS>::construct(config.comparison.clone());
+        let comparison_chip = ComparisonChip::<F, LOOKUP_BITS>::construct(config.comparison.clone());
         comparison_chip.load_table(layouter.namespace(|| "load range table"))?;
 
-        // Assign private inputs and compute commitment
-        let (collateral_cell, threshold_cell, commitment_cell) = layouter.assign_region(
+        // Assign private inputs and threshold
+        let (collateral_cell, salt_cell, threshold_cell) = layouter.assign_region(
             || "assign inputs",
             |mut region| {
-                // Enable commitment gate
-                config.q_commitment.enable(&mut region, 0)?;
-
                 // Assign collateral (private)
                 let collateral_cell = region.assign_advice(
                     || "collateral",
@@ -198,7 +175,7 @@ impl Circuit<Fp> for CollateralCircuit<Fp> {
                 )?;
 
                 // Assign salt (private)
-                region.assign_advice(|| "salt", config.salt, 0, || self.salt)?;
+                let salt_cell = region.assign_advice(|| "salt", config.salt, 0, || self.salt)?;
 
                 // Assign threshold (will constrain to instance)
                 let threshold_cell = region.assign_advice(
@@ -208,27 +185,31 @@ impl Circuit<Fp> for CollateralCircuit<Fp> {
                     || self.threshold,
                 )?;
 
-                // Compute and assign commitment
-                let commitment_value = self.collateral.zip(self.salt).map(|(c, s)| {
-                    Self::compute_commitment(c, s)
-                });
-                let commitment_cell = region.assign_advice(
-                    || "commitment",
-                    config.commitment_computed,
-                    0,
-                    || commitment_value,
-                )?;
-
-                Ok((collateral_cell, threshold_cell, commitment_cell))
+                Ok((collateral_cell, salt_cell, threshold_cell))
             },
         )?;
 
+        // commitment = Poseidon(collateral, salt)
+        let poseidon_chip = PoseidonChip::construct(config.poseidon.clone());
+        let commitment_cell = poseidon_chip.hash(
+            layouter.namespace(|| "commitment"),
+            collateral_cell.clone(),
+            salt_cell,
+        )?;
+
         // Constrain threshold to public input (instance[0])
         layouter.constrain_instance(threshold_cell.cell(), config.instance, 0)?;
 
         // Constrain commitment to public input (instance[1])
         layouter.constrain_instance(commitment_cell.cell(), config.instance, 1)?;
 
+        // Threshold is public but unbounded; keep both operands in range
+        comparison_chip.range_check(
+            layouter.namespace(|| "threshold range"),
+            threshold_cell.clone(),
+            RANGE_BITS,
+        )?;
+
         // Prove collateral >= threshold
         comparison_chip.gte(
             layouter.namespace(|| "collateral >= threshold"),
@@ -243,6 +224,7 @@ impl Circuit<Fp> for CollateralCircuit<Fp> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use pasta_curves::Fp;
     use halo2_proofs::dev::MockProver;
 
     fn create_test_circuit(collateral: u64, salt: u64, threshold: u64) -> (CollateralCircuit<Fp>, Vec<Fp>) {
@@ -308,6 +290,36 @@ mod tests {
         assert!(prover.verify().is_err(), "Wrong commitment should fail");
     }
 
+    #[test]
+    fn test_collateral_proof_wrong_salt() {
+        let k = 17;
+
+        let collateral = Fp::from(1000u64);
+        let threshold = Fp::from(500u64);
+
+        // Commitment published with the real salt
+        let commitment = CollateralCircuit::compute_commitment(collateral, Fp::from(12345u64));
+
+        // Prover tries to open it with a different salt
+        let circuit = CollateralCircuit::new(collateral, Fp::from(54321u64), threshold, commitment);
+        let public_inputs = vec![threshold, commitment];
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err(), "Wrong salt should fail");
+    }
+
+    #[test]
+    fn test_commitment_is_poseidon() {
+        let collateral = Fp::from(1000u64);
+        let salt = Fp::from(12345u64);
+
+        let commitment = CollateralCircuit::compute_commitment(collateral, salt);
+        assert_eq!(commitment, poseidon_hash(collateral, salt));
+
+        // No longer the invertible collateral * salt + collateral
+        assert_ne!(commitment, collateral * salt + collateral);
+    }
+
     #[test]
     fn test_collateral_proof_edge_cases() {
         let k = 17;
@@ -323,6 +335,30 @@ mod tests {
         assert_eq!(prover.verify(), Ok(()), "Zero threshold should work");
     }
 
+    #[test]
+    fn test_collateral_proof_full_width() {
+        let k = 17;
+
+        // Wei-denominated amounts well beyond the 16-bit table
+        let (circuit, public_inputs) = create_test_circuit(u64::MAX, 12345, u64::MAX - 1);
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert_eq!(prover.verify(), Ok(()), "Values near 2^64 should work");
+
+        let (circuit, public_inputs) = create_test_circuit(1 << 40, 12345, 1 << 20);
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert_eq!(prover.verify(), Ok(()), "Multi-limb difference should work");
+    }
+
+    #[test]
+    fn test_collateral_proof_wraparound() {
+        let k = 17;
+
+        // collateral < threshold by one, near 2^64: diff wraps to p - 1
+        let (circuit, public_inputs) = create_test_circuit(u64::MAX - 1, 12345, u64::MAX);
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err(), "Wrapped difference should fail");
+    }
+
     /// Print circuit statistics using MockProver
     #[test]
     fn test_print_circuit_info() {
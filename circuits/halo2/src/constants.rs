@@ -0,0 +1,22 @@
+//! Widths and scales shared by the circuits
+//!
+//! Every circuit range checks amounts and prices with the same 16-bit lookup
+//! table, and all prices come from the same 8-decimal oracle. Circuit-specific
+//! widths (e.g. the width of the compared products) stay in each circuit.
+
+/// Lookup table width (2^16 = 65536 entries)
+pub const LOOKUP_BITS: usize = 16;
+
+/// Width of collateral, debt and other token amounts (u128 in the API),
+/// checked as 8 limbs of `LOOKUP_BITS`
+pub const RANGE_BITS: usize = 128;
+
+/// Width of the oracle price
+pub const PRICE_BITS: usize = 64;
+
+/// Oracle prices carry 8 decimals
+pub const PRICE_PRECISION: u64 = 100_000_000;
+
+/// Depth of the registered commitment tree (2^20 commitments). Circuits that
+/// spend a commitment prove membership in it instead of revealing the commitment.
+pub const COMMITMENT_TREE_DEPTH: usize = 20;
@@ -46,6 +46,11 @@ pub enum CircuitError {
     /// Division by zero
     DivisionByZero,
 
+    /// Merkle tree has no free leaf positions
+    MerkleTreeFull {
+        capacity: u64,
+    },
+
     /// Invalid circuit configuration
     InvalidConfiguration {
         message: String,
@@ -100,6 +105,9 @@ impl fmt::Display for CircuitError {
             CircuitError::DivisionByZero => {
                 write!(f, "Division by zero")
             }
+            CircuitError::MerkleTreeFull { capacity } => {
+                write!(f, "Merkle tree is full ({} leaves)", capacity)
+            }
             CircuitError::InvalidConfiguration { message } => {
                 write!(f, "Invalid configuration: {}", message)
             }
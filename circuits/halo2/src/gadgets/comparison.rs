@@ -1,28 +1,41 @@
-//! Comparison Gadget for Greater-Than-Or-Equal
+//! Comparison Gadget
 //!
-//! Proves a >= b by showing (a - b) is in range [0, 2^BITS).
+//! Proves a >= b by showing (a - b) is in range [0, 2^range_bits).
+//! The other orderings reduce to it:
+//! - `gt`: a - b - 1 in range (the offset lives in a fixed column)
+//! - `lt` / `lte`: `gt` / `gte` with the operands swapped
+//! - `in_range(lo, hi)`: value - lo and hi - value both in range
+//!
+//! `is_zero` / `is_equal` return an assigned boolean cell instead of
+//! failing, using the usual inverse witness (`out = 1 - a * a_inv`, `a * out = 0`).
 //!
 //! # Strategy
 //! 1. Compute diff = a - b (in finite field)
-//! 2. Range check that diff is in [0, 2^BITS)
+//! 2. Range check that diff is in [0, 2^range_bits)
 //! 3. If a >= b, diff is small and in range
 //! 4. If a < b, diff wraps to p - (b - a) which is huge, failing range check
 //!
+//! `BITS` is the lookup table width; `range_bits` (default `BITS`) may be
+//! wider, in which case the diff is decomposed into `BITS`-bit limbs.
+//!
 //! # Important Constraint
-//! Both a and b MUST be in range [0, 2^BITS) for this to work correctly.
-//! If a and b can exceed 2^BITS, the caller must ensure they are range-checked
-//! before using this comparison.
+//! Both a and b MUST be in range [0, 2^range_bits) for this to work correctly.
+//! If a and b can exceed 2^range_bits, the caller must ensure they are
+//! range-checked (see `ComparisonChip::range_check`) before using this comparison.
 //!
 //! # Example
 //! ```ignore
 //! // Prove collateral >= threshold
 //! comparison_chip.gte(layouter, collateral, threshold)?;
+//!
+//! // Prove 0 < max_ltv <= 100
+//! comparison_chip.in_range(layouter, max_ltv, Fp::from(1), Fp::from(100))?;
 //! ```
 
 use ff::PrimeField;
 use halo2_proofs::{
-    circuit::{AssignedCell, Layouter},
-    plonk::{Advice, Column, ConstraintSystem, Error, Selector},
+    circuit::{AssignedCell, Layouter, Value},
+    plonk::{Advice, Column, ConstraintSystem, Error, Expression, Fixed, Selector},
     poly::Rotation,
 };
 use std::marker::PhantomData;
@@ -38,8 +51,18 @@ pub struct ComparisonConfig<F: PrimeField, const BITS: usize> {
     pub b: Column<Advice>,
     /// Advice column for the difference
     pub diff: Column<Advice>,
+    /// Fixed column for the comparison offset or constant bound
+    pub constant: Column<Fixed>,
     /// Selector for the comparison gate
     pub q_cmp: Selector,
+    /// Selector for `diff = a - constant` (lower bound)
+    pub q_lower: Selector,
+    /// Selector for `diff = constant - a` (upper bound)
+    pub q_upper: Selector,
+    /// Selector for the is-zero gate (a = value, b = inverse, diff = output)
+    pub q_is_zero: Selector,
+    /// Width of the diff range check (may exceed BITS)
+    pub range_bits: usize,
     /// Range check config for validating difference
     pub range_check: RangeCheckConfig<F, BITS>,
     _marker: PhantomData<F>,
@@ -62,9 +85,49 @@ pub trait ComparisonInstruction<F: PrimeField> {
         a: AssignedCell<F, F>,
         b: AssignedCell<F, F>,
     ) -> Result<(), Error>;
+
+    /// Prove that a < b (strictly less)
+    fn lt(
+        &self,
+        layouter: impl Layouter<F>,
+        a: AssignedCell<F, F>,
+        b: AssignedCell<F, F>,
+    ) -> Result<(), Error>;
+
+    /// Prove that a <= b
+    fn lte(
+        &self,
+        layouter: impl Layouter<F>,
+        a: AssignedCell<F, F>,
+        b: AssignedCell<F, F>,
+    ) -> Result<(), Error>;
+
+    /// Return a cell that is 1 if a == 0 and 0 otherwise
+    fn is_zero(
+        &self,
+        layouter: impl Layouter<F>,
+        a: AssignedCell<F, F>,
+    ) -> Result<AssignedCell<F, F>, Error>;
+
+    /// Return a cell that is 1 if a == b and 0 otherwise
+    fn is_equal(
+        &self,
+        layouter: impl Layouter<F>,
+        a: AssignedCell<F, F>,
+        b: AssignedCell<F, F>,
+    ) -> Result<AssignedCell<F, F>, Error>;
+
+    /// Prove that lo <= value <= hi for constant bounds
+    fn in_range(
+        &self,
+        layouter: impl Layouter<F>,
+        value: AssignedCell<F, F>,
+        lo: F,
+        hi: F,
+    ) -> Result<(), Error>;
 }
 
-/// Comparison chip for >= and > operations
+/// Comparison chip for ordering, equality and interval checks
 #[derive(Debug, Clone)]
 pub struct ComparisonChip<F: PrimeField, const BITS: usize> {
     config: ComparisonConfig<F, BITS>,
@@ -76,36 +139,88 @@ impl<F: PrimeField, const BITS: usize> ComparisonChip<F, BITS> {
         Self { config }
     }
 
-    /// Configure the comparison chip
+    /// Configure the comparison chip for operands below 2^BITS
     pub fn configure(
         meta: &mut ConstraintSystem<F>,
         a: Column<Advice>,
         b: Column<Advice>,
         diff: Column<Advice>,
+    ) -> ComparisonConfig<F, BITS> {
+        Self::configure_with_range_bits(meta, a, b, diff, BITS)
+    }
+
+    /// Configure the comparison chip for operands below 2^range_bits
+    ///
+    /// The diff is range-checked with `ceil(range_bits / BITS)` table lookups.
+    pub fn configure_with_range_bits(
+        meta: &mut ConstraintSystem<F>,
+        a: Column<Advice>,
+        b: Column<Advice>,
+        diff: Column<Advice>,
+        range_bits: usize,
     ) -> ComparisonConfig<F, BITS> {
         let q_cmp = meta.selector();
+        let q_lower = meta.selector();
+        let q_upper = meta.selector();
+        let q_is_zero = meta.selector();
+        let constant = meta.fixed_column();
 
         // Configure range check for the difference
         let range_check = RangeCheckChip::<F, BITS>::configure(meta, diff);
 
-        // Custom gate: diff = a - b
-        // In finite field: if a >= b, diff is small; if a < b, diff wraps to huge value
+        // Custom gate: diff = a - b - offset (offset 0 for >=, 1 for >)
+        // In finite field: if a >= b + offset, diff is small; otherwise diff wraps to huge value
         meta.create_gate("comparison", |meta| {
             let q = meta.query_selector(q_cmp);
             let a = meta.query_advice(a, Rotation::cur());
             let b = meta.query_advice(b, Rotation::cur());
             let diff = meta.query_advice(diff, Rotation::cur());
+            let offset = meta.query_fixed(constant, Rotation::cur());
 
-            // Constraint: diff = a - b
-            // Rearranged: diff - a + b = 0
-            vec![q * (diff - a + b)]
+            // Constraint: diff = a - b - offset
+            // Rearranged: diff - a + b + offset = 0
+            vec![q * (diff - a + b + offset)]
+        });
+
+        // Constant bounds: diff = a - lo, or diff = hi - a
+        meta.create_gate("comparison with constant", |meta| {
+            let q_lower = meta.query_selector(q_lower);
+            let q_upper = meta.query_selector(q_upper);
+            let a = meta.query_advice(a, Rotation::cur());
+            let diff = meta.query_advice(diff, Rotation::cur());
+            let bound = meta.query_fixed(constant, Rotation::cur());
+
+            vec![
+                q_lower * (diff.clone() - a.clone() + bound.clone()),
+                q_upper * (diff - bound + a),
+            ]
+        });
+
+        // Is-zero gate: out = 1 - a * a_inv, a * out = 0
+        // If a != 0 the prover must use a_inv = 1/a (out = 0); if a == 0, out = 1
+        meta.create_gate("is zero", |meta| {
+            let q = meta.query_selector(q_is_zero);
+            let value = meta.query_advice(a, Rotation::cur());
+            let value_inv = meta.query_advice(b, Rotation::cur());
+            let out = meta.query_advice(diff, Rotation::cur());
+            let one = Expression::Constant(F::ONE);
+
+            vec![
+                q.clone() * (out.clone() - one + value.clone() * value_inv),
+                q * value * out,
+            ]
         });
 
         ComparisonConfig {
             a,
             b,
             diff,
+            constant,
             q_cmp,
+            q_lower,
+            q_upper,
+            q_is_zero,
+            range_bits,
             range_check,
             _marker: PhantomData,
         }
@@ -116,17 +231,28 @@ impl<F: PrimeField, const BITS: usize> ComparisonChip<F, BITS> {
         let range_chip = RangeCheckChip::<F, BITS>::construct(self.config.range_check.clone());
         range_chip.load_table(layouter)
     }
-}
 
-impl<F: PrimeField, const BITS: usize> ComparisonInstruction<F> for ComparisonChip<F, BITS> {
-    fn gte(
+    /// Range check an operand to [0, 2^bits) with the shared lookup table
+    pub fn range_check(
+        &self,
+        layouter: impl Layouter<F>,
+        value: AssignedCell<F, F>,
+        bits: usize,
+    ) -> Result<(), Error> {
+        let range_chip = RangeCheckChip::<F, BITS>::construct(self.config.range_check.clone());
+        range_chip.check(layouter, value, bits)
+    }
+
+    /// Assign diff = a - b - offset and range check it
+    fn gte_with_offset(
         &self,
         mut layouter: impl Layouter<F>,
         a: AssignedCell<F, F>,
         b: AssignedCell<F, F>,
+        offset: F,
     ) -> Result<(), Error> {
         let diff_cell = layouter.assign_region(
-            || "comparison: a >= b",
+            || "comparison: a >= b + offset",
             |mut region| {
                 // Enable the comparison selector
                 self.config.q_cmp.enable(&mut region, 0)?;
@@ -134,12 +260,13 @@ impl<F: PrimeField, const BITS: usize> ComparisonInstruction<F> for ComparisonCh
                 // Copy a and b to this region
                 a.copy_advice(|| "a", &mut region, self.config.a, 0)?;
                 b.copy_advice(|| "b", &mut region, self.config.b, 0)?;
+                region.assign_fixed(|| "offset", self.config.constant, 0, || Value::known(offset))?;
 
-                // Compute and assign diff = a - b
-                // In finite field: if a >= b, diff is small (in range)
-                // If a < b, diff = p - (b - a) is huge (fails range check)
+                // Compute and assign diff = a - b - offset
+                // In finite field: if a >= b + offset, diff is small (in range)
+                // Otherwise diff = p - (b + offset - a) is huge (fails range check)
                 let diff_value = a.value().zip(b.value()).map(|(a, b)| {
-                    *a - *b
+                    *a - *b - offset
                 });
 
                 region.assign_advice(|| "diff", self.config.diff, 0, || diff_value)
@@ -147,53 +274,167 @@ impl<F: PrimeField, const BITS: usize> ComparisonInstruction<F> for ComparisonCh
         )?;
 
         // Range check the difference
-        let range_chip = RangeCheckChip::<F, BITS>::construct(self.config.range_check.clone());
-        range_chip.check(
+        self.range_check(
             layouter.namespace(|| "range check diff"),
             diff_cell,
-            BITS,
-        )?;
+            self.config.range_bits,
+        )
+    }
+}
 
-        Ok(())
+impl<F: PrimeField, const BITS: usize> ComparisonInstruction<F> for ComparisonChip<F, BITS> {
+    fn gte(
+        &self,
+        layouter: impl Layouter<F>,
+        a: AssignedCell<F, F>,
+        b: AssignedCell<F, F>,
+    ) -> Result<(), Error> {
+        self.gte_with_offset(layouter, a, b, F::ZERO)
     }
 
     fn gt(
         &self,
-        mut layouter: impl Layouter<F>,
+        layouter: impl Layouter<F>,
         a: AssignedCell<F, F>,
         b: AssignedCell<F, F>,
     ) -> Result<(), Error> {
         // For a > b, we prove a >= b + 1
-        // This is equivalent to checking a - b - 1 >= 0
-        let b_plus_one = layouter.assign_region(
-            || "b + 1",
+        // The 1 is a fixed-column offset, so the prover cannot choose it
+        self.gte_with_offset(layouter, a, b, F::ONE)
+    }
+
+    fn lt(
+        &self,
+        layouter: impl Layouter<F>,
+        a: AssignedCell<F, F>,
+        b: AssignedCell<F, F>,
+    ) -> Result<(), Error> {
+        self.gt(layouter, b, a)
+    }
+
+    fn lte(
+        &self,
+        layouter: impl Layouter<F>,
+        a: AssignedCell<F, F>,
+        b: AssignedCell<F, F>,
+    ) -> Result<(), Error> {
+        self.gte(layouter, b, a)
+    }
+
+    fn is_zero(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: AssignedCell<F, F>,
+    ) -> Result<AssignedCell<F, F>, Error> {
+        layouter.assign_region(
+            || "is zero",
             |mut region| {
-                let b_val = b.value().map(|b| *b + F::ONE);
-                region.assign_advice(|| "b + 1", self.config.b, 0, || b_val)
+                self.config.q_is_zero.enable(&mut region, 0)?;
+
+                a.copy_advice(|| "a", &mut region, self.config.a, 0)?;
+
+                // a_inv = 1/a, or 0 when a == 0
+                let a_inv = a.value().map(|a| a.invert().unwrap_or(F::ZERO));
+                region.assign_advice(|| "a_inv", self.config.b, 0, || a_inv)?;
+
+                let out = a.value().map(|a| if bool::from(a.is_zero()) { F::ONE } else { F::ZERO });
+                region.assign_advice(|| "is_zero", self.config.diff, 0, || out)
+            },
+        )
+    }
+
+    fn is_equal(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: AssignedCell<F, F>,
+        b: AssignedCell<F, F>,
+    ) -> Result<AssignedCell<F, F>, Error> {
+        // diff = a - b through the comparison gate (offset 0), without a range check
+        let diff_cell = layouter.assign_region(
+            || "a - b",
+            |mut region| {
+                self.config.q_cmp.enable(&mut region, 0)?;
+
+                a.copy_advice(|| "a", &mut region, self.config.a, 0)?;
+                b.copy_advice(|| "b", &mut region, self.config.b, 0)?;
+                region.assign_fixed(|| "offset", self.config.constant, 0, || Value::known(F::ZERO))?;
+
+                let diff_value = a.value().zip(b.value()).map(|(a, b)| *a - *b);
+                region.assign_advice(|| "diff", self.config.diff, 0, || diff_value)
+            },
+        )?;
+
+        self.is_zero(layouter.namespace(|| "a - b == 0"), diff_cell)
+    }
+
+    fn in_range(
+        &self,
+        mut layouter: impl Layouter<F>,
+        value: AssignedCell<F, F>,
+        lo: F,
+        hi: F,
+    ) -> Result<(), Error> {
+        let (lower_diff, upper_diff) = layouter.assign_region(
+            || "in range: lo <= value <= hi",
+            |mut region| {
+                // Row 0: diff = value - lo
+                self.config.q_lower.enable(&mut region, 0)?;
+                value.copy_advice(|| "value", &mut region, self.config.a, 0)?;
+                region.assign_fixed(|| "lo", self.config.constant, 0, || Value::known(lo))?;
+                let lower_diff = region.assign_advice(
+                    || "value - lo",
+                    self.config.diff,
+                    0,
+                    || value.value().map(|v| *v - lo),
+                )?;
+
+                // Row 1: diff = hi - value
+                self.config.q_upper.enable(&mut region, 1)?;
+                value.copy_advice(|| "value", &mut region, self.config.a, 1)?;
+                region.assign_fixed(|| "hi", self.config.constant, 1, || Value::known(hi))?;
+                let upper_diff = region.assign_advice(
+                    || "hi - value",
+                    self.config.diff,
+                    1,
+                    || value.value().map(|v| hi - *v),
+                )?;
+
+                Ok((lower_diff, upper_diff))
             },
         )?;
 
-        self.gte(layouter.namespace(|| "a > b"), a, b_plus_one)
+        self.range_check(
+            layouter.namespace(|| "range check value - lo"),
+            lower_diff,
+            self.config.range_bits,
+        )?;
+        self.range_check(
+            layouter.namespace(|| "range check hi - value"),
+            upper_diff,
+            self.config.range_bits,
+        )
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use ff::Field;
     use halo2_proofs::{
-        circuit::{SimpleFloorPlanner, Value},
+        circuit::SimpleFloorPlanner,
         dev::MockProver,
-        plonk::Circuit,
+        plonk::{Circuit, Instance},
     };
     use pasta_curves::Fp;
 
+    /// Proves a >= b with a 2^BITS table and RANGE-bit operands
     #[derive(Clone)]
-    struct ComparisonTestCircuit<const BITS: usize> {
+    struct ComparisonTestCircuit<const BITS: usize, const RANGE: usize> {
         a: Value<Fp>,
         b: Value<Fp>,
     }
 
-    impl<const BITS: usize> Default for ComparisonTestCircuit<BITS> {
+    impl<const BITS: usize, const RANGE: usize> Default for ComparisonTestCircuit<BITS, RANGE> {
         fn default() -> Self {
             Self {
                 a: Value::unknown(),
@@ -202,7 +443,7 @@ mod tests {
         }
     }
 
-    impl<const BITS: usize> Circuit<Fp> for ComparisonTestCircuit<BITS> {
+    impl<const BITS: usize, const RANGE: usize> Circuit<Fp> for ComparisonTestCircuit<BITS, RANGE> {
         type Config = ComparisonConfig<Fp, BITS>;
         type FloorPlanner = SimpleFloorPlanner;
 
@@ -219,7 +460,7 @@ mod tests {
             meta.enable_equality(b);
             meta.enable_equality(diff);
 
-            ComparisonChip::<Fp, BITS>::configure(meta, a, b, diff)
+            ComparisonChip::<Fp, BITS>::configure_with_range_bits(meta, a, b, diff, RANGE)
         }
 
         fn synthesize(
@@ -263,7 +504,7 @@ mod tests {
         ];
 
         for (a, b) in test_cases {
-            let circuit = ComparisonTestCircuit::<BITS> {
+            let circuit = ComparisonTestCircuit::<BITS, BITS> {
                 a: Value::known(Fp::from(a)),
                 b: Value::known(Fp::from(b)),
             };
@@ -285,7 +526,7 @@ mod tests {
         const BITS: usize = 8;
 
         // Test case where a < b (should fail)
-        let circuit = ComparisonTestCircuit::<BITS> {
+        let circuit = ComparisonTestCircuit::<BITS, BITS> {
             a: Value::known(Fp::from(50u64)),
             b: Value::known(Fp::from(100u64)),
         };
@@ -293,4 +534,244 @@ mod tests {
         let prover = MockProver::run(k, &circuit, vec![]).unwrap();
         assert!(prover.verify().is_err(), "Should fail when a < b");
     }
+
+    #[test]
+    fn test_comparison_gte_64bit_valid() {
+        let k = 10;
+        const BITS: usize = 8;
+        const RANGE: usize = 64;
+
+        let max = u64::MAX as u128;
+        let test_cases = vec![
+            (max, max - 1),        // near 2^64
+            (max, max),            // equal at the top
+            (max, 0),              // full-width diff
+            (1 << 40, 12345),      // beyond a single limb
+        ];
+
+        for (a, b) in test_cases {
+            let circuit = ComparisonTestCircuit::<BITS, RANGE> {
+                a: Value::known(Fp::from_u128(a)),
+                b: Value::known(Fp::from_u128(b)),
+            };
+
+            let prover = MockProver::run(k, &circuit, vec![]).unwrap();
+            assert_eq!(prover.verify(), Ok(()), "Failed for a={}, b={}", a, b);
+        }
+    }
+
+    #[test]
+    fn test_comparison_gte_64bit_wraparound() {
+        let k = 10;
+        const BITS: usize = 8;
+        const RANGE: usize = 64;
+
+        // a < b: diff = p - (b - a) is a huge field element
+        let max = u64::MAX as u128;
+        let test_cases = vec![
+            (0u128, 1u128),
+            (max - 1, max),
+            (1 << 63, max),
+            (0, max),
+        ];
+
+        for (a, b) in test_cases {
+            let circuit = ComparisonTestCircuit::<BITS, RANGE> {
+                a: Value::known(Fp::from_u128(a)),
+                b: Value::known(Fp::from_u128(b)),
+            };
+
+            let prover = MockProver::run(k, &circuit, vec![]).unwrap();
+            assert!(prover.verify().is_err(), "Should fail for a={}, b={}", a, b);
+        }
+    }
+
+    #[derive(Clone, Copy, Debug)]
+    enum Op {
+        Gt,
+        Lt,
+        Lte,
+        /// Exposes the boolean result as instance[0]
+        IsZero,
+        /// Exposes the boolean result as instance[0]
+        IsEqual,
+        InRange(u64, u64),
+    }
+
+    /// Applies one comparison instruction to (a, b)
+    #[derive(Clone)]
+    struct OpTestCircuit<const BITS: usize> {
+        a: Value<Fp>,
+        b: Value<Fp>,
+        op: Op,
+    }
+
+    impl<const BITS: usize> OpTestCircuit<BITS> {
+        fn new(a: u64, b: u64, op: Op) -> Self {
+            Self {
+                a: Value::known(Fp::from(a)),
+                b: Value::known(Fp::from(b)),
+                op,
+            }
+        }
+    }
+
+    impl<const BITS: usize> Circuit<Fp> for OpTestCircuit<BITS> {
+        type Config = (ComparisonConfig<Fp, BITS>, Column<Instance>);
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self {
+                a: Value::unknown(),
+                b: Value::unknown(),
+                op: self.op,
+            }
+        }
+
+        fn configure(meta: &mut ConstraintSystem<Fp>) -> Self::Config {
+            let a = meta.advice_column();
+            let b = meta.advice_column();
+            let diff = meta.advice_column();
+            let instance = meta.instance_column();
+
+            meta.enable_equality(a);
+            meta.enable_equality(b);
+            meta.enable_equality(diff);
+            meta.enable_equality(instance);
+
+            (ComparisonChip::<Fp, BITS>::configure(meta, a, b, diff), instance)
+        }
+
+        fn synthesize(
+            &self,
+            (config, instance): Self::Config,
+            mut layouter: impl Layouter<Fp>,
+        ) -> Result<(), Error> {
+            let chip = ComparisonChip::<Fp, BITS>::construct(config.clone());
+            chip.load_table(layouter.namespace(|| "load table"))?;
+
+            let (a_cell, b_cell) = layouter.assign_region(
+                || "assign inputs",
+                |mut region| {
+                    let a = region.assign_advice(|| "a", config.a, 0, || self.a)?;
+                    let b = region.assign_advice(|| "b", config.b, 0, || self.b)?;
+                    Ok((a, b))
+                },
+            )?;
+
+            let ns = layouter.namespace(|| "op");
+            let out = match self.op {
+                Op::Gt => chip.gt(ns, a_cell, b_cell).map(|_| None),
+                Op::Lt => chip.lt(ns, a_cell, b_cell).map(|_| None),
+                Op::Lte => chip.lte(ns, a_cell, b_cell).map(|_| None),
+                Op::IsZero => chip.is_zero(ns, a_cell).map(Some),
+                Op::IsEqual => chip.is_equal(ns, a_cell, b_cell).map(Some),
+                Op::InRange(lo, hi) => chip
+                    .in_range(ns, a_cell, Fp::from(lo), Fp::from(hi))
+                    .map(|_| None),
+            }?;
+
+            if let Some(out) = out {
+                layouter.constrain_instance(out.cell(), instance, 0)?;
+            }
+
+            Ok(())
+        }
+    }
+
+    fn run_op(a: u64, b: u64, op: Op, public: Vec<Fp>) -> bool {
+        const BITS: usize = 8;
+        let circuit = OpTestCircuit::<BITS>::new(a, b, op);
+        MockProver::run(10, &circuit, vec![public]).unwrap().verify().is_ok()
+    }
+
+    #[test]
+    fn test_comparison_gt_valid() {
+        for (a, b) in [(100u64, 50u64), (255, 0), (1, 0)] {
+            assert!(run_op(a, b, Op::Gt, vec![]), "Failed for a={}, b={}", a, b);
+        }
+    }
+
+    #[test]
+    fn test_comparison_gt_invalid() {
+        // Equal values are not strictly greater
+        for (a, b) in [(100u64, 100u64), (50, 100), (0, 0)] {
+            assert!(!run_op(a, b, Op::Gt, vec![]), "Should fail for a={}, b={}", a, b);
+        }
+    }
+
+    #[test]
+    fn test_comparison_lt_valid() {
+        for (a, b) in [(50u64, 100u64), (0, 255), (99, 100)] {
+            assert!(run_op(a, b, Op::Lt, vec![]), "Failed for a={}, b={}", a, b);
+        }
+    }
+
+    #[test]
+    fn test_comparison_lt_invalid() {
+        for (a, b) in [(100u64, 100u64), (100, 50), (255, 0)] {
+            assert!(!run_op(a, b, Op::Lt, vec![]), "Should fail for a={}, b={}", a, b);
+        }
+    }
+
+    #[test]
+    fn test_comparison_lte_valid() {
+        for (a, b) in [(50u64, 100u64), (100, 100), (0, 0)] {
+            assert!(run_op(a, b, Op::Lte, vec![]), "Failed for a={}, b={}", a, b);
+        }
+    }
+
+    #[test]
+    fn test_comparison_lte_invalid() {
+        for (a, b) in [(101u64, 100u64), (255, 0)] {
+            assert!(!run_op(a, b, Op::Lte, vec![]), "Should fail for a={}, b={}", a, b);
+        }
+    }
+
+    #[test]
+    fn test_comparison_is_zero() {
+        let (one, zero) = (vec![Fp::ONE], vec![Fp::ZERO]);
+
+        assert!(run_op(0, 0, Op::IsZero, one.clone()));
+        assert!(run_op(7, 0, Op::IsZero, zero.clone()));
+
+        // The output cannot be flipped
+        assert!(!run_op(0, 0, Op::IsZero, zero));
+        assert!(!run_op(7, 0, Op::IsZero, one));
+    }
+
+    #[test]
+    fn test_comparison_is_equal() {
+        let (one, zero) = (vec![Fp::ONE], vec![Fp::ZERO]);
+
+        assert!(run_op(42, 42, Op::IsEqual, one.clone()));
+        assert!(run_op(42, 43, Op::IsEqual, zero.clone()));
+
+        // The output cannot be flipped
+        assert!(!run_op(42, 42, Op::IsEqual, zero));
+        assert!(!run_op(42, 43, Op::IsEqual, one));
+    }
+
+    #[test]
+    fn test_comparison_in_range_valid() {
+        // 0 < max_ltv <= 100
+        for value in [1u64, 50, 100] {
+            assert!(
+                run_op(value, 0, Op::InRange(1, 100), vec![]),
+                "Failed for value={}",
+                value
+            );
+        }
+    }
+
+    #[test]
+    fn test_comparison_in_range_invalid() {
+        for value in [0u64, 101, 255] {
+            assert!(
+                !run_op(value, 0, Op::InRange(1, 100), vec![]),
+                "Should fail for value={}",
+                value
+            );
+        }
+    }
 }
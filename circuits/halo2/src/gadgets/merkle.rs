@@ -0,0 +1,547 @@
+//! Merkle Tree Membership Gadget
+//!
+//! Proves that a leaf belongs to a Poseidon Merkle tree with a given root
+//! without revealing its position.
+//!
+//! # Hashing
+//! - Internal node: `Poseidon(left, right)` (`poseidon_hash`, `ConstantLength<2>`)
+//! - Empty leaf: `0`; empty subtree of height `l`: `zeros[l] = Poseidon(zeros[l-1], zeros[l-1])`
+//!
+//! # Path Encoding
+//! Level `i` of a path holds the sibling at that level and the bit `i` of the
+//! leaf index (little-endian): `0` if the current node is the left child,
+//! `1` if it is the right child. The depth of a proof is the path length,
+//! fixed by the circuit that uses the chip.
+//!
+//! # Constraints (per level)
+//! 1. `bit * (1 - bit) == 0`
+//! 2. `left = cur + bit * (sibling - cur)`, `right = sibling + bit * (cur - sibling)`
+//! 3. `next = Poseidon(left, right)` (via `PoseidonChip`)
+//!
+//! # Usage
+//! ```ignore
+//! let mut tree = IncrementalMerkleTree::<Fp>::new(20)?;
+//! let index = tree.insert(commitment)?;
+//! let path = tree.path(index)?;
+//! assert_eq!(path.compute_root(commitment), tree.root());
+//! ```
+
+use ff::PrimeField;
+use halo2_proofs::{
+    circuit::{AssignedCell, Layouter, Value},
+    plonk::{Advice, Column, ConstraintSystem, Error, Expression, Selector},
+    poly::Rotation,
+};
+use std::marker::PhantomData;
+
+use super::poseidon::{poseidon_hash, PoseidonChip, PoseidonConfig};
+use crate::error::{CircuitError, CircuitResult};
+
+/// Deepest tree supported by `IncrementalMerkleTree` (2^32 leaves)
+pub const MAX_DEPTH: usize = 32;
+
+/// Configuration for the Merkle path chip
+#[derive(Debug, Clone)]
+pub struct MerkleConfig<F: PrimeField> {
+    /// Current node (leaf at level 0)
+    pub cur: Column<Advice>,
+    /// Sibling at this level
+    pub sibling: Column<Advice>,
+    /// Path bit: 1 if `cur` is the right child
+    pub bit: Column<Advice>,
+    /// Left input of the node hash
+    pub left: Column<Advice>,
+    /// Right input of the node hash
+    pub right: Column<Advice>,
+    /// Selector for the conditional swap gate
+    pub q_swap: Selector,
+    /// Poseidon chip config for node hashes
+    pub poseidon: PoseidonConfig<F>,
+    _marker: PhantomData<F>,
+}
+
+/// Merkle path chip
+#[derive(Debug, Clone)]
+pub struct MerkleChip<F: PrimeField> {
+    config: MerkleConfig<F>,
+}
+
+impl<F: PrimeField> MerkleChip<F> {
+    pub fn construct(config: MerkleConfig<F>) -> Self {
+        Self { config }
+    }
+
+    /// Configure the Merkle path chip
+    ///
+    /// `advice` is `[cur, sibling, bit, left, right]`. The Poseidon chip is
+    /// configured over `[left, right, cur]`, so the gadget needs no further
+    /// advice columns.
+    pub fn configure(
+        meta: &mut ConstraintSystem<F>,
+        advice: [Column<Advice>; 5],
+    ) -> MerkleConfig<F> {
+        let [cur, sibling, bit, left, right] = advice;
+        let q_swap = meta.selector();
+
+        for col in advice {
+            meta.enable_equality(col);
+        }
+
+        // Order (cur, sibling) by the path bit
+        meta.create_gate("merkle swap", |meta| {
+            let q = meta.query_selector(q_swap);
+            let cur = meta.query_advice(cur, Rotation::cur());
+            let sibling = meta.query_advice(sibling, Rotation::cur());
+            let bit = meta.query_advice(bit, Rotation::cur());
+            let left = meta.query_advice(left, Rotation::cur());
+            let right = meta.query_advice(right, Rotation::cur());
+            let one = Expression::Constant(F::ONE);
+
+            vec![
+                q.clone() * bit.clone() * (one - bit.clone()),
+                q.clone() * (left - cur.clone() - bit.clone() * (sibling.clone() - cur.clone())),
+                q * (right - sibling.clone() - bit * (cur - sibling)),
+            ]
+        });
+
+        let poseidon = PoseidonChip::configure(meta, [left, right, cur]);
+
+        MerkleConfig {
+            cur,
+            sibling,
+            bit,
+            left,
+            right,
+            q_swap,
+            poseidon,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Compute the root of the tree containing `leaf` along the given path
+    ///
+    /// `siblings[i]` and `path_bits[i]` describe level `i` (leaf level first).
+    /// The caller constrains the returned cell, typically to a public root.
+    pub fn root(
+        &self,
+        mut layouter: impl Layouter<F>,
+        leaf: AssignedCell<F, F>,
+        siblings: &[Value<F>],
+        path_bits: &[Value<F>],
+    ) -> Result<AssignedCell<F, F>, Error> {
+        if siblings.len() != path_bits.len() {
+            return Err(Error::Synthesis);
+        }
+
+        let poseidon_chip = PoseidonChip::construct(self.config.poseidon.clone());
+        let mut node = leaf;
+
+        for (level, (sibling, bit)) in siblings.iter().zip(path_bits).enumerate() {
+            let (left, right) = layouter.assign_region(
+                || format!("merkle level {}", level),
+                |mut region| {
+                    self.config.q_swap.enable(&mut region, 0)?;
+
+                    let cur = node.copy_advice(|| "cur", &mut region, self.config.cur, 0)?;
+                    region.assign_advice(|| "sibling", self.config.sibling, 0, || *sibling)?;
+                    region.assign_advice(|| "bit", self.config.bit, 0, || *bit)?;
+
+                    let cur = cur.value().copied();
+                    let left_val = cur.zip(*sibling).zip(*bit).map(|((c, s), b)| c + b * (s - c));
+                    let right_val = cur.zip(*sibling).zip(*bit).map(|((c, s), b)| s + b * (c - s));
+
+                    let left = region.assign_advice(|| "left", self.config.left, 0, || left_val)?;
+                    let right = region.assign_advice(|| "right", self.config.right, 0, || right_val)?;
+
+                    Ok((left, right))
+                },
+            )?;
+
+            node = poseidon_chip.hash(layouter.namespace(|| format!("merkle hash {}", level)), left, right)?;
+        }
+
+        Ok(node)
+    }
+}
+
+// ============================================================================
+// OFF-CIRCUIT TREE
+// ============================================================================
+
+/// Authentication path from a leaf to the root
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MerklePath<F: PrimeField> {
+    /// Sibling at each level, leaf level first
+    pub siblings: Vec<F>,
+    /// `true` where the node on the path is a right child
+    pub path_bits: Vec<bool>,
+}
+
+impl<F: PrimeField> MerklePath<F> {
+    /// Tree depth this path belongs to
+    pub fn depth(&self) -> usize {
+        self.siblings.len()
+    }
+
+    /// Leaf index encoded by the path bits
+    pub fn index(&self) -> u64 {
+        self.path_bits
+            .iter()
+            .enumerate()
+            .fold(0, |acc, (i, bit)| acc | (u64::from(*bit) << i))
+    }
+
+    /// Hash `leaf` up the path, matching `MerkleChip::root`
+    pub fn compute_root(&self, leaf: F) -> F {
+        self.siblings
+            .iter()
+            .zip(&self.path_bits)
+            .fold(leaf, |node, (sibling, is_right)| {
+                if *is_right {
+                    poseidon_hash(*sibling, node)
+                } else {
+                    poseidon_hash(node, *sibling)
+                }
+            })
+    }
+
+    /// Path bits as field elements, for circuit witnesses
+    pub fn path_bits_as_field(&self) -> Vec<F> {
+        self.path_bits
+            .iter()
+            .map(|bit| if *bit { F::ONE } else { F::ZERO })
+            .collect()
+    }
+}
+
+/// Append-only Poseidon Merkle tree
+///
+/// Leaves are inserted left to right; unfilled positions hold the empty
+/// leaf `0`. Only the non-empty nodes of each level are stored.
+#[derive(Debug, Clone)]
+pub struct IncrementalMerkleTree<F: PrimeField> {
+    depth: usize,
+    /// `zeros[l]`: root of an empty subtree of height `l`
+    zeros: Vec<F>,
+    /// `layers[l]`: non-empty nodes at level `l` (leaves at level 0)
+    layers: Vec<Vec<F>>,
+}
+
+impl<F: PrimeField> IncrementalMerkleTree<F> {
+    /// Create an empty tree of the given depth (1..=MAX_DEPTH)
+    pub fn new(depth: usize) -> CircuitResult<Self> {
+        if depth == 0 || depth > MAX_DEPTH {
+            return Err(CircuitError::InvalidConfiguration {
+                message: format!("Merkle depth must be between 1 and {}, got {}", MAX_DEPTH, depth),
+            });
+        }
+
+        let mut zeros = Vec::with_capacity(depth + 1);
+        zeros.push(F::ZERO);
+        for l in 0..depth {
+            zeros.push(poseidon_hash(zeros[l], zeros[l]));
+        }
+
+        Ok(Self {
+            depth,
+            zeros,
+            layers: vec![Vec::new(); depth + 1],
+        })
+    }
+
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Number of leaves inserted
+    pub fn len(&self) -> u64 {
+        self.layers[0].len() as u64
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.layers[0].is_empty()
+    }
+
+    /// Maximum number of leaves (2^depth)
+    pub fn capacity(&self) -> u64 {
+        1u64 << self.depth
+    }
+
+    /// Current root
+    pub fn root(&self) -> F {
+        self.layers[self.depth]
+            .first()
+            .copied()
+            .unwrap_or(self.zeros[self.depth])
+    }
+
+    /// Append a leaf and return its index
+    pub fn insert(&mut self, leaf: F) -> CircuitResult<u64> {
+        let index = self.len();
+        if index >= self.capacity() {
+            return Err(CircuitError::MerkleTreeFull {
+                capacity: self.capacity(),
+            });
+        }
+
+        self.layers[0].push(leaf);
+
+        // Recompute the ancestors of the new leaf
+        let mut idx = index as usize;
+        for l in 0..self.depth {
+            let parent = idx / 2;
+            let left = self.layers[l][2 * parent];
+            let right = self.node(l, 2 * parent + 1);
+            let hash = poseidon_hash(left, right);
+
+            if parent < self.layers[l + 1].len() {
+                self.layers[l + 1][parent] = hash;
+            } else {
+                self.layers[l + 1].push(hash);
+            }
+            idx = parent;
+        }
+
+        Ok(index)
+    }
+
+    /// Authentication path for the leaf at `index`
+    pub fn path(&self, index: u64) -> CircuitResult<MerklePath<F>> {
+        if index >= self.len() {
+            return Err(CircuitError::ValueOutOfRange {
+                value: index,
+                max: self.len().saturating_sub(1),
+                field: "leaf index".to_string(),
+            });
+        }
+
+        let mut siblings = Vec::with_capacity(self.depth);
+        let mut path_bits = Vec::with_capacity(self.depth);
+        let mut idx = index as usize;
+        for l in 0..self.depth {
+            siblings.push(self.node(l, idx ^ 1));
+            path_bits.push(idx & 1 == 1);
+            idx /= 2;
+        }
+
+        Ok(MerklePath { siblings, path_bits })
+    }
+
+    /// Node at `level`/`idx`, or the empty subtree root if not yet filled
+    fn node(&self, level: usize, idx: usize) -> F {
+        self.layers[level].get(idx).copied().unwrap_or(self.zeros[level])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ff::Field;
+    use halo2_proofs::{
+        circuit::SimpleFloorPlanner,
+        dev::MockProver,
+        plonk::{Circuit, Instance},
+    };
+    use pasta_curves::Fp;
+
+    #[derive(Clone)]
+    struct MerkleTestCircuit<const DEPTH: usize> {
+        leaf: Value<Fp>,
+        siblings: Vec<Value<Fp>>,
+        path_bits: Vec<Value<Fp>>,
+    }
+
+    impl<const DEPTH: usize> MerkleTestCircuit<DEPTH> {
+        fn new(leaf: Fp, siblings: &[Fp], path_bits: &[Fp]) -> Self {
+            Self {
+                leaf: Value::known(leaf),
+                siblings: siblings.iter().map(|s| Value::known(*s)).collect(),
+                path_bits: path_bits.iter().map(|b| Value::known(*b)).collect(),
+            }
+        }
+
+        fn from_path(leaf: Fp, path: &MerklePath<Fp>) -> Self {
+            Self::new(leaf, &path.siblings, &path.path_bits_as_field())
+        }
+    }
+
+    impl<const DEPTH: usize> Circuit<Fp> for MerkleTestCircuit<DEPTH> {
+        type Config = (MerkleConfig<Fp>, Column<Instance>);
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self {
+                leaf: Value::unknown(),
+                siblings: vec![Value::unknown(); DEPTH],
+                path_bits: vec![Value::unknown(); DEPTH],
+            }
+        }
+
+        fn configure(meta: &mut ConstraintSystem<Fp>) -> Self::Config {
+            let advice = [(); 5].map(|_| meta.advice_column());
+            let instance = meta.instance_column();
+            meta.enable_equality(instance);
+
+            (MerkleChip::configure(meta, advice), instance)
+        }
+
+        fn synthesize(
+            &self,
+            (config, instance): Self::Config,
+            mut layouter: impl Layouter<Fp>,
+        ) -> Result<(), Error> {
+            let leaf = layouter.assign_region(
+                || "leaf",
+                |mut region| region.assign_advice(|| "leaf", config.cur, 0, || self.leaf),
+            )?;
+
+            let chip = MerkleChip::construct(config);
+            let root = chip.root(layouter.namespace(|| "root"), leaf, &self.siblings, &self.path_bits)?;
+
+            layouter.constrain_instance(root.cell(), instance, 0)
+        }
+    }
+
+    /// Tree of the given depth filled with `count` leaves, returning (tree, leaves)
+    fn tree_with_leaves(depth: usize, count: u64) -> (IncrementalMerkleTree<Fp>, Vec<Fp>) {
+        let mut tree = IncrementalMerkleTree::new(depth).unwrap();
+        let leaves: Vec<Fp> = (0..count)
+            .map(|i| poseidon_hash(Fp::from(1000 + i), Fp::from(12345u64)))
+            .collect();
+        for leaf in &leaves {
+            tree.insert(*leaf).unwrap();
+        }
+        (tree, leaves)
+    }
+
+    #[test]
+    fn test_empty_tree_root() {
+        let tree = IncrementalMerkleTree::<Fp>::new(3).unwrap();
+
+        let z1 = poseidon_hash(Fp::ZERO, Fp::ZERO);
+        let z2 = poseidon_hash(z1, z1);
+        assert_eq!(tree.root(), poseidon_hash(z2, z2));
+        assert!(tree.is_empty());
+    }
+
+    #[test]
+    fn test_root_matches_full_tree() {
+        let (tree, leaves) = tree_with_leaves(3, 5);
+
+        // Hash the padded level-by-level tree directly
+        let mut level: Vec<Fp> = leaves.clone();
+        level.resize(8, Fp::ZERO);
+        while level.len() > 1 {
+            level = level.chunks(2).map(|pair| poseidon_hash(pair[0], pair[1])).collect();
+        }
+
+        assert_eq!(tree.root(), level[0]);
+    }
+
+    #[test]
+    fn test_paths_verify() {
+        let (tree, leaves) = tree_with_leaves(4, 7);
+
+        for (i, leaf) in leaves.iter().enumerate() {
+            let path = tree.path(i as u64).unwrap();
+            assert_eq!(path.depth(), 4);
+            assert_eq!(path.index(), i as u64);
+            assert_eq!(path.compute_root(*leaf), tree.root());
+        }
+
+        // A path does not verify a different leaf
+        let path = tree.path(0).unwrap();
+        assert_ne!(path.compute_root(leaves[1]), tree.root());
+
+        // No path for a leaf that was never inserted
+        assert!(tree.path(7).is_err());
+    }
+
+    #[test]
+    fn test_tree_full() {
+        let (mut tree, _) = tree_with_leaves(2, 4);
+
+        assert_eq!(tree.len(), tree.capacity());
+        assert_eq!(
+            tree.insert(Fp::from(1u64)),
+            Err(CircuitError::MerkleTreeFull { capacity: 4 })
+        );
+    }
+
+    #[test]
+    fn test_invalid_depth() {
+        assert!(IncrementalMerkleTree::<Fp>::new(0).is_err());
+        assert!(IncrementalMerkleTree::<Fp>::new(MAX_DEPTH + 1).is_err());
+    }
+
+    #[test]
+    fn test_merkle_chip_depth_20() {
+        let k = 12;
+        let (tree, leaves) = tree_with_leaves(20, 6);
+
+        for index in [0u64, 3, 5] {
+            let path = tree.path(index).unwrap();
+            let circuit = MerkleTestCircuit::<20>::from_path(leaves[index as usize], &path);
+
+            let prover = MockProver::run(k, &circuit, vec![vec![tree.root()]]).unwrap();
+            assert_eq!(prover.verify(), Ok(()), "Leaf {} should be a member", index);
+        }
+    }
+
+    #[test]
+    fn test_merkle_chip_rejects_invalid_sibling() {
+        let k = 12;
+        let (tree, leaves) = tree_with_leaves(20, 6);
+        let mut path = tree.path(3).unwrap();
+        path.siblings[1] += Fp::ONE;
+
+        let circuit = MerkleTestCircuit::<20>::from_path(leaves[3], &path);
+
+        let prover = MockProver::run(k, &circuit, vec![vec![tree.root()]]).unwrap();
+        assert!(prover.verify().is_err(), "Tampered sibling should fail");
+    }
+
+    #[test]
+    fn test_merkle_chip_rejects_non_member() {
+        let k = 12;
+        let (tree, _) = tree_with_leaves(20, 6);
+        let path = tree.path(3).unwrap();
+
+        let circuit = MerkleTestCircuit::<20>::from_path(Fp::from(999u64), &path);
+
+        let prover = MockProver::run(k, &circuit, vec![vec![tree.root()]]).unwrap();
+        assert!(prover.verify().is_err(), "Non-member leaf should fail");
+    }
+
+    #[test]
+    fn test_merkle_chip_rejects_flipped_bit() {
+        let k = 12;
+        let (tree, leaves) = tree_with_leaves(20, 6);
+        let mut path = tree.path(3).unwrap();
+        path.path_bits[0] = !path.path_bits[0];
+
+        let circuit = MerkleTestCircuit::<20>::from_path(leaves[3], &path);
+
+        let prover = MockProver::run(k, &circuit, vec![vec![tree.root()]]).unwrap();
+        assert!(prover.verify().is_err(), "Wrong path bit should fail");
+    }
+
+    #[test]
+    fn test_merkle_chip_rejects_non_boolean_bit() {
+        let k = 10;
+        let (tree, leaves) = tree_with_leaves(2, 4);
+        let path = tree.path(1).unwrap();
+
+        // bit = 2 swaps into (2 * sibling - cur, 2 * cur - sibling)
+        let mut bits = path.path_bits_as_field();
+        bits[0] = Fp::from(2u64);
+        let left = Fp::from(2u64) * path.siblings[0] - leaves[1];
+        let right = Fp::from(2u64) * leaves[1] - path.siblings[0];
+        let root = poseidon_hash(poseidon_hash(left, right), path.siblings[1]);
+
+        let circuit = MerkleTestCircuit::<2>::new(leaves[1], &path.siblings, &bits);
+
+        let prover = MockProver::run(k, &circuit, vec![vec![root]]).unwrap();
+        assert!(prover.verify().is_err(), "Non-boolean path bit should fail");
+    }
+}
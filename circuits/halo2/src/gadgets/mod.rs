@@ -1,15 +1,19 @@
 //! Reusable gadgets for ZK circuits
 //!
 //! - `RangeCheckChip`: Efficient range checks using lookup tables
-//! - `ComparisonChip`: Greater-than-or-equal comparisons
+//! - `ComparisonChip`: Ordering, equality and interval checks
+//! - `MerkleChip`: Poseidon Merkle path membership
 //! - `PoseidonChip`: Poseidon hash for secure commitments
+//! - `PoseidonSponge`: Poseidon over any number of inputs
 //! - `SimpleCommitmentChip`: Simple commitment for testing
 
 pub mod comparison;
+pub mod merkle;
 pub mod poseidon;
 pub mod range_check;
 
 pub use comparison::{ComparisonChip, ComparisonConfig, ComparisonInstruction};
+pub use merkle::{IncrementalMerkleTree, MerkleChip, MerkleConfig, MerklePath};
 pub use poseidon::simple::{SimpleCommitmentChip, SimpleCommitmentConfig};
-pub use poseidon::{PoseidonChip, PoseidonConfig};
+pub use poseidon::{PoseidonChip, PoseidonConfig, PoseidonSponge};
 pub use range_check::{RangeCheckChip, RangeCheckConfig, RangeCheckInstruction};
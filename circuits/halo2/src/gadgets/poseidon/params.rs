@@ -0,0 +1,290 @@
+//! Poseidon Parameters (Grain LFSR)
+//!
+//! Generates round constants and the MDS matrix exactly as the Poseidon
+//! reference implementation (`generate_parameters_grain.sage`) does, for
+//! width 3, rate 2, x^5 S-box, R_F = 8 and R_P = `partial_rounds::<F>()`.
+//!
+//! On the Pallas base field these are the `P128Pow5T3` parameters used by
+//! `halo2_gadgets`, so `poseidon_hash` agrees with other Halo2 Poseidon users.
+//! On the BN254 scalar field (R_P = 57) the round constants are the circomlib
+//! ones for t = 3; hashes still differ from circomlib's, which starts from a
+//! zero capacity instead of the `ConstantLength` domain tag.
+//!
+//! # Derivation
+//! 1. Seed an 80-bit Grain LFSR with (field type, S-box, field bits, t, R_F, R_P)
+//! 2. Discard the first 160 output bits
+//! 3. Self-shrink: read bits in pairs, emit the second bit when the first is 1
+//! 4. Round constants: `(R_F + R_P) * t` field elements, big-endian, rejection sampled
+//! 5. MDS: Cauchy matrix `1 / (x_i + y_j)` over 2t further elements (reduced mod p)
+
+use ff::PrimeField;
+use std::any::{Any, TypeId};
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Mutex, OnceLock};
+
+use super::{partial_rounds, FULL_ROUNDS, POSEIDON_WIDTH};
+
+/// Grain LFSR state size in bits
+const GRAIN_STATE_BITS: usize = 80;
+
+/// Index of the MDS matrix to use among those sampled from the Grain stream
+///
+/// The reference implementation rejects matrices that fail its algorithms 1-3
+/// (infinitely long invariant subspace trails). For t = 3 on Pallas the first
+/// sampled matrix passes, matching `P128Pow5T3Gen<Fp, 0>` in `halo2_gadgets`.
+const SECURE_MDS: usize = 0;
+
+/// Grain LFSR in self-shrinking mode, as specified in the Poseidon paper (Appendix F)
+struct Grain {
+    state: VecDeque<bool>,
+}
+
+impl Grain {
+    /// Initialize for a prime field with an x^alpha S-box
+    fn new(field_bits: u32, t: usize, r_f: usize, r_p: usize) -> Self {
+        let mut state = VecDeque::with_capacity(GRAIN_STATE_BITS);
+        let mut push_bits = |value: u64, len: usize| {
+            for i in (0..len).rev() {
+                state.push_back((value >> i) & 1 == 1);
+            }
+        };
+
+        push_bits(1, 2); // field type: GF(p)
+        push_bits(0, 4); // S-box: x^alpha
+        push_bits(field_bits as u64, 12);
+        push_bits(t as u64, 12);
+        push_bits(r_f as u64, 10);
+        push_bits(r_p as u64, 10);
+        push_bits((1 << 30) - 1, 30);
+
+        let mut grain = Self { state };
+
+        // Discard the first 160 bits
+        for _ in 0..160 {
+            grain.step();
+        }
+
+        grain
+    }
+
+    /// Clock the LFSR once: b_{i+80} = b_{i+62} ^ b_{i+51} ^ b_{i+38} ^ b_{i+23} ^ b_{i+13} ^ b_i
+    fn step(&mut self) -> bool {
+        let s = &self.state;
+        let bit = s[62] ^ s[51] ^ s[38] ^ s[23] ^ s[13] ^ s[0];
+        self.state.pop_front();
+        self.state.push_back(bit);
+        bit
+    }
+
+    /// Next output bit of the self-shrinking generator
+    fn next_bit(&mut self) -> bool {
+        loop {
+            let keep = self.step();
+            let bit = self.step();
+            if keep {
+                return bit;
+            }
+        }
+    }
+
+    /// Next field element, rejecting values >= p (used for round constants)
+    ///
+    /// Bits are read most-significant first, as in the reference implementation.
+    /// Assumes a little-endian `Repr`, which holds for the Pasta and BN254 fields.
+    fn next_field_element<F: PrimeField>(&mut self) -> F {
+        loop {
+            let mut repr = F::Repr::default();
+            let view = repr.as_mut();
+            for i in (0..F::NUM_BITS as usize).rev() {
+                if self.next_bit() {
+                    view[i / 8] |= 1 << (i % 8);
+                }
+            }
+
+            if let Some(f) = Option::<F>::from(F::from_repr(repr)) {
+                return f;
+            }
+        }
+    }
+
+    /// Next field element reduced mod p, without rejection (used for the MDS matrix)
+    fn next_field_element_without_rejection<F: PrimeField>(&mut self) -> F {
+        let mut acc = F::ZERO;
+        for _ in 0..F::NUM_BITS {
+            acc = acc.double();
+            if self.next_bit() {
+                acc += F::ONE;
+            }
+        }
+        acc
+    }
+}
+
+/// Round constants and MDS matrix for the Poseidon permutation
+#[derive(Debug, Clone)]
+pub struct PoseidonParams<F: PrimeField> {
+    /// One `[F; POSEIDON_WIDTH]` per round (FULL_ROUNDS + partial_rounds)
+    pub round_constants: Vec<[F; POSEIDON_WIDTH]>,
+    /// MDS matrix for the linear layer
+    pub mds: [[F; POSEIDON_WIDTH]; POSEIDON_WIDTH],
+}
+
+impl<F: PrimeField> PoseidonParams<F> {
+    /// Run the Grain LFSR and derive all parameters
+    ///
+    /// Costs a few hundred thousand LFSR steps; use `params()` for the cached copy.
+    pub fn generate() -> Self {
+        let partial_rounds = partial_rounds::<F>();
+        let total_rounds = FULL_ROUNDS + partial_rounds;
+        let mut grain = Grain::new(F::NUM_BITS, POSEIDON_WIDTH, FULL_ROUNDS, partial_rounds);
+
+        let round_constants = (0..total_rounds)
+            .map(|_| {
+                let mut round = [F::ZERO; POSEIDON_WIDTH];
+                for c in round.iter_mut() {
+                    *c = grain.next_field_element();
+                }
+                round
+            })
+            .collect();
+
+        let mds = Self::generate_mds(&mut grain);
+
+        Self {
+            round_constants,
+            mds,
+        }
+    }
+
+    /// Sample a Cauchy matrix M[i][j] = 1 / (x_i + y_j) from the Grain stream
+    fn generate_mds(grain: &mut Grain) -> [[F; POSEIDON_WIDTH]; POSEIDON_WIDTH] {
+        let mut select = SECURE_MDS;
+
+        loop {
+            // 2t pairwise distinct elements
+            let vals = loop {
+                let vals: Vec<F> = (0..2 * POSEIDON_WIDTH)
+                    .map(|_| grain.next_field_element_without_rejection())
+                    .collect();
+
+                let distinct = vals
+                    .iter()
+                    .enumerate()
+                    .all(|(i, a)| vals[i + 1..].iter().all(|b| a != b));
+                if distinct {
+                    break vals;
+                }
+            };
+            let (xs, ys) = vals.split_at(POSEIDON_WIDTH);
+
+            if xs.iter().any(|x| ys.iter().any(|y| bool::from((*x + *y).is_zero()))) {
+                continue;
+            }
+
+            if select != 0 {
+                select -= 1;
+                continue;
+            }
+
+            let mut mds = [[F::ZERO; POSEIDON_WIDTH]; POSEIDON_WIDTH];
+            for (i, row) in mds.iter_mut().enumerate() {
+                for (j, entry) in row.iter_mut().enumerate() {
+                    *entry = (xs[i] + ys[j]).invert().unwrap();
+                }
+            }
+            return mds;
+        }
+    }
+}
+
+/// Cached parameters for field `F`
+///
+/// Generated on first use per field and kept for the lifetime of the process.
+pub fn params<F: PrimeField>() -> &'static PoseidonParams<F> {
+    static CACHE: OnceLock<Mutex<HashMap<TypeId, &'static (dyn Any + Send + Sync)>>> =
+        OnceLock::new();
+
+    let mut cache = CACHE
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(|e| e.into_inner());
+
+    let entry = *cache.entry(TypeId::of::<F>()).or_insert_with(|| {
+        let params: &'static PoseidonParams<F> = Box::leak(Box::new(PoseidonParams::generate()));
+        params as &'static (dyn Any + Send + Sync)
+    });
+
+    entry
+        .downcast_ref::<PoseidonParams<F>>()
+        .expect("cache entries are keyed by field type")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pasta_curves::Fp;
+
+    fn fp(decimal: &str) -> Fp {
+        Fp::from_str_vartime(decimal).unwrap()
+    }
+
+    /// Values from the Poseidon reference implementation
+    /// (`generate_parameters_grain.sage 1 0 255 3 8 56 <pallas p>`)
+    #[test]
+    fn test_round_constants_match_reference() {
+        let params = params::<Fp>();
+
+        assert_eq!(params.round_constants.len(), FULL_ROUNDS + partial_rounds::<Fp>());
+        assert_eq!(
+            params.round_constants[0][0],
+            fp("24448666467656506447555018649749346340705294023832615387641453784702583464707")
+        );
+        assert_eq!(
+            params.round_constants[0][1],
+            fp("19752610610343814834081989345964253902282700341539483876504601969121084774539")
+        );
+        assert_eq!(
+            params.round_constants[63][2],
+            fp("26478650004402903178047977963783244343981356179413342953452391396292365740114")
+        );
+    }
+
+    /// Values from circomlib's `poseidon_constants` for t = 3
+    /// (`generate_parameters_grain.sage 1 0 254 3 8 57 <bn254 r>`)
+    #[test]
+    fn test_bn254_round_constants_match_reference() {
+        use halo2_proofs::halo2curves::bn256::Fr;
+
+        let params = params::<Fr>();
+
+        assert_eq!(params.round_constants.len(), FULL_ROUNDS + 57);
+        assert_eq!(
+            params.round_constants[0][0],
+            Fr::from_str_vartime("6745197990210204598374042828761989596302876299545964402857411729872131034734").unwrap()
+        );
+        assert_eq!(
+            params.round_constants[0][1],
+            Fr::from_str_vartime("426281677759936592021316809065178817848084678679510574715894138690250139748").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_mds_matches_reference() {
+        let params = params::<Fp>();
+
+        assert_eq!(
+            params.mds[0][0],
+            fp("4844513277385895547578596669280046666372576567380472439333234012806535256931")
+        );
+        assert_eq!(
+            params.mds[2][2],
+            fp("27123552791154096240274588421608257979835967097480491934880175221940903501553")
+        );
+    }
+
+    #[test]
+    fn test_params_cached() {
+        assert!(std::ptr::eq(params::<Fp>(), params::<Fp>()));
+        assert_eq!(params::<Fp>().mds, PoseidonParams::<Fp>::generate().mds);
+    }
+}
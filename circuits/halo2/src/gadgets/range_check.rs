@@ -5,16 +5,35 @@
 //! - Halo2 lookup: 1 constraint
 //! - R1CS bit decomposition: ~BITS constraints
 //!
+//! # Wide Values (Running Sum)
+//! Values wider than the table are split into `BITS`-bit limbs with a running sum:
+//! - `z_0 = value`, `z_{i+1} = (z_i - limb_i) / 2^BITS`
+//! - each `limb_i = z_i - 2^BITS * z_{i+1}` is looked up in the table
+//! - the last `z_{n-1}` is looked up directly, so `value < 2^(BITS * n)`
+//!
+//! A 64-bit check with a 16-bit table costs 4 lookups, a 128-bit check 8.
+//!
+//! # Narrow Values (Shifted Lookup)
+//! For `bits < BITS` (and the top limb of a wide check) both `v` and
+//! `v * 2^(BITS - bits)` are looked up. The second lookup only succeeds when
+//! `v < 2^bits`, so one table serves every width up to `BITS`.
+//!
 //! # Example
 //! ```ignore
 //! // Check that value is in range [0, 256)
 //! range_check_chip.check(layouter, value, 8)?;
+//!
+//! // Check that a percentage fits in 7 bits with the same table
+//! range_check_chip.check(layouter, ltv, 7)?;
+//!
+//! // Check that value is in range [0, 2^64) with a 16-bit table
+//! range_check_chip.check(layouter, value, 64)?;
 //! ```
 
 use ff::PrimeField;
 use halo2_proofs::{
     circuit::{AssignedCell, Layouter, Value},
-    plonk::{Advice, Column, ConstraintSystem, Error, Selector, TableColumn},
+    plonk::{Advice, Column, ConstraintSystem, Error, Expression, Fixed, Selector, TableColumn},
     poly::Rotation,
 };
 use std::marker::PhantomData;
@@ -26,6 +45,12 @@ pub struct RangeCheckConfig<F: PrimeField, const BITS: usize> {
     pub value: Column<Advice>,
     /// Selector to enable the lookup
     pub q_lookup: Selector,
+    /// Selector switching the lookup to the running-sum limb `z_cur - 2^BITS * z_next`
+    pub q_running: Selector,
+    /// Selector for the shift gate `z_next = z_cur * shift`
+    pub q_shift: Selector,
+    /// Fixed column holding `2^(BITS - bits)` for short checks
+    pub shift: Column<Fixed>,
     /// Table column containing valid range values
     pub table: TableColumn,
     _marker: PhantomData<F>,
@@ -34,6 +59,8 @@ pub struct RangeCheckConfig<F: PrimeField, const BITS: usize> {
 /// Instructions for the range check chip
 pub trait RangeCheckInstruction<F: PrimeField> {
     /// Check that value is within [0, 2^bits)
+    ///
+    /// Any `bits` up to the field capacity is enforced exactly.
     fn check(
         &self,
         layouter: impl Layouter<F>,
@@ -58,18 +85,38 @@ impl<F: PrimeField, const BITS: usize> RangeCheckChip<F, BITS> {
     ///
     /// This sets up:
     /// 1. A lookup table with values [0, 2^BITS)
-    /// 2. A lookup argument that checks value is in the table
+    /// 2. A lookup argument that checks value (or a running-sum limb) is in the table
+    /// 3. A shift gate for bounds narrower than the table
     pub fn configure(
         meta: &mut ConstraintSystem<F>,
         value: Column<Advice>,
     ) -> RangeCheckConfig<F, BITS> {
         let q_lookup = meta.complex_selector();
+        let q_running = meta.complex_selector();
+        let q_shift = meta.selector();
+        let shift = meta.fixed_column();
         let table = meta.lookup_table_column();
 
+        // Shift gate: shifted = z * 2^(BITS - bits)
+        meta.create_gate("range check shift", |meta| {
+            let q = meta.query_selector(q_shift);
+            let z = meta.query_advice(value, Rotation::cur());
+            let shifted = meta.query_advice(value, Rotation::next());
+            let shift = meta.query_fixed(shift, Rotation::cur());
+
+            vec![q * (shifted - z * shift)]
+        });
+
         // Configure the lookup: value must exist in table
         meta.lookup("range check", |meta| {
             let q = meta.query_selector(q_lookup);
-            let v = meta.query_advice(value, Rotation::cur());
+            let q_running = meta.query_selector(q_running);
+            let z_cur = meta.query_advice(value, Rotation::cur());
+            let z_next = meta.query_advice(value, Rotation::next());
+            let shift = Expression::Constant(F::from(1u64 << BITS));
+
+            // Plain check: z_cur; running sum: limb = z_cur - 2^BITS * z_next
+            let v = z_cur - q_running * z_next * shift;
 
             // When q_lookup is enabled, v must be in the table
             vec![(q * v, table)]
@@ -78,11 +125,25 @@ impl<F: PrimeField, const BITS: usize> RangeCheckChip<F, BITS> {
         RangeCheckConfig {
             value,
             q_lookup,
+            q_running,
+            q_shift,
+            shift,
             table,
             _marker: PhantomData,
         }
     }
 
+    /// Number of `BITS`-bit limbs needed to cover `bits`
+    ///
+    /// Fails if `bits` reaches the field size, where the running sum could
+    /// wrap around the modulus.
+    fn num_limbs(bits: usize) -> Result<usize, Error> {
+        if bits > F::CAPACITY as usize {
+            return Err(Error::Synthesis);
+        }
+        Ok(((bits + BITS - 1) / BITS).max(1))
+    }
+
     /// Load the lookup table with values [0, 2^BITS)
     pub fn load_table(&self, mut layouter: impl Layouter<F>) -> Result<(), Error> {
         let table_size = 1 << BITS; // 2^BITS
@@ -109,16 +170,54 @@ impl<F: PrimeField, const BITS: usize> RangeCheckInstruction<F> for RangeCheckCh
         &self,
         mut layouter: impl Layouter<F>,
         value: AssignedCell<F, F>,
-        _bits: usize,
+        bits: usize,
     ) -> Result<(), Error> {
+        let num_limbs = Self::num_limbs(bits)?;
+        let top_bits = bits - (num_limbs - 1) * BITS;
+        let shift_inv = F::from(1u64 << BITS).invert().unwrap();
+
         layouter.assign_region(
             || "range check",
             |mut region| {
-                // Enable the lookup selector
-                self.config.q_lookup.enable(&mut region, 0)?;
+                // Copy the value to this region (z_0)
+                let mut z = value.copy_advice(|| "value", &mut region, self.config.value, 0)?;
+
+                // Running sum: look up limb_i = z_i - 2^BITS * z_{i+1}
+                for i in 0..num_limbs - 1 {
+                    self.config.q_lookup.enable(&mut region, i)?;
+                    self.config.q_running.enable(&mut region, i)?;
+
+                    let z_next = z.value().map(|z| {
+                        let limb = F::from(low_bits(z, BITS));
+                        (*z - limb) * shift_inv
+                    });
+                    z = region.assign_advice(
+                        || format!("z[{}]", i + 1),
+                        self.config.value,
+                        i + 1,
+                        || z_next,
+                    )?;
+                }
+
+                // Top limb: z_{n-1} itself must be in the table
+                let top = num_limbs - 1;
+                self.config.q_lookup.enable(&mut region, top)?;
+
+                // Narrower top limb: z_{n-1} * 2^(BITS - top_bits) must be in the table too
+                if top_bits < BITS {
+                    let shift = F::from(1u64 << (BITS - top_bits));
 
-                // Copy the value to this region
-                value.copy_advice(|| "value", &mut region, self.config.value, 0)?;
+                    self.config.q_shift.enable(&mut region, top)?;
+                    region.assign_fixed(|| "shift", self.config.shift, top, || Value::known(shift))?;
+
+                    region.assign_advice(
+                        || "shifted",
+                        self.config.value,
+                        top + 1,
+                        || z.value().map(|z| *z * shift),
+                    )?;
+                    self.config.q_lookup.enable(&mut region, top + 1)?;
+                }
 
                 Ok(())
             },
@@ -126,12 +225,26 @@ impl<F: PrimeField, const BITS: usize> RangeCheckInstruction<F> for RangeCheckCh
     }
 }
 
+/// The low `bits` bits of a field element's canonical representation
+///
+/// Assumes a little-endian `Repr`, which holds for the Pasta fields.
+fn low_bits<F: PrimeField>(value: &F, bits: usize) -> u64 {
+    let repr = value.to_repr();
+    let bytes = repr.as_ref();
+
+    (0..bits).fold(0u64, |acc, i| {
+        let bit = (bytes[i / 8] >> (i % 8)) & 1;
+        acc | (u64::from(bit) << i)
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use ff::Field;
     use halo2_proofs::{
         circuit::SimpleFloorPlanner,
-        dev::MockProver,
+        dev::{MockProver, VerifyFailure},
         plonk::Circuit,
     };
     use pasta_curves::Fp;
@@ -139,6 +252,7 @@ mod tests {
     #[derive(Default, Clone)]
     struct RangeCheckTestCircuit<const BITS: usize> {
         value: Value<Fp>,
+        bits: usize,
     }
 
     impl<const BITS: usize> Circuit<Fp> for RangeCheckTestCircuit<BITS> {
@@ -146,7 +260,10 @@ mod tests {
         type FloorPlanner = SimpleFloorPlanner;
 
         fn without_witnesses(&self) -> Self {
-            Self::default()
+            Self {
+                bits: self.bits,
+                ..Self::default()
+            }
         }
 
         fn configure(meta: &mut ConstraintSystem<Fp>) -> Self::Config {
@@ -174,7 +291,7 @@ mod tests {
             )?;
 
             // Check the range
-            chip.check(layouter.namespace(|| "range check"), value_cell, BITS)?;
+            chip.check(layouter.namespace(|| "range check"), value_cell, self.bits)?;
 
             Ok(())
         }
@@ -189,6 +306,7 @@ mod tests {
         for value in [0u64, 1, 127, 255] {
             let circuit = RangeCheckTestCircuit::<BITS> {
                 value: Value::known(Fp::from(value)),
+                bits: BITS,
             };
 
             let prover = MockProver::run(k, &circuit, vec![]).unwrap();
@@ -204,9 +322,128 @@ mod tests {
         // Value 256 is out of range [0, 256)
         let circuit = RangeCheckTestCircuit::<BITS> {
             value: Value::known(Fp::from(256u64)),
+            bits: BITS,
         };
 
         let prover = MockProver::run(k, &circuit, vec![]).unwrap();
         assert!(prover.verify().is_err(), "Should fail for value 256");
     }
+
+    fn run_check<const BITS: usize>(value: Fp, bits: usize) -> Result<(), Vec<VerifyFailure>> {
+        let circuit = RangeCheckTestCircuit::<BITS> {
+            value: Value::known(value),
+            bits,
+        };
+        MockProver::run(9, &circuit, vec![]).unwrap().verify()
+    }
+
+    #[test]
+    fn test_range_check_64_bits() {
+        const BITS: usize = 8;
+
+        // 8 limbs of 8 bits
+        for value in [0u128, 1, 255, 256, (1 << 63) + 12345, u64::MAX as u128] {
+            assert_eq!(
+                run_check::<BITS>(Fp::from_u128(value), 64),
+                Ok(()),
+                "Failed for value {}",
+                value
+            );
+        }
+
+        // 2^64 and 2^64 + 1 just overflow
+        assert!(run_check::<BITS>(Fp::from_u128(1 << 64), 64).is_err());
+        assert!(run_check::<BITS>(Fp::from_u128((1 << 64) + 1), 64).is_err());
+    }
+
+    #[test]
+    fn test_range_check_short_bounds() {
+        const BITS: usize = 8;
+
+        // Every width up to the table size, at its boundary
+        for bits in 0..=BITS {
+            let limit = 1u64 << bits;
+            assert_eq!(
+                run_check::<BITS>(Fp::from(limit - 1), bits),
+                Ok(()),
+                "2^{} - 1 should pass a {}-bit check",
+                bits,
+                bits
+            );
+            assert!(
+                run_check::<BITS>(Fp::from(limit), bits).is_err(),
+                "2^{} should fail a {}-bit check",
+                bits,
+                bits
+            );
+        }
+    }
+
+    #[test]
+    fn test_range_check_percentage() {
+        const BITS: usize = 8;
+
+        // 7 bits: [0, 128) holds any percentage
+        assert_eq!(run_check::<BITS>(Fp::from(100u64), 7), Ok(()));
+        assert!(run_check::<BITS>(Fp::from(200u64), 7).is_err());
+
+        // Field-sized values must not slip through the shift
+        assert!(run_check::<BITS>(-Fp::ONE, 7).is_err());
+    }
+
+    #[test]
+    fn test_range_check_partial_top_limb() {
+        const BITS: usize = 8;
+
+        // 20 bits = two full limbs plus a 4-bit top limb
+        for bits in [9usize, 12, 20, 63] {
+            let limit = 1u128 << bits;
+            assert_eq!(
+                run_check::<BITS>(Fp::from_u128(limit - 1), bits),
+                Ok(()),
+                "2^{} - 1 should pass a {}-bit check",
+                bits,
+                bits
+            );
+            assert!(
+                run_check::<BITS>(Fp::from_u128(limit), bits).is_err(),
+                "2^{} should fail a {}-bit check",
+                bits,
+                bits
+            );
+        }
+    }
+
+    #[test]
+    fn test_range_check_128_bits() {
+        const BITS: usize = 8;
+
+        assert_eq!(run_check::<BITS>(Fp::from_u128(u128::MAX), 128), Ok(()));
+        assert_eq!(run_check::<BITS>(Fp::from_u128(1 << 100), 128), Ok(()));
+
+        // 2^128 needs a 17th limb
+        let two_128 = Fp::from_u128(u128::MAX) + Fp::ONE;
+        assert!(run_check::<BITS>(two_128, 128).is_err());
+    }
+
+    #[test]
+    fn test_range_check_rejects_wraparound() {
+        const BITS: usize = 8;
+
+        // -1 = p - 1 and -2^64: small looking after reduction, huge as integers
+        assert!(run_check::<BITS>(-Fp::ONE, 64).is_err());
+        assert!(run_check::<BITS>(-Fp::from_u128(1 << 64), 128).is_err());
+    }
+
+    #[test]
+    fn test_range_check_rejects_field_sized_width() {
+        const BITS: usize = 8;
+
+        // 255 bits would let the running sum wrap around the modulus
+        let circuit = RangeCheckTestCircuit::<BITS> {
+            value: Value::known(Fp::from(1u64)),
+            bits: Fp::NUM_BITS as usize,
+        };
+        assert!(MockProver::run(9, &circuit, vec![]).is_err());
+    }
 }
@@ -0,0 +1,616 @@
+//! HealthFactorBucket Circuit
+//!
+//! Proves that a committed position's health factor lies in a public bucket
+//! `[lo, hi)` without revealing the exact value, so a user can opt in to
+//! liquidation warnings while the server only learns the urgency level.
+//!
+//! # Health Factor Formula
+//! Same as `LiquidationCircuit`, with the oracle price carrying 8 decimals:
+//! `HF = (collateral * price * liquidation_threshold) / (debt * 100 * 10^8)`
+//!
+//! Bucket bounds are in basis points of HF (`1.0 = 10000`). Rearranged for
+//! integer arithmetic:
+//! `lo * debt * 10^10 <= collateral * price * liquidation_threshold * 10^4 < hi * debt * 10^10`
+//!
+//! # Public Inputs
+//! - `lo`, `hi`: Bucket bounds in basis points, < 2^32 (use `MAX_HEALTH_FACTOR`
+//!   as `hi` for an open-ended bucket)
+//! - `price`: Current asset price from oracle (8 decimals), 0 < price < 2^64
+//! - `liquidation_threshold`: Protocol's liquidation threshold (e.g., 85%),
+//!   0 < threshold <= 100
+//! - `collateral_commitment`: Poseidon(collateral, collateral_salt)
+//! - `debt_commitment`: Poseidon(debt, debt_salt)
+//!
+//! # Private Inputs
+//! - `collateral`: Amount of collateral
+//! - `debt`: Amount of debt
+//! - `collateral_salt`, `debt_salt`: Salts for commitments
+//!
+//! # Constraints
+//! 1. `collateral_scaled = collateral * price * liquidation_threshold * 10^4`
+//! 2. `lo_scaled = lo * debt * 10^10`, `hi_scaled = hi * debt * 10^10`
+//! 3. `lo_scaled <= collateral_scaled < hi_scaled`
+//! 4. Both commitments == Poseidon(value, salt)
+//!
+//! # Example
+//! - collateral: 1 ETH, price: $2000, threshold: 85%, debt: 1600 DAI (both 18 decimals)
+//! - HF = 2000 * 0.85 / 1600 = 1.0625 → proves the bucket `[10000, 12000)`
+//!
+//! # Circuit Statistics
+//! - Advice columns: 12 (amounts, salt, public parameters, scaled values, commitment, diff)
+//! - Instance columns: 1 (public inputs)
+//! - Custom gates: bucket scaling, Poseidon rounds, comparison
+//! - Lookup tables: 1 (range check)
+//! - Estimated rows: ~2^17 (16-bit lookup table, two Poseidon hashes)
+
+use ff::PrimeField;
+use halo2_proofs::{
+    circuit::{Layouter, SimpleFloorPlanner, Value},
+    plonk::{Advice, Circuit, Column, ConstraintSystem, Error, Expression, Instance, Selector},
+    poly::Rotation,
+};
+use std::marker::PhantomData;
+
+use crate::constants::{LOOKUP_BITS, RANGE_BITS, PRICE_BITS, PRICE_PRECISION};
+use crate::gadgets::comparison::{ComparisonChip, ComparisonConfig, ComparisonInstruction};
+use crate::gadgets::poseidon::{PoseidonChip, PoseidonConfig};
+
+/// Width of the bucket bounds
+const BUCKET_BITS: usize = 32;
+/// Width of the compared values: `collateral * price * threshold * 10^4` needs 213 bits,
+/// `hi * debt * 10^10` needs 194
+const SCALED_BITS: usize = RANGE_BITS + PRICE_BITS + 2 * LOOKUP_BITS;
+const PRECISION: u64 = 100; // For percentage calculations
+/// Largest valid liquidation_threshold (100%)
+const MAX_THRESHOLD: u64 = PRECISION;
+/// Health factor basis points (1.0 = 10000)
+pub const HF_PRECISION: u64 = 10_000;
+/// Largest bucket bound; the `hi` of an open-ended bucket
+pub const MAX_HEALTH_FACTOR: u64 = u32::MAX as u64;
+
+/// Configuration for the HealthFactorBucket circuit
+#[derive(Debug, Clone)]
+pub struct HealthFactorBucketConfig<F: PrimeField> {
+    // Private inputs
+    pub collateral: Column<Advice>,
+    pub debt: Column<Advice>,
+    /// Salts for both commitments (one per row)
+    pub salt: Column<Advice>,
+
+    // Public parameters (copied from instance)
+    pub lo: Column<Advice>,
+    pub hi: Column<Advice>,
+    pub price: Column<Advice>,
+    pub liquidation_threshold: Column<Advice>,
+
+    // Intermediate values
+    pub collateral_scaled: Column<Advice>,  // collateral * price * liq_threshold * 10^4
+    pub lo_scaled: Column<Advice>,          // lo * debt * 10^10
+    pub hi_scaled: Column<Advice>,          // hi * debt * 10^10
+    /// Poseidon output column (third state column)
+    pub commitment: Column<Advice>,
+
+    // Public inputs
+    pub instance: Column<Instance>,
+
+    // Gates
+    pub q_bucket: Selector,
+
+    /// Poseidon chip config (state = [collateral, salt, commitment])
+    pub poseidon: PoseidonConfig<F>,
+    /// Comparison for the bucket bounds
+    pub comparison: ComparisonConfig<F, LOOKUP_BITS>,
+
+    _marker: PhantomData<F>,
+}
+
+/// HealthFactorBucket circuit
+#[derive(Clone)]
+pub struct HealthFactorBucketCircuit<F: PrimeField> {
+    pub collateral: Value<F>,
+    pub debt: Value<F>,
+    pub collateral_salt: Value<F>,
+    pub debt_salt: Value<F>,
+    pub price: Value<F>,
+    pub liquidation_threshold: Value<F>,
+    pub lo: Value<F>,
+    pub hi: Value<F>,
+}
+
+impl<F: PrimeField> Default for HealthFactorBucketCircuit<F> {
+    fn default() -> Self {
+        Self {
+            collateral: Value::unknown(),
+            debt: Value::unknown(),
+            collateral_salt: Value::unknown(),
+            debt_salt: Value::unknown(),
+            price: Value::unknown(),
+            liquidation_threshold: Value::unknown(),
+            lo: Value::unknown(),
+            hi: Value::unknown(),
+        }
+    }
+}
+
+impl<F: PrimeField> HealthFactorBucketCircuit<F> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        collateral: F,
+        debt: F,
+        collateral_salt: F,
+        debt_salt: F,
+        price: F,
+        liquidation_threshold: F,
+        lo: F,
+        hi: F,
+    ) -> Self {
+        Self {
+            collateral: Value::known(collateral),
+            debt: Value::known(debt),
+            collateral_salt: Value::known(collateral_salt),
+            debt_salt: Value::known(debt_salt),
+            price: Value::known(price),
+            liquidation_threshold: Value::known(liquidation_threshold),
+            lo: Value::known(lo),
+            hi: Value::known(hi),
+        }
+    }
+
+    /// Health factor in basis points, rounded down
+    ///
+    /// `collateral * price * liq_threshold * 10^4 / (debt * 10^10)`.
+    /// Since bucket bounds are integers, the position is in `[lo, hi)` exactly
+    /// when `lo <= health_factor(..) < hi`. `None` if debt is zero or the
+    /// numerator overflows a u128.
+    ///
+    /// Example: collateral=10^18, price=2000*10^8, liq_threshold=85, debt=1600*10^18
+    /// HF = 1.0625 → 10625
+    pub fn health_factor(
+        collateral: u128,
+        debt: u128,
+        price: u64,
+        liquidation_threshold: u64,
+    ) -> Option<u128> {
+        if debt == 0 {
+            return None;
+        }
+        let collateral_value = collateral
+            .checked_mul(price as u128)?
+            .checked_mul(liquidation_threshold as u128)?;
+        let divisor = (PRECISION * PRICE_PRECISION / HF_PRECISION) as u128;
+        Some(collateral_value / divisor / debt)
+    }
+}
+
+impl<F: PrimeField> Circuit<F> for HealthFactorBucketCircuit<F> {
+    type Config = HealthFactorBucketConfig<F>;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        // Advice columns
+        let collateral = meta.advice_column();
+        let debt = meta.advice_column();
+        let salt = meta.advice_column();
+        let lo = meta.advice_column();
+        let hi = meta.advice_column();
+        let price = meta.advice_column();
+        let liquidation_threshold = meta.advice_column();
+        let collateral_scaled = meta.advice_column();
+        let lo_scaled = meta.advice_column();
+        let hi_scaled = meta.advice_column();
+        let commitment = meta.advice_column();
+
+        // Instance for public inputs
+        let instance = meta.instance_column();
+
+        // Enable equality
+        for col in [
+            collateral,
+            debt,
+            salt,
+            lo,
+            hi,
+            price,
+            liquidation_threshold,
+            collateral_scaled,
+            lo_scaled,
+            hi_scaled,
+            commitment,
+        ] {
+            meta.enable_equality(col);
+        }
+        meta.enable_equality(instance);
+
+        let q_bucket = meta.selector();
+
+        // Bucket scaling gate:
+        //   collateral_scaled = collateral * price * liq_threshold * 10^4
+        //   lo_scaled = lo * debt * 100 * 10^8
+        //   hi_scaled = hi * debt * 100 * 10^8
+        meta.create_gate("health factor bucket", |meta| {
+            let q = meta.query_selector(q_bucket);
+            let coll = meta.query_advice(collateral, Rotation::cur());
+            let d = meta.query_advice(debt, Rotation::cur());
+            let p = meta.query_advice(price, Rotation::cur());
+            let lt = meta.query_advice(liquidation_threshold, Rotation::cur());
+            let lo = meta.query_advice(lo, Rotation::cur());
+            let hi = meta.query_advice(hi, Rotation::cur());
+            let cs = meta.query_advice(collateral_scaled, Rotation::cur());
+            let los = meta.query_advice(lo_scaled, Rotation::cur());
+            let his = meta.query_advice(hi_scaled, Rotation::cur());
+            let hf_precision = Expression::Constant(F::from(HF_PRECISION));
+            let debt_precision = Expression::Constant(F::from(PRECISION * PRICE_PRECISION));
+
+            vec![
+                q.clone() * (cs - coll * p * lt * hf_precision),
+                q.clone() * (los - lo * d.clone() * debt_precision.clone()),
+                q * (his - hi * d * debt_precision),
+            ]
+        });
+
+        // Poseidon chip for both commitments
+        let poseidon = PoseidonChip::configure(meta, [collateral, salt, commitment]);
+
+        // Comparison config for the bucket bounds
+        let diff = meta.advice_column();
+        meta.enable_equality(diff);
+        let comparison = ComparisonChip::<F, LOOKUP_BITS>::configure_with_range_bits(
+            meta,
+            collateral_scaled,  // a = collateral * price * liq_threshold * 10^4
+            lo_scaled,          // b = lo * debt * 10^10
+            diff,
+            SCALED_BITS,
+        );
+
+        HealthFactorBucketConfig {
+            collateral,
+            debt,
+            salt,
+            lo,
+            hi,
+            price,
+            liquidation_threshold,
+            collateral_scaled,
+            lo_scaled,
+            hi_scaled,
+            commitment,
+            instance,
+            q_bucket,
+            poseidon,
+            comparison,
+            _marker: PhantomData,
+        }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        // Load comparison lookup table
+        let comparison_chip = ComparisonChip::<F, LOOKUP_BITS>::construct(config.comparison.clone());
+        comparison_chip.load_table(layouter.namespace(|| "load table"))?;
+
+        // Main region: assign values and compute scaled amounts
+        let (
+            collateral_cell,
+            debt_cell,
+            lo_cell,
+            hi_cell,
+            price_cell,
+            lt_cell,
+            collateral_scaled_cell,
+            lo_scaled_cell,
+            hi_scaled_cell,
+        ) = layouter.assign_region(
+            || "health factor bucket",
+            |mut region| {
+                config.q_bucket.enable(&mut region, 0)?;
+
+                // Assign private inputs
+                let collateral_cell =
+                    region.assign_advice(|| "collateral", config.collateral, 0, || self.collateral)?;
+                let debt_cell = region.assign_advice(|| "debt", config.debt, 0, || self.debt)?;
+
+                // Assign public parameters (from instance)
+                let lo_cell = region.assign_advice(|| "lo", config.lo, 0, || self.lo)?;
+                let hi_cell = region.assign_advice(|| "hi", config.hi, 0, || self.hi)?;
+                let price_cell = region.assign_advice(|| "price", config.price, 0, || self.price)?;
+                let lt_cell = region.assign_advice(
+                    || "liquidation_threshold",
+                    config.liquidation_threshold,
+                    0,
+                    || self.liquidation_threshold,
+                )?;
+
+                // collateral_scaled = collateral * price * liq_threshold * 10^4
+                let collateral_scaled_val = self
+                    .collateral
+                    .zip(self.price)
+                    .zip(self.liquidation_threshold)
+                    .map(|((c, p), lt)| c * p * lt * F::from(HF_PRECISION));
+                let collateral_scaled_cell = region.assign_advice(
+                    || "collateral_scaled",
+                    config.collateral_scaled,
+                    0,
+                    || collateral_scaled_val,
+                )?;
+
+                // lo_scaled, hi_scaled = bound * debt * 10^10
+                let debt_precision = F::from(PRECISION * PRICE_PRECISION);
+                let lo_scaled_val = self.lo.zip(self.debt).map(|(lo, d)| lo * d * debt_precision);
+                let lo_scaled_cell =
+                    region.assign_advice(|| "lo_scaled", config.lo_scaled, 0, || lo_scaled_val)?;
+                let hi_scaled_val = self.hi.zip(self.debt).map(|(hi, d)| hi * d * debt_precision);
+                let hi_scaled_cell =
+                    region.assign_advice(|| "hi_scaled", config.hi_scaled, 0, || hi_scaled_val)?;
+
+                Ok((
+                    collateral_cell,
+                    debt_cell,
+                    lo_cell,
+                    hi_cell,
+                    price_cell,
+                    lt_cell,
+                    collateral_scaled_cell,
+                    lo_scaled_cell,
+                    hi_scaled_cell,
+                ))
+            },
+        )?;
+
+        // Salts
+        let (collateral_salt_cell, debt_salt_cell) = layouter.assign_region(
+            || "assign salts",
+            |mut region| {
+                let collateral_salt =
+                    region.assign_advice(|| "collateral_salt", config.salt, 0, || self.collateral_salt)?;
+                let debt_salt = region.assign_advice(|| "debt_salt", config.salt, 1, || self.debt_salt)?;
+                Ok((collateral_salt, debt_salt))
+            },
+        )?;
+
+        // Commitments = Poseidon(value, salt)
+        let poseidon_chip = PoseidonChip::construct(config.poseidon.clone());
+        let coll_comm_cell = poseidon_chip.hash(
+            layouter.namespace(|| "collateral commitment"),
+            collateral_cell.clone(),
+            collateral_salt_cell,
+        )?;
+        let debt_comm_cell = poseidon_chip.hash(
+            layouter.namespace(|| "debt commitment"),
+            debt_cell.clone(),
+            debt_salt_cell,
+        )?;
+
+        // Constrain public inputs
+        // instance[0] = lo
+        // instance[1] = hi
+        // instance[2] = price
+        // instance[3] = liquidation_threshold
+        // instance[4] = collateral_commitment
+        // instance[5] = debt_commitment
+        layouter.constrain_instance(lo_cell.cell(), config.instance, 0)?;
+        layouter.constrain_instance(hi_cell.cell(), config.instance, 1)?;
+        layouter.constrain_instance(price_cell.cell(), config.instance, 2)?;
+        layouter.constrain_instance(lt_cell.cell(), config.instance, 3)?;
+        layouter.constrain_instance(coll_comm_cell.cell(), config.instance, 4)?;
+        layouter.constrain_instance(debt_comm_cell.cell(), config.instance, 5)?;
+
+        // Public parameter bounds: 0 < price < 2^64, 0 < threshold <= 100, bounds < 2^32
+        comparison_chip.in_range(
+            layouter.namespace(|| "price bounds"),
+            price_cell,
+            F::ONE,
+            F::from(u64::MAX),
+        )?;
+        comparison_chip.in_range(
+            layouter.namespace(|| "liquidation_threshold bounds"),
+            lt_cell,
+            F::ONE,
+            F::from(MAX_THRESHOLD),
+        )?;
+        comparison_chip.range_check(layouter.namespace(|| "lo range"), lo_cell, BUCKET_BITS)?;
+        comparison_chip.range_check(layouter.namespace(|| "hi range"), hi_cell, BUCKET_BITS)?;
+
+        // Bound the private amounts so the products cannot wrap the field
+        comparison_chip.range_check(
+            layouter.namespace(|| "collateral range"),
+            collateral_cell,
+            RANGE_BITS,
+        )?;
+        comparison_chip.range_check(layouter.namespace(|| "debt range"), debt_cell, RANGE_BITS)?;
+
+        // Prove the bucket: lo <= HF < hi
+        comparison_chip.gte(
+            layouter.namespace(|| "bucket lower bound"),
+            collateral_scaled_cell.clone(),
+            lo_scaled_cell,
+        )?;
+        comparison_chip.lt(
+            layouter.namespace(|| "bucket upper bound"),
+            collateral_scaled_cell,
+            hi_scaled_cell,
+        )?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gadgets::poseidon::compute_commitment;
+    use pasta_curves::Fp;
+    use halo2_proofs::dev::MockProver;
+
+    const ETH: u128 = 1_000_000_000_000_000_000;
+    const USD: u64 = 100_000_000;
+
+    fn create_bucket_circuit(
+        collateral: u128,
+        debt: u128,
+        price: u64,
+        liquidation_threshold: u64,
+        lo: u64,
+        hi: u64,
+    ) -> (HealthFactorBucketCircuit<Fp>, Vec<Fp>) {
+        let collateral_fp = Fp::from_u128(collateral);
+        let debt_fp = Fp::from_u128(debt);
+        let collateral_salt = Fp::from(11111u64);
+        let debt_salt = Fp::from(22222u64);
+        let price_fp = Fp::from(price);
+        let lt_fp = Fp::from(liquidation_threshold);
+        let (lo_fp, hi_fp) = (Fp::from(lo), Fp::from(hi));
+
+        let collateral_commitment = compute_commitment(collateral_fp, collateral_salt);
+        let debt_commitment = compute_commitment(debt_fp, debt_salt);
+
+        let circuit = HealthFactorBucketCircuit::new(
+            collateral_fp,
+            debt_fp,
+            collateral_salt,
+            debt_salt,
+            price_fp,
+            lt_fp,
+            lo_fp,
+            hi_fp,
+        );
+        let public_inputs = vec![lo_fp, hi_fp, price_fp, lt_fp, collateral_commitment, debt_commitment];
+
+        (circuit, public_inputs)
+    }
+
+    #[test]
+    fn test_health_factor_helper() {
+        // 1 ETH at $2000, 85% threshold, 1600 debt → HF = 1.0625
+        assert_eq!(
+            HealthFactorBucketCircuit::<Fp>::health_factor(ETH, 1600 * ETH, 2000 * USD, 85),
+            Some(10625)
+        );
+        // Exactly 1.0: 1 ETH at $2000, 85%, 1700 debt
+        assert_eq!(
+            HealthFactorBucketCircuit::<Fp>::health_factor(ETH, 1700 * ETH, 2000 * USD, 85),
+            Some(10000)
+        );
+        assert_eq!(HealthFactorBucketCircuit::<Fp>::health_factor(ETH, 0, 2000 * USD, 85), None);
+    }
+
+    #[test]
+    fn test_position_in_bucket() {
+        let k = 17;
+
+        // HF = 1.0625 ∈ [1.0, 1.2)
+        let (circuit, public_inputs) = create_bucket_circuit(ETH, 1600 * ETH, 2000 * USD, 85, 10000, 12000);
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert_eq!(prover.verify(), Ok(()), "HF inside the bucket should verify");
+    }
+
+    #[test]
+    fn test_bucket_lower_bound_inclusive() {
+        let k = 17;
+
+        // HF = 1.0 exactly ∈ [1.0, 1.2)
+        let (circuit, public_inputs) = create_bucket_circuit(ETH, 1700 * ETH, 2000 * USD, 85, 10000, 12000);
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert_eq!(prover.verify(), Ok(()), "HF equal to lo should verify");
+    }
+
+    #[test]
+    fn test_bucket_upper_bound_exclusive() {
+        let k = 17;
+
+        // HF = 1.0 exactly ∉ [0.9, 1.0)
+        let (circuit, public_inputs) = create_bucket_circuit(ETH, 1700 * ETH, 2000 * USD, 85, 9000, 10000);
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err(), "HF equal to hi should fail");
+    }
+
+    #[test]
+    fn test_position_below_bucket() {
+        let k = 17;
+
+        // HF = 1700 / 1800 ≈ 0.944 ∉ [1.0, 1.2)
+        let (circuit, public_inputs) = create_bucket_circuit(ETH, 1800 * ETH, 2000 * USD, 85, 10000, 12000);
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err(), "HF below the bucket should fail");
+    }
+
+    #[test]
+    fn test_position_above_bucket() {
+        let k = 17;
+
+        // HF = 1700 / 1000 = 1.7 ∉ [1.0, 1.2)
+        let (circuit, public_inputs) = create_bucket_circuit(ETH, 1000 * ETH, 2000 * USD, 85, 10000, 12000);
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err(), "HF above the bucket should fail");
+    }
+
+    #[test]
+    fn test_open_ended_bucket() {
+        let k = 17;
+
+        // HF = 1.7 ∈ [1.2, MAX_HEALTH_FACTOR)
+        let (circuit, public_inputs) =
+            create_bucket_circuit(ETH, 1000 * ETH, 2000 * USD, 85, 12000, MAX_HEALTH_FACTOR);
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert_eq!(prover.verify(), Ok(()), "Healthy position should fit the open-ended bucket");
+    }
+
+    #[test]
+    fn test_zero_debt_fails() {
+        let k = 17;
+
+        // hi_scaled = 0, so no bucket can hold a debt-free position
+        let (circuit, public_inputs) = create_bucket_circuit(ETH, 0, 2000 * USD, 85, 0, MAX_HEALTH_FACTOR);
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err(), "Zero debt should fail");
+    }
+
+    #[test]
+    fn test_wrong_public_bucket() {
+        let k = 17;
+
+        // Proof for [1.0, 1.2) cannot be presented as [1.2, MAX)
+        let (circuit, mut public_inputs) = create_bucket_circuit(ETH, 1600 * ETH, 2000 * USD, 85, 10000, 12000);
+        public_inputs[0] = Fp::from(12000u64);
+        public_inputs[1] = Fp::from(MAX_HEALTH_FACTOR);
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err(), "Mismatched bucket should fail");
+    }
+
+    #[test]
+    fn test_wrong_commitment() {
+        let k = 17;
+
+        let (circuit, mut public_inputs) = create_bucket_circuit(ETH, 1600 * ETH, 2000 * USD, 85, 10000, 12000);
+        public_inputs[5] = compute_commitment(Fp::from_u128(1000 * ETH), Fp::from(22222u64));
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err(), "Commitment to a different debt should fail");
+    }
+
+    #[test]
+    fn test_bucket_bound_out_of_range() {
+        let k = 17;
+
+        // hi beyond 32 bits is rejected by the range check
+        let (circuit, public_inputs) =
+            create_bucket_circuit(ETH, 1000 * ETH, 2000 * USD, 85, 12000, MAX_HEALTH_FACTOR + 1);
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err(), "hi >= 2^32 should fail");
+    }
+}
@@ -0,0 +1,697 @@
+//! InterestAccrual Circuit
+//!
+//! Proves that a new debt commitment opens to the committed principal plus the
+//! interest accrued since the last update, computed exactly like
+//! `ZKLendingPool.getCurrentDebt`:
+//! `interest = principal * rate * elapsed / (INTEREST_RATE_BASE * SECONDS_PER_YEAR)`
+//! `new_debt = principal + accrued_interest + interest`
+//!
+//! Solidity's integer division rounds down, so the circuit witnesses the
+//! quotient and remainder of the division and bounds the remainder:
+//! `principal * rate * elapsed == interest * DIVISOR + remainder`, `0 <= remainder < DIVISOR`
+//!
+//! # Public Inputs
+//! - `commitment_root`: Root of the registered commitment tree that holds the
+//!   debt commitment before accrual; that commitment itself stays private
+//! - `new_commitment`: Poseidon(new_debt, new_salt) - debt commitment after accrual
+//! - `rate`: Borrow rate in basis points (`getCurrentInterestRate`), < 2^32
+//! - `elapsed`: Seconds since `borrowTimestamp`, < 2^64
+//! - `accrued_interest`: Interest already settled on-chain (`accruedInterest[user]`)
+//! - `nullifier`: Poseidon(old_salt, old_commitment) - retires the stale commitment
+//!
+//! # Private Inputs
+//! - `principal`: Borrowed principal
+//! - `old_salt`, `new_salt`: Salts for commitments
+//! - Merkle path of the old commitment (siblings and index bits,
+//!   `COMMITMENT_TREE_DEPTH` levels)
+//! - `interest`, `remainder`: Quotient and remainder of the interest division
+//!
+//! # Constraints
+//! 1. `principal * rate * elapsed == interest * DIVISOR + remainder`
+//! 2. `0 <= remainder <= DIVISOR - 1`
+//! 3. `new_debt = principal + accrued_interest + interest`
+//! 4. `principal`, `accrued_interest`, `interest`, `new_debt` fit in 128 bits,
+//!    `rate` in 32 bits and `elapsed` in 64 bits (both sides of 1. stay below the modulus)
+//! 5. Both commitments == Poseidon(value, salt), and hashing
+//!    `old_commitment = Poseidon(principal, old_salt)` up the Merkle path gives
+//!    `commitment_root`
+//! 6. `nullifier == Poseidon(old_salt, old_commitment)`, with the same `old_salt`
+//!    that opens `old_commitment`
+//!
+//! # Example
+//! - principal: 10000 USDC, rate: 500 (5% APR), elapsed: half a year
+//! - interest = 10000 * 500 * 15768000 / (10000 * 31536000) = 250 USDC
+//! - new debt: 10250 USDC
+//!
+//! # Circuit Statistics
+//! - Advice columns: 10 (principal, rate, elapsed, interest, remainder, accrued, new debt, salt, commitment, diff;
+//!   the Merkle path reuses new debt, rate, elapsed, accrued and salt)
+//! - Instance columns: 1 (public inputs)
+//! - Custom gates: interest accrual, Poseidon rounds, Merkle swap, comparison
+//! - Lookup tables: 1 (range check)
+//! - Estimated rows: ~2^17 (16-bit lookup table, three Poseidon hashes plus one
+//!   per tree level)
+
+use ff::PrimeField;
+use halo2_proofs::{
+    circuit::{Layouter, SimpleFloorPlanner, Value},
+    plonk::{Advice, Circuit, Column, ConstraintSystem, Error, Expression, Instance, Selector},
+    poly::Rotation,
+};
+use std::marker::PhantomData;
+
+use crate::constants::{COMMITMENT_TREE_DEPTH, LOOKUP_BITS, RANGE_BITS};
+use crate::gadgets::comparison::{ComparisonChip, ComparisonConfig, ComparisonInstruction};
+use crate::gadgets::merkle::{MerkleChip, MerkleConfig, MerklePath};
+use crate::gadgets::poseidon::{PoseidonChip, PoseidonConfig};
+
+/// Width of the borrow rate (basis points)
+const RATE_BITS: usize = 32;
+/// Width of the elapsed time (seconds)
+const TIME_BITS: usize = 64;
+/// `ZKLendingPool.INTEREST_RATE_BASE` (10000 = 100%)
+pub const INTEREST_RATE_BASE: u64 = 10_000;
+/// `ZKLendingPool.SECONDS_PER_YEAR` (365 days)
+pub const SECONDS_PER_YEAR: u64 = 365 * 24 * 60 * 60;
+/// Denominator of the interest formula
+const DIVISOR: u64 = INTEREST_RATE_BASE * SECONDS_PER_YEAR;
+
+/// Configuration for the InterestAccrual circuit
+#[derive(Debug, Clone)]
+pub struct InterestAccrualConfig<F: PrimeField> {
+    // Private values
+    pub principal: Column<Advice>,
+    pub interest: Column<Advice>,
+    pub remainder: Column<Advice>,
+    pub new_debt: Column<Advice>,
+
+    // Public parameters (copied from instance)
+    pub rate: Column<Advice>,
+    pub elapsed: Column<Advice>,
+    pub accrued_interest: Column<Advice>,
+
+    /// Salts for the two commitments (one per row)
+    pub salt: Column<Advice>,
+    /// Poseidon output column (third state column)
+    pub commitment: Column<Advice>,
+
+    // Public inputs
+    pub instance: Column<Instance>,
+
+    // Gates
+    pub q_accrual: Selector,
+
+    /// Poseidon chip config (state = [principal, salt, commitment])
+    pub poseidon: PoseidonConfig<F>,
+    /// Merkle path chip proving the old debt commitment is registered
+    pub merkle: MerkleConfig<F>,
+    /// Range checks and the remainder bound
+    pub comparison: ComparisonConfig<F, LOOKUP_BITS>,
+
+    _marker: PhantomData<F>,
+}
+
+/// InterestAccrual circuit
+#[derive(Clone)]
+pub struct InterestAccrualCircuit<F: PrimeField> {
+    /// Private: borrowed principal
+    pub principal: Value<F>,
+    /// Private: salt of the old debt commitment
+    pub old_salt: Value<F>,
+    /// Private: siblings of the old debt commitment in the commitment tree (leaf level first)
+    pub old_siblings: Vec<Value<F>>,
+    /// Private: index bits of the old debt commitment (1 = right child)
+    pub old_path_bits: Vec<Value<F>>,
+    /// Private: salt of the new debt commitment
+    pub new_salt: Value<F>,
+    /// Private: interest accrued over `elapsed` (division quotient)
+    pub interest: Value<F>,
+    /// Private: division remainder
+    pub remainder: Value<F>,
+    /// Public: borrow rate in basis points (passed via instance)
+    pub rate: Value<F>,
+    /// Public: seconds elapsed (passed via instance)
+    pub elapsed: Value<F>,
+    /// Public: interest already settled on-chain (passed via instance)
+    pub accrued_interest: Value<F>,
+}
+
+impl<F: PrimeField> Default for InterestAccrualCircuit<F> {
+    fn default() -> Self {
+        Self {
+            principal: Value::unknown(),
+            old_salt: Value::unknown(),
+            old_siblings: vec![Value::unknown(); COMMITMENT_TREE_DEPTH],
+            old_path_bits: vec![Value::unknown(); COMMITMENT_TREE_DEPTH],
+            new_salt: Value::unknown(),
+            interest: Value::unknown(),
+            remainder: Value::unknown(),
+            rate: Value::unknown(),
+            elapsed: Value::unknown(),
+            accrued_interest: Value::unknown(),
+        }
+    }
+}
+
+impl<F: PrimeField> InterestAccrualCircuit<F> {
+    /// Create a new circuit, computing the interest witnesses
+    ///
+    /// If the interest does not fit in a u128 the witnesses are zero and the
+    /// proof fails. `old_path` is the path of the old debt commitment in the
+    /// commitment tree and must have `COMMITMENT_TREE_DEPTH` levels.
+    pub fn new(
+        principal: u128,
+        old_salt: F,
+        old_path: &MerklePath<F>,
+        new_salt: F,
+        rate: u64,
+        elapsed: u64,
+        accrued_interest: u128,
+    ) -> Self {
+        let (interest, remainder) = Self::divide_interest(principal, rate, elapsed).unwrap_or((0, 0));
+
+        Self {
+            principal: Value::known(F::from_u128(principal)),
+            old_salt: Value::known(old_salt),
+            old_siblings: old_path.siblings.iter().map(|s| Value::known(*s)).collect(),
+            old_path_bits: old_path.path_bits_as_field().into_iter().map(Value::known).collect(),
+            new_salt: Value::known(new_salt),
+            interest: Value::known(F::from_u128(interest)),
+            remainder: Value::known(F::from(remainder)),
+            rate: Value::known(F::from(rate)),
+            elapsed: Value::known(F::from(elapsed)),
+            accrued_interest: Value::known(F::from_u128(accrued_interest)),
+        }
+    }
+
+    /// Interest accrued on `principal`, rounded down like Solidity
+    ///
+    /// `principal * rate * elapsed / (INTEREST_RATE_BASE * SECONDS_PER_YEAR)`,
+    /// exact even when the numerator exceeds a u128. `None` if the result does not.
+    pub fn compute_interest(principal: u128, rate: u64, elapsed: u64) -> Option<u128> {
+        Self::divide_interest(principal, rate, elapsed).map(|(interest, _)| interest)
+    }
+
+    /// Debt after accrual: `principal + accrued_interest + interest`
+    pub fn compute_new_debt(principal: u128, rate: u64, elapsed: u64, accrued_interest: u128) -> Option<u128> {
+        Self::compute_interest(principal, rate, elapsed)?
+            .checked_add(principal)?
+            .checked_add(accrued_interest)
+    }
+
+    /// Quotient and remainder of `principal * rate * elapsed / DIVISOR`
+    ///
+    /// With `a = rate * elapsed = a_hi * D + a_lo` and `principal = p_hi * D + p_lo`:
+    /// `principal * a = (principal * a_hi + p_hi * a_lo) * D + p_lo * a_lo`,
+    /// where `p_lo * a_lo < D^2` fits in a u128.
+    fn divide_interest(principal: u128, rate: u64, elapsed: u64) -> Option<(u128, u64)> {
+        let d = DIVISOR as u128;
+        let a = rate as u128 * elapsed as u128;
+        let (a_hi, a_lo) = (a / d, a % d);
+        let (p_hi, p_lo) = (principal / d, principal % d);
+        let low = p_lo * a_lo;
+
+        let interest = principal
+            .checked_mul(a_hi)?
+            .checked_add(p_hi.checked_mul(a_lo)?)?
+            .checked_add(low / d)?;
+        Some((interest, (low % d) as u64))
+    }
+}
+
+impl<F: PrimeField> Circuit<F> for InterestAccrualCircuit<F> {
+    type Config = InterestAccrualConfig<F>;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        // Advice columns
+        let principal = meta.advice_column();
+        let interest = meta.advice_column();
+        let remainder = meta.advice_column();
+        let new_debt = meta.advice_column();
+        let rate = meta.advice_column();
+        let elapsed = meta.advice_column();
+        let accrued_interest = meta.advice_column();
+        let salt = meta.advice_column();
+        let commitment = meta.advice_column();
+
+        // Instance for public inputs
+        let instance = meta.instance_column();
+
+        // Enable equality
+        for col in [
+            principal,
+            interest,
+            remainder,
+            new_debt,
+            rate,
+            elapsed,
+            accrued_interest,
+            salt,
+            commitment,
+        ] {
+            meta.enable_equality(col);
+        }
+        meta.enable_equality(instance);
+
+        let q_accrual = meta.selector();
+
+        // Accrual gate:
+        //   principal * rate * elapsed = interest * DIVISOR + remainder
+        //   new_debt = principal + accrued_interest + interest
+        meta.create_gate("interest accrual", |meta| {
+            let q = meta.query_selector(q_accrual);
+            let principal = meta.query_advice(principal, Rotation::cur());
+            let interest = meta.query_advice(interest, Rotation::cur());
+            let remainder = meta.query_advice(remainder, Rotation::cur());
+            let new_debt = meta.query_advice(new_debt, Rotation::cur());
+            let rate = meta.query_advice(rate, Rotation::cur());
+            let elapsed = meta.query_advice(elapsed, Rotation::cur());
+            let accrued = meta.query_advice(accrued_interest, Rotation::cur());
+            let divisor = Expression::Constant(F::from(DIVISOR));
+
+            vec![
+                q.clone() * (principal.clone() * rate * elapsed - interest.clone() * divisor - remainder),
+                q * (new_debt - principal - accrued - interest),
+            ]
+        });
+
+        // Poseidon chip for both commitments and the nullifier
+        let poseidon = PoseidonChip::configure(meta, [principal, salt, commitment]);
+
+        // Merkle path chip on existing columns ([cur, sibling, bit, left, right])
+        let merkle = MerkleChip::configure(meta, [new_debt, rate, elapsed, accrued_interest, salt]);
+
+        // Comparison config for range checks and the remainder bound
+        let diff = meta.advice_column();
+        meta.enable_equality(diff);
+        let comparison = ComparisonChip::<F, LOOKUP_BITS>::configure_with_range_bits(
+            meta,
+            remainder,
+            interest,
+            diff,
+            RANGE_BITS,
+        );
+
+        InterestAccrualConfig {
+            principal,
+            interest,
+            remainder,
+            new_debt,
+            rate,
+            elapsed,
+            accrued_interest,
+            salt,
+            commitment,
+            instance,
+            q_accrual,
+            poseidon,
+            merkle,
+            comparison,
+            _marker: PhantomData,
+        }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        // The path length fixes the circuit shape, so it must match the keys
+        if self.old_siblings.len() != COMMITMENT_TREE_DEPTH {
+            return Err(Error::Synthesis);
+        }
+
+        // Load range check lookup table
+        let comparison_chip = ComparisonChip::<F, LOOKUP_BITS>::construct(config.comparison.clone());
+        comparison_chip.load_table(layouter.namespace(|| "load table"))?;
+
+        // Main region: interest division and new debt
+        let (principal_cell, interest_cell, remainder_cell, new_debt_cell, rate_cell, elapsed_cell, accrued_cell) =
+            layouter.assign_region(
+                || "interest accrual",
+                |mut region| {
+                    config.q_accrual.enable(&mut region, 0)?;
+
+                    let principal_cell =
+                        region.assign_advice(|| "principal", config.principal, 0, || self.principal)?;
+                    let interest_cell =
+                        region.assign_advice(|| "interest", config.interest, 0, || self.interest)?;
+                    let remainder_cell =
+                        region.assign_advice(|| "remainder", config.remainder, 0, || self.remainder)?;
+                    let rate_cell = region.assign_advice(|| "rate", config.rate, 0, || self.rate)?;
+                    let elapsed_cell = region.assign_advice(|| "elapsed", config.elapsed, 0, || self.elapsed)?;
+                    let accrued_cell = region.assign_advice(
+                        || "accrued_interest",
+                        config.accrued_interest,
+                        0,
+                        || self.accrued_interest,
+                    )?;
+
+                    // new_debt = principal + accrued_interest + interest
+                    let new_debt_val = self
+                        .principal
+                        .zip(self.accrued_interest)
+                        .zip(self.interest)
+                        .map(|((p, a), i)| p + a + i);
+                    let new_debt_cell =
+                        region.assign_advice(|| "new_debt", config.new_debt, 0, || new_debt_val)?;
+
+                    Ok((
+                        principal_cell,
+                        interest_cell,
+                        remainder_cell,
+                        new_debt_cell,
+                        rate_cell,
+                        elapsed_cell,
+                        accrued_cell,
+                    ))
+                },
+            )?;
+
+        // Salts
+        let (old_salt_cell, new_salt_cell) = layouter.assign_region(
+            || "assign salts",
+            |mut region| {
+                let old_salt = region.assign_advice(|| "old_salt", config.salt, 0, || self.old_salt)?;
+                let new_salt = region.assign_advice(|| "new_salt", config.salt, 1, || self.new_salt)?;
+                Ok((old_salt, new_salt))
+            },
+        )?;
+
+        // Commitments = Poseidon(value, salt)
+        let poseidon_chip = PoseidonChip::construct(config.poseidon.clone());
+        let old_comm_cell = poseidon_chip.hash(
+            layouter.namespace(|| "old debt commitment"),
+            principal_cell.clone(),
+            old_salt_cell.clone(),
+        )?;
+        let new_comm_cell = poseidon_chip.hash(
+            layouter.namespace(|| "new debt commitment"),
+            new_debt_cell.clone(),
+            new_salt_cell,
+        )?;
+
+        // nullifier = Poseidon(old_salt, old_commitment), reusing the salt cell
+        // that opened the old commitment
+        let nullifier_cell = poseidon_chip.nullifier(
+            layouter.namespace(|| "nullifier"),
+            old_salt_cell,
+            old_comm_cell.clone(),
+        )?;
+
+        // commitment_root = MerkleRoot(old_commitment, path)
+        let merkle_chip = MerkleChip::construct(config.merkle.clone());
+        let root_cell = merkle_chip.root(
+            layouter.namespace(|| "old debt commitment membership"),
+            old_comm_cell,
+            &self.old_siblings,
+            &self.old_path_bits,
+        )?;
+
+        // Constrain public inputs
+        // instance[0] = commitment_root
+        // instance[1] = new_commitment
+        // instance[2] = rate
+        // instance[3] = elapsed
+        // instance[4] = accrued_interest
+        // instance[5] = nullifier
+        layouter.constrain_instance(root_cell.cell(), config.instance, 0)?;
+        layouter.constrain_instance(new_comm_cell.cell(), config.instance, 1)?;
+        layouter.constrain_instance(rate_cell.cell(), config.instance, 2)?;
+        layouter.constrain_instance(elapsed_cell.cell(), config.instance, 3)?;
+        layouter.constrain_instance(accrued_cell.cell(), config.instance, 4)?;
+        layouter.constrain_instance(nullifier_cell.cell(), config.instance, 5)?;
+
+        // Rounding: 0 <= remainder < DIVISOR, so interest is the floor of the division
+        comparison_chip.in_range(
+            layouter.namespace(|| "remainder bounds"),
+            remainder_cell,
+            F::ZERO,
+            F::from(DIVISOR - 1),
+        )?;
+
+        // With these widths principal * rate * elapsed < 2^224 and
+        // interest * DIVISOR + remainder < 2^167, so the gate holds over the integers
+        comparison_chip.range_check(layouter.namespace(|| "principal range"), principal_cell, RANGE_BITS)?;
+        comparison_chip.range_check(layouter.namespace(|| "rate range"), rate_cell, RATE_BITS)?;
+        comparison_chip.range_check(layouter.namespace(|| "elapsed range"), elapsed_cell, TIME_BITS)?;
+        comparison_chip.range_check(layouter.namespace(|| "accrued range"), accrued_cell, RANGE_BITS)?;
+        comparison_chip.range_check(layouter.namespace(|| "interest range"), interest_cell, RANGE_BITS)?;
+        comparison_chip.range_check(layouter.namespace(|| "new debt range"), new_debt_cell, RANGE_BITS)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gadgets::merkle::IncrementalMerkleTree;
+    use crate::gadgets::poseidon::{compute_commitment, compute_nullifier};
+    use ff::Field;
+    use pasta_curves::Fp;
+    use halo2_proofs::dev::MockProver;
+
+    const OLD_SALT: u64 = 11111;
+    const NEW_SALT: u64 = 22222;
+    const USDC: u128 = 1_000_000;
+    const HALF_YEAR: u64 = SECONDS_PER_YEAR / 2;
+
+    /// Commitment tree holding a few other commitments and then `commitment`
+    fn registered_tree(commitment: Fp) -> (IncrementalMerkleTree<Fp>, MerklePath<Fp>) {
+        let mut tree = IncrementalMerkleTree::new(COMMITMENT_TREE_DEPTH).unwrap();
+        for i in 0..3u64 {
+            tree.insert(compute_commitment(Fp::from(1000 + i), Fp::from(44444u64))).unwrap();
+        }
+        let index = tree.insert(commitment).unwrap();
+        let path = tree.path(index).unwrap();
+        (tree, path)
+    }
+
+    fn create_accrual_circuit(
+        principal: u128,
+        rate: u64,
+        elapsed: u64,
+        accrued_interest: u128,
+    ) -> (InterestAccrualCircuit<Fp>, Vec<Fp>) {
+        let (old_salt, new_salt) = (Fp::from(OLD_SALT), Fp::from(NEW_SALT));
+
+        let new_debt =
+            InterestAccrualCircuit::<Fp>::compute_new_debt(principal, rate, elapsed, accrued_interest).unwrap();
+        let old_commitment = compute_commitment(Fp::from_u128(principal), old_salt);
+        let new_commitment = compute_commitment(Fp::from_u128(new_debt), new_salt);
+        let nullifier = compute_nullifier(old_salt, old_commitment);
+        let (tree, old_path) = registered_tree(old_commitment);
+
+        let circuit = InterestAccrualCircuit::new(
+            principal,
+            old_salt,
+            &old_path,
+            new_salt,
+            rate,
+            elapsed,
+            accrued_interest,
+        );
+        let public_inputs = vec![
+            tree.root(),
+            new_commitment,
+            Fp::from(rate),
+            Fp::from(elapsed),
+            Fp::from_u128(accrued_interest),
+            nullifier,
+        ];
+
+        (circuit, public_inputs)
+    }
+
+    #[test]
+    fn test_compute_interest_matches_solidity() {
+        // 10000 USDC at 5% APR for half a year = 250 USDC
+        assert_eq!(
+            InterestAccrualCircuit::<Fp>::compute_interest(10000 * USDC, 500, HALF_YEAR),
+            Some(250 * USDC)
+        );
+
+        // Rounds down: 1 unit for one second never accrues
+        assert_eq!(InterestAccrualCircuit::<Fp>::compute_interest(1, 500, 1), Some(0));
+
+        // Numerator above u128: (3 * DIVISOR) * 2^31 * 2^60 / DIVISOR = 3 * 2^91
+        let principal = 3 * DIVISOR as u128;
+        let (rate, elapsed) = (1u64 << 31, 1u64 << 60);
+        assert_eq!(
+            InterestAccrualCircuit::<Fp>::compute_interest(principal, rate, elapsed),
+            Some(3u128 << 91)
+        );
+
+        // Interest above u128
+        assert_eq!(
+            InterestAccrualCircuit::<Fp>::compute_interest(u128::MAX, u32::MAX as u64, u64::MAX),
+            None
+        );
+    }
+
+    #[test]
+    fn test_accrual_valid() {
+        let k = 17;
+
+        let (circuit, public_inputs) = create_accrual_circuit(10000 * USDC, 500, HALF_YEAR, 0);
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert_eq!(prover.verify(), Ok(()), "Accrued debt commitment should verify");
+    }
+
+    #[test]
+    fn test_accrual_with_settled_interest() {
+        let k = 17;
+
+        let (circuit, public_inputs) = create_accrual_circuit(10000 * USDC, 500, 12345, 17 * USDC);
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert_eq!(prover.verify(), Ok(()), "Settled interest should be added");
+    }
+
+    #[test]
+    fn test_accrual_rounding() {
+        let k = 17;
+
+        // 1234567 * 733 * 86399 / DIVISOR has a non-zero remainder
+        let (circuit, public_inputs) = create_accrual_circuit(1_234_567, 733, 86_399, 0);
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert_eq!(prover.verify(), Ok(()), "Rounded-down interest should verify");
+
+        // Rounding up instead (interest + 1, remainder - DIVISOR) must fail
+        let (mut circuit, mut public_inputs) = create_accrual_circuit(1_234_567, 733, 86_399, 0);
+        let interest = InterestAccrualCircuit::<Fp>::compute_interest(1_234_567, 733, 86_399).unwrap();
+        circuit.interest = circuit.interest.map(|i| i + Fp::ONE);
+        circuit.remainder = circuit.remainder.map(|r| r - Fp::from(DIVISOR));
+        public_inputs[1] = compute_commitment(
+            Fp::from_u128(1_234_567 + interest + 1),
+            Fp::from(NEW_SALT),
+        );
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err(), "Rounded-up interest should fail");
+    }
+
+    #[test]
+    fn test_accrual_stale_commitment() {
+        let k = 17;
+
+        // New commitment to the bare principal (no interest) must not verify
+        let (circuit, mut public_inputs) = create_accrual_circuit(10000 * USDC, 500, HALF_YEAR, 0);
+        public_inputs[1] = compute_commitment(Fp::from_u128(10000 * USDC), Fp::from(NEW_SALT));
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err(), "Commitment without interest should fail");
+    }
+
+    #[test]
+    fn test_accrual_understated_interest() {
+        let k = 17;
+
+        // Shift interest into the remainder: interest - 1, remainder + DIVISOR
+        let (mut circuit, mut public_inputs) = create_accrual_circuit(10000 * USDC, 500, HALF_YEAR, 0);
+        circuit.interest = circuit.interest.map(|i| i - Fp::ONE);
+        circuit.remainder = circuit.remainder.map(|r| r + Fp::from(DIVISOR));
+        public_inputs[1] = compute_commitment(Fp::from_u128(10250 * USDC - 1), Fp::from(NEW_SALT));
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err(), "Remainder >= DIVISOR should fail");
+    }
+
+    #[test]
+    fn test_accrual_wrong_public_rate() {
+        let k = 17;
+
+        // Proof made at 5% cannot be presented as a 1% accrual
+        let (circuit, mut public_inputs) = create_accrual_circuit(10000 * USDC, 500, HALF_YEAR, 0);
+        public_inputs[2] = Fp::from(100u64);
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err(), "Mismatched rate should fail");
+    }
+
+    #[test]
+    fn test_accrual_wrong_elapsed() {
+        let k = 17;
+
+        let (circuit, mut public_inputs) = create_accrual_circuit(10000 * USDC, 500, HALF_YEAR, 0);
+        public_inputs[3] = Fp::from(HALF_YEAR / 2);
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err(), "Mismatched elapsed time should fail");
+    }
+
+    #[test]
+    fn test_accrual_wrong_root() {
+        let k = 17;
+
+        // The old debt commitment is proven against the registered root, not revealed
+        let (circuit, mut public_inputs) = create_accrual_circuit(10000 * USDC, 500, HALF_YEAR, 0);
+        public_inputs[0] = compute_commitment(Fp::from_u128(10000 * USDC), Fp::from(OLD_SALT));
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err(), "Old commitment in place of the root should fail");
+    }
+
+    #[test]
+    fn test_accrual_unregistered_commitment() {
+        let k = 17;
+
+        // Tree of other commitments only: no path opens the old debt commitment
+        let mut tree = IncrementalMerkleTree::new(COMMITMENT_TREE_DEPTH).unwrap();
+        for i in 0..3u64 {
+            tree.insert(compute_commitment(Fp::from(1000 + i), Fp::from(44444u64))).unwrap();
+        }
+        let other_path = tree.path(0).unwrap();
+
+        let (mut circuit, mut public_inputs) = create_accrual_circuit(10000 * USDC, 500, HALF_YEAR, 0);
+        circuit.old_siblings = other_path.siblings.iter().map(|s| Value::known(*s)).collect();
+        circuit.old_path_bits = other_path.path_bits_as_field().into_iter().map(Value::known).collect();
+        public_inputs[0] = tree.root();
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err(), "Unregistered old commitment should fail");
+    }
+
+    #[test]
+    fn test_accrual_wrong_nullifier() {
+        let k = 17;
+
+        let (circuit, mut public_inputs) = create_accrual_circuit(10000 * USDC, 500, HALF_YEAR, 0);
+        public_inputs[5] = Fp::from(12345u64);
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err(), "Unrelated nullifier should fail");
+    }
+
+    #[test]
+    fn test_accrual_second_nullifier_key_fails() {
+        let k = 17;
+
+        // Re-spending the stale debt commitment under a second nullifier needs
+        // a second salt that opens it
+        let (mut circuit, mut public_inputs) = create_accrual_circuit(10000 * USDC, 500, HALF_YEAR, 0);
+        let other_salt = Fp::from(99999u64);
+        circuit.old_salt = Value::known(other_salt);
+        let old_commitment = compute_commitment(Fp::from_u128(10000 * USDC), Fp::from(OLD_SALT));
+        public_inputs[5] = compute_nullifier(other_salt, old_commitment);
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err(), "Second nullifier for the same commitment should fail");
+    }
+
+    #[test]
+    fn test_accrual_zero_elapsed() {
+        let k = 17;
+
+        let (circuit, public_inputs) = create_accrual_circuit(10000 * USDC, 500, 0, 0);
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert_eq!(prover.verify(), Ok(()), "No elapsed time leaves the debt unchanged");
+    }
+}
@@ -5,9 +5,19 @@
 //! # Circuits
 //! - `CollateralProof`: Prove collateral >= threshold without revealing amount
 //! - `LTVProof`: Prove LTV ratio within bounds
+//! - `PriceLTVProof`: Prove USD LTV within bounds for collateral and debt in different tokens
+//! - `MultiAssetLTVProof`: Prove price- and collateral-factor-weighted collateral covers the debt
 //! - `LiquidationProof`: Prove position is liquidatable (HF < 1.0)
+//! - `LiquidationSettlement`: Prove repay within the close factor and the collateral seized with the bonus
+//! - `HealthFactorBucket`: Prove a position's HF lies in a public bucket `[lo, hi)`
+//! - `WithdrawProof`: Prove a withdrawal keeps the position within max LTV
+//! - `CommitmentTransition`: Prove a new commitment is the old value ± a public delta
+//! - `InterestAccrual`: Prove a new debt commitment includes interest accrued at a public rate
+//! - `Solvency`: Prove the positions under a Merkle root hold collateral worth at least their total debt
 //!
 //! # Features
+//! - Generic over the field: Pasta `Fp` for the IPA backend, BN254 `Fr` for KZG
+//! - `EvmTranscript`: Keccak256 transcript in the encoding generated Solidity verifiers read
 //! - Production-grade Poseidon hash for commitments
 //! - Efficient range checks using lookup tables
 //! - Comprehensive error handling and validation
@@ -25,10 +35,20 @@
 //! ```
 
 pub mod collateral;
+pub mod constants;
 pub mod error;
 pub mod gadgets;
+pub mod health_factor;
+pub mod interest;
 pub mod liquidation;
+pub mod liquidation_settlement;
 pub mod ltv;
+pub mod multi_asset;
+pub mod price_ltv;
+pub mod solvency;
+pub mod transcript;
+pub mod transition;
+pub mod withdraw;
 
 #[cfg(feature = "wasm")]
 pub mod wasm;
@@ -38,8 +58,21 @@ mod tests;
 
 // Circuit exports
 pub use collateral::CollateralCircuit;
+pub use health_factor::HealthFactorBucketCircuit;
+pub use interest::InterestAccrualCircuit;
 pub use liquidation::LiquidationCircuit;
+pub use liquidation_settlement::LiquidationSettlementCircuit;
 pub use ltv::LTVCircuit;
+pub use multi_asset::MultiAssetLTVCircuit;
+pub use price_ltv::PriceLTVCircuit;
+pub use solvency::{SolvencyCircuit, SolvencyPosition};
+pub use transition::CommitmentTransitionCircuit;
+pub use transcript::{ChallengeEvm, EvmTranscript};
+pub use withdraw::WithdrawCircuit;
+
+// Commitment scheme shared by every circuit: Poseidon(value, salt), spent
+// through the nullifier Poseidon(salt, commitment)
+pub use gadgets::poseidon::{compute_commitment, compute_nullifier};
 
 // Error handling
 pub use error::{CircuitError, CircuitResult};
@@ -48,9 +81,10 @@ pub use error::validation;
 // Gadget exports
 pub use gadgets::{
     ComparisonChip, ComparisonConfig,
+    MerkleChip, MerkleConfig, IncrementalMerkleTree, MerklePath,
     RangeCheckChip, RangeCheckConfig,
     SimpleCommitmentChip, SimpleCommitmentConfig,
-    PoseidonChip, PoseidonConfig,
+    PoseidonChip, PoseidonConfig, PoseidonSponge,
 };
 
 // Re-export commonly used types from halo2
@@ -61,3 +95,6 @@ pub use halo2_proofs::{
 
 // Re-export Pasta curves
 pub use pasta_curves::Fp;
+
+// Re-export the BN254 scalar field
+pub use halo2_proofs::halo2curves::bn256::Fr;
@@ -11,23 +11,29 @@
 //! `collateral * price * liquidation_threshold < debt * PRECISION`
 //!
 //! # Public Inputs
-//! - `price`: Current asset price from oracle
-//! - `liquidation_threshold`: Protocol's liquidation threshold (e.g., 85%)
-//! - `position_hash`: Commitment to the position
+//! - `price`: Current asset price from oracle, constrained to 0 < price < 2^64
+//! - `liquidation_threshold`: Protocol's liquidation threshold (e.g., 85%),
+//!   constrained to 0 < threshold <= 100
+//! - `collateral_commitment`: Poseidon(collateral, salt_c)
+//! - `debt_commitment`: Poseidon(debt, salt_d)
+//!
+//! Both commitments use the shared `compute_commitment` scheme (the same as
+//! `CollateralCircuit` and `LTVCircuit`), so they can be matched against the
+//! position stored in `CommitmentRegistry`.
 //!
 //! # Private Inputs
 //! - `collateral`: Amount of collateral
 //! - `debt`: Amount of debt
-//! - `salt`: Salt for position commitment
+//! - `salt_c`, `salt_d`: Salts for the collateral and debt commitments
 //!
 //! # Use Case
 //! Liquidators can prove a position is liquidatable without revealing
 //! the exact position details until liquidation is executed.
 //!
 //! # Circuit Statistics
-//! - Advice columns: 8 (collateral, debt, salt, price, threshold, computed values)
+//! - Advice columns: 9 (collateral, debt, salt, price, threshold, computed values, commitment, diff)
 //! - Instance columns: 1 (public inputs)
-//! - Custom gates: 2 (computation, position hash)
+//! - Custom gates: computation, Poseidon rounds, comparison
 //! - Lookup tables: 1 (range check for comparison)
 //! - Privacy: Position details hidden until liquidation execution
 
@@ -37,15 +43,17 @@ use halo2_proofs::{
     plonk::{Advice, Circuit, Column, ConstraintSystem, Error, Instance, Selector},
     poly::Rotation,
 };
-use pasta_curves::Fp;
 use std::marker::PhantomData;
 
+use crate::constants::{LOOKUP_BITS, RANGE_BITS, PRICE_BITS};
 use crate::gadgets::comparison::{ComparisonChip, ComparisonConfig, ComparisonInstruction};
+use crate::gadgets::poseidon::{PoseidonChip, PoseidonConfig};
 
-/// Note: For production 64-bit range checks, decompose into multiple smaller checks
-/// Using 16 bits for demo/testing (2^16 = 65536 entries fits in lookup table)
-const RANGE_BITS: usize = 16;
+/// Width of collateral * price * liquidation_threshold
+const SCALED_BITS: usize = RANGE_BITS + PRICE_BITS + LOOKUP_BITS;
 const PRECISION: u64 = 100; // For percentage calculations
+/// Largest valid liquidation_threshold (100%)
+const MAX_THRESHOLD: u64 = PRECISION;
 
 /// Configuration for Liquidation circuit
 #[derive(Debug, Clone)]
@@ -53,6 +61,7 @@ pub struct LiquidationConfig<F: PrimeField> {
     // Private inputs
     pub collateral: Column<Advice>,
     pub debt: Column<Advice>,
+    /// Salts for both commitments (one per row)
     pub salt: Column<Advice>,
 
     // Public inputs (copied from instance)
@@ -63,18 +72,20 @@ pub struct LiquidationConfig<F: PrimeField> {
     pub collateral_value: Column<Advice>,  // collateral * price * liq_threshold
     pub debt_scaled: Column<Advice>,       // debt * PRECISION
 
-    // Commitment
-    pub position_hash: Column<Advice>,
+    /// Poseidon output column (third state column)
+    pub commitment: Column<Advice>,
 
     // Instance column
     pub instance: Column<Instance>,
 
     // Selectors
     pub q_compute: Selector,
-    pub q_commitment: Selector,
+
+    /// Poseidon chip config (state = [collateral, salt, commitment])
+    pub poseidon: PoseidonConfig<F>,
 
     // Comparison: debt_scaled > collateral_value (position is underwater)
-    pub comparison: ComparisonConfig<F, RANGE_BITS>,
+    pub comparison: ComparisonConfig<F, LOOKUP_BITS>,
 
     _marker: PhantomData<F>,
 }
@@ -84,7 +95,8 @@ pub struct LiquidationConfig<F: PrimeField> {
 pub struct LiquidationCircuit<F: PrimeField> {
     pub collateral: Value<F>,
     pub debt: Value<F>,
-    pub salt: Value<F>,
+    pub salt_c: Value<F>,
+    pub salt_d: Value<F>,
     pub price: Value<F>,
     pub liquidation_threshold: Value<F>,
 }
@@ -94,7 +106,8 @@ impl<F: PrimeField> Default for LiquidationCircuit<F> {
         Self {
             collateral: Value::unknown(),
             debt: Value::unknown(),
-            salt: Value::unknown(),
+            salt_c: Value::unknown(),
+            salt_d: Value::unknown(),
             price: Value::unknown(),
             liquidation_threshold: Value::unknown(),
         }
@@ -105,32 +118,21 @@ impl<F: PrimeField> LiquidationCircuit<F> {
     pub fn new(
         collateral: F,
         debt: F,
-        salt: F,
+        salt_c: F,
+        salt_d: F,
         price: F,
         liquidation_threshold: F,
     ) -> Self {
         Self {
             collateral: Value::known(collateral),
             debt: Value::known(debt),
-            salt: Value::known(salt),
+            salt_c: Value::known(salt_c),
+            salt_d: Value::known(salt_d),
             price: Value::known(price),
             liquidation_threshold: Value::known(liquidation_threshold),
         }
     }
 
-    /// Position commitment for demo/testing
-    ///
-    /// position_hash = collateral * salt + debt * salt + collateral + debt
-    ///
-    /// NOTE: This is a simplified commitment for circuit demonstration.
-    /// For production use, replace with Poseidon hash and implement
-    /// Poseidon verification as circuit constraints.
-    ///
-    /// The formula matches the circuit's custom gate constraint.
-    pub fn compute_position_hash(collateral: F, debt: F, salt: F) -> F {
-        collateral * salt + debt * salt + collateral + debt
-    }
-
     /// Check if position is liquidatable
     /// HF = (collateral * price * liq_threshold) / (debt * 100)
     /// Liquidatable when HF < 1, i.e., collateral_value < debt_scaled
@@ -151,15 +153,15 @@ impl<F: PrimeField> LiquidationCircuit<F> {
     }
 }
 
-impl Circuit<Fp> for LiquidationCircuit<Fp> {
-    type Config = LiquidationConfig<Fp>;
+impl<F: PrimeField> Circuit<F> for LiquidationCircuit<F> {
+    type Config = LiquidationConfig<F>;
     type FloorPlanner = SimpleFloorPlanner;
 
     fn without_witnesses(&self) -> Self {
         Self::default()
     }
 
-    fn configure(meta: &mut ConstraintSystem<Fp>) -> Self::Config {
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
         // Advice columns
         let collateral = meta.advice_column();
         let debt = meta.advice_column();
@@ -168,20 +170,19 @@ impl Circuit<Fp> for LiquidationCircuit<Fp> {
         let liquidation_threshold = meta.advice_column();
         let collateral_value = meta.advice_column();
         let debt_scaled = meta.advice_column();
-        let position_hash = meta.advice_column();
+        let commitment = meta.advice_column();
 
         // Instance column
         let instance = meta.instance_column();
 
         // Enable equality
-        for col in [collateral, debt, collateral_value, debt_scaled, position_hash, price, liquidation_threshold] {
+        for col in [collateral, debt, salt, collateral_value, debt_scaled, commitment, price, liquidation_threshold] {
             meta.enable_equality(col);
         }
         meta.enable_equality(instance);
 
         // Selectors
         let q_compute = meta.selector();
-        let q_commitment = meta.selector();
 
         // Computation gate:
         // collateral_value = collateral * price * liquidation_threshold
@@ -195,7 +196,7 @@ impl Circuit<Fp> for LiquidationCircuit<Fp> {
             let cv = meta.query_advice(collateral_value, Rotation::cur());
             let ds = meta.query_advice(debt_scaled, Rotation::cur());
             let precision = halo2_proofs::plonk::Expression::Constant(
-                Fp::from(PRECISION)
+                F::from(PRECISION)
             );
 
             vec![
@@ -206,27 +207,19 @@ impl Circuit<Fp> for LiquidationCircuit<Fp> {
             ]
         });
 
-        // Position commitment gate
-        meta.create_gate("position hash", |meta| {
-            let q = meta.query_selector(q_commitment);
-            let coll = meta.query_advice(collateral, Rotation::cur());
-            let d = meta.query_advice(debt, Rotation::cur());
-            let s = meta.query_advice(salt, Rotation::cur());
-            let hash = meta.query_advice(position_hash, Rotation::cur());
-
-            // hash = coll * s + debt * s + coll + debt
-            vec![q * (hash - coll.clone() * s.clone() - d.clone() * s - coll - d)]
-        });
+        // Poseidon chip for both commitments
+        let poseidon = PoseidonChip::configure(meta, [collateral, salt, commitment]);
 
         // Comparison: debt_scaled > collateral_value
         // (proving HF < 1.0, position is underwater)
         let diff = meta.advice_column();
         meta.enable_equality(diff);
-        let comparison = ComparisonChip::<Fp, RANGE_BITS>::configure(
+        let comparison = ComparisonChip::<F, LOOKUP_BITS>::configure_with_range_bits(
             meta,
             debt_scaled,        // a = debt_scaled (must be greater)
             collateral_value,   // b = collateral_value
             diff,
+            SCALED_BITS,
         );
 
         LiquidationConfig {
@@ -237,10 +230,10 @@ impl Circuit<Fp> for LiquidationCircuit<Fp> {
             liquidation_threshold,
             collateral_value,
             debt_scaled,
-            position_hash,
+            commitment,
             instance,
             q_compute,
-            q_commitment,
+            poseidon,
             comparison,
             _marker: PhantomData,
         }
@@ -249,29 +242,28 @@ impl Circuit<Fp> for LiquidationCircuit<Fp> {
     fn synthesize(
         &self,
         config: Self::Config,
-        mut layouter: impl Layouter<Fp>,
+        mut layouter: impl Layouter<F>,
     ) -> Result<(), Error> {
         // Load comparison lookup table
-        let comparison_chip = ComparisonChip::<Fp, RANGE_BITS>::construct(config.comparison.clone());
+        let comparison_chip = ComparisonChip::<F, LOOKUP_BITS>::construct(config.comparison.clone());
         comparison_chip.load_table(layouter.namespace(|| "load table"))?;
 
         // Main computation region
-        let (debt_scaled_cell, collateral_value_cell, position_hash_cell) =
+        let (collateral_cell, debt_cell, price_cell, lt_cell, debt_scaled_cell, collateral_value_cell) =
             layouter.assign_region(
                 || "liquidation computation",
                 |mut region| {
                     // Enable selectors
                     config.q_compute.enable(&mut region, 0)?;
-                    config.q_commitment.enable(&mut region, 0)?;
 
                     // Assign private inputs
-                    region.assign_advice(|| "collateral", config.collateral, 0, || self.collateral)?;
-                    region.assign_advice(|| "debt", config.debt, 0, || self.debt)?;
-                    region.assign_advice(|| "salt", config.salt, 0, || self.salt)?;
+                    let collateral_cell =
+                        region.assign_advice(|| "collateral", config.collateral, 0, || self.collateral)?;
+                    let debt_cell = region.assign_advice(|| "debt", config.debt, 0, || self.debt)?;
 
                     // Assign public inputs (from instance)
-                    region.assign_advice(|| "price", config.price, 0, || self.price)?;
-                    region.assign_advice(
+                    let price_cell = region.assign_advice(|| "price", config.price, 0, || self.price)?;
+                    let lt_cell = region.assign_advice(
                         || "liquidation_threshold",
                         config.liquidation_threshold,
                         0,
@@ -291,7 +283,7 @@ impl Circuit<Fp> for LiquidationCircuit<Fp> {
                     )?;
 
                     // Compute debt_scaled = debt * PRECISION
-                    let ds_val = self.debt.map(|d| d * Fp::from(PRECISION));
+                    let ds_val = self.debt.map(|d| d * F::from(PRECISION));
                     let debt_scaled_cell = region.assign_advice(
                         || "debt_scaled",
                         config.debt_scaled,
@@ -299,27 +291,68 @@ impl Circuit<Fp> for LiquidationCircuit<Fp> {
                         || ds_val,
                     )?;
 
-                    // Compute position hash
-                    let hash_val = self.collateral
-                        .zip(self.debt)
-                        .zip(self.salt)
-                        .map(|((c, d), s)| Self::compute_position_hash(c, d, s));
-                    let position_hash_cell = region.assign_advice(
-                        || "position_hash",
-                        config.position_hash,
-                        0,
-                        || hash_val,
-                    )?;
-
-                    Ok((debt_scaled_cell, collateral_value_cell, position_hash_cell))
+                    Ok((
+                        collateral_cell,
+                        debt_cell,
+                        price_cell,
+                        lt_cell,
+                        debt_scaled_cell,
+                        collateral_value_cell,
+                    ))
                 },
             )?;
 
+        // Salts
+        let (salt_c_cell, salt_d_cell) = layouter.assign_region(
+            || "assign salts",
+            |mut region| {
+                let salt_c = region.assign_advice(|| "salt_c", config.salt, 0, || self.salt_c)?;
+                let salt_d = region.assign_advice(|| "salt_d", config.salt, 1, || self.salt_d)?;
+                Ok((salt_c, salt_d))
+            },
+        )?;
+
+        // Commitments = Poseidon(value, salt)
+        let poseidon_chip = PoseidonChip::construct(config.poseidon.clone());
+        let coll_comm_cell = poseidon_chip.hash(
+            layouter.namespace(|| "collateral commitment"),
+            collateral_cell.clone(),
+            salt_c_cell,
+        )?;
+        let debt_comm_cell = poseidon_chip.hash(
+            layouter.namespace(|| "debt commitment"),
+            debt_cell.clone(),
+            salt_d_cell,
+        )?;
+
         // Constrain public inputs
         // instance[0] = price
         // instance[1] = liquidation_threshold
-        // instance[2] = position_hash
-        layouter.constrain_instance(position_hash_cell.cell(), config.instance, 2)?;
+        // instance[2] = collateral_commitment
+        // instance[3] = debt_commitment
+        layouter.constrain_instance(price_cell.cell(), config.instance, 0)?;
+        layouter.constrain_instance(lt_cell.cell(), config.instance, 1)?;
+        layouter.constrain_instance(coll_comm_cell.cell(), config.instance, 2)?;
+        layouter.constrain_instance(debt_comm_cell.cell(), config.instance, 3)?;
+
+        // Public parameter bounds: 0 < price < 2^64, 0 < threshold <= 100
+        comparison_chip.in_range(
+            layouter.namespace(|| "price bounds"),
+            price_cell,
+            F::ONE,
+            F::from(u64::MAX),
+        )?;
+        comparison_chip.in_range(
+            layouter.namespace(|| "liquidation_threshold bounds"),
+            lt_cell,
+            F::ONE,
+            F::from(MAX_THRESHOLD),
+        )?;
+
+        // Bound the private amounts so the products cannot wrap the field
+        for cell in [collateral_cell, debt_cell] {
+            comparison_chip.range_check(layouter.namespace(|| "amount range"), cell, RANGE_BITS)?;
+        }
 
         // Prove position is liquidatable: debt_scaled > collateral_value
         // This means HF < 1.0
@@ -336,6 +369,8 @@ impl Circuit<Fp> for LiquidationCircuit<Fp> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::gadgets::poseidon::compute_commitment;
+    use pasta_curves::Fp;
     use halo2_proofs::dev::MockProver;
 
     fn create_liquidation_circuit(
@@ -346,18 +381,18 @@ mod tests {
     ) -> (LiquidationCircuit<Fp>, Vec<Fp>) {
         let collateral_fp = Fp::from(collateral);
         let debt_fp = Fp::from(debt);
-        let salt = Fp::from(99999u64);
+        let salt_c = Fp::from(22222u64);
+        let salt_d = Fp::from(11111u64);
         let price_fp = Fp::from(price);
         let lt_fp = Fp::from(liquidation_threshold);
 
-        let position_hash = LiquidationCircuit::compute_position_hash(
-            collateral_fp, debt_fp, salt
-        );
+        let collateral_commitment = compute_commitment(collateral_fp, salt_c);
+        let debt_commitment = compute_commitment(debt_fp, salt_d);
 
         let circuit = LiquidationCircuit::new(
-            collateral_fp, debt_fp, salt, price_fp, lt_fp
+            collateral_fp, debt_fp, salt_c, salt_d, price_fp, lt_fp
         );
-        let public_inputs = vec![price_fp, lt_fp, position_hash];
+        let public_inputs = vec![price_fp, lt_fp, collateral_commitment, debt_commitment];
 
         (circuit, public_inputs)
     }
@@ -422,6 +457,61 @@ mod tests {
         assert_eq!(prover.verify(), Ok(()), "Borderline case should be liquidatable");
     }
 
+    #[test]
+    fn test_liquidation_wei_amounts() {
+        let k = 17;
+
+        // 0.01 ETH collateral (wei) at price 1000, threshold 85%
+        // collateral_value = 10^16 * 1000 * 85 = 8.5e20
+        // debt_scaled = 9e18 * 100 = 9e20 → liquidatable
+        let (circuit, public_inputs) =
+            create_liquidation_circuit(10_000_000_000_000_000, 9_000_000_000_000_000_000, 1000, 85);
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert_eq!(prover.verify(), Ok(()), "Wei-denominated underwater position should pass");
+
+        // debt_scaled = 8e20 < 8.5e20 → healthy
+        let (circuit, public_inputs) =
+            create_liquidation_circuit(10_000_000_000_000_000, 8_000_000_000_000_000_000, 1000, 85);
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err(), "Wei-denominated healthy position should fail");
+    }
+
+    #[test]
+    fn test_commitments_match_registry_scheme() {
+        let k = 17;
+
+        // The registry's commitments come from CollateralCircuit / LTVCircuit
+        let salt_c = Fp::from(22222u64);
+        let salt_d = Fp::from(11111u64);
+        let collateral_commitment = crate::CollateralCircuit::compute_commitment(Fp::from(100u64), salt_c);
+        let debt_commitment = crate::LTVCircuit::compute_commitment(Fp::from(90u64), salt_d);
+        assert_eq!(debt_commitment, compute_commitment(Fp::from(90u64), salt_d));
+
+        let (circuit, public_inputs) = create_liquidation_circuit(100, 90, 1, 85);
+        assert_eq!(public_inputs[2], collateral_commitment);
+        assert_eq!(public_inputs[3], debt_commitment);
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert_eq!(prover.verify(), Ok(()), "Registry commitments should verify");
+    }
+
+    #[test]
+    fn test_liquidation_wrong_position_fails() {
+        let k = 17;
+
+        // Proof about (100, 90) cannot be presented against another position's debt
+        let (circuit, mut public_inputs) = create_liquidation_circuit(100, 90, 1, 85);
+        public_inputs[3] = compute_commitment(Fp::from(50u64), Fp::from(11111u64));
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err(), "Unrelated debt commitment should fail");
+
+        // Swapping the collateral and debt commitments must also fail
+        let (circuit, mut public_inputs) = create_liquidation_circuit(100, 90, 1, 85);
+        public_inputs.swap(2, 3);
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err(), "Swapped commitments should fail");
+    }
+
     #[test]
     fn test_is_liquidatable_helper() {
         // Test the helper function (with values that fit in 16-bit range)
@@ -0,0 +1,784 @@
+//! LiquidationSettlement Circuit
+//!
+//! Proves how much debt a liquidator may repay and how much collateral they may
+//! seize, and commits to the position left after liquidation:
+//! `repay <= debt * close_factor / 100` and
+//! `seized = repay * (100 + bonus) / 100 * 10^20 / price`
+//!
+//! The seizure formula is `ZKLendingPool.liquidate` step for step (collateral in
+//! 18 decimals, debt in 6 decimals, price in 8 decimals), including Solidity's
+//! rounding down after each division. Each division is witnessed as a quotient
+//! and a bounded remainder.
+//!
+//! Pair with `LiquidationCircuit` (HF < 1.0) on the same commitments to show
+//! the position may be liquidated at all.
+//!
+//! # Public Inputs
+//! - `price`: Collateral price from oracle (8 decimals), 0 < price < 2^64
+//! - `close_factor`: Largest share of the debt repayable at once (%), 0 < close_factor <= 100
+//! - `bonus`: Liquidation bonus (%), bonus <= 100
+//! - `repay`: Debt repaid by the liquidator
+//! - `seized`: Collateral transferred to the liquidator
+//! - `collateral_commitment`: Poseidon(collateral, salt_c) - before liquidation
+//! - `debt_commitment`: Poseidon(debt, salt_d) - before liquidation
+//! - `new_collateral_commitment`: Poseidon(collateral - seized, new_salt_c)
+//! - `new_debt_commitment`: Poseidon(debt - repay, new_salt_d)
+//!
+//! # Private Inputs
+//! - `collateral`, `debt`: Position before liquidation
+//! - `salt_c`, `salt_d`, `new_salt_c`, `new_salt_d`: Salts for commitments
+//! - `value_usd`, `usd_remainder`, `seize_remainder`: Division witnesses
+//!
+//! # Constraints
+//! 1. `repay * 100 <= debt * close_factor`
+//! 2. `repay * (100 + bonus) == value_usd * 100 + usd_remainder`, `0 <= usd_remainder < 100`
+//! 3. `value_usd * 10^20 == seized * price + seize_remainder`, `0 <= seize_remainder < price`
+//! 4. `new_debt = debt - repay`, `new_collateral = collateral - seized`, both >= 0
+//! 5. All four commitments == Poseidon(value, salt)
+//!
+//! # Example
+//! - debt: 1800 USDC, collateral: 1 ETH, price: $2000, close_factor: 50%, bonus: 5%
+//! - repay 900 USDC → value 945 USD → seized 0.4725 ETH
+//! - post-liquidation: debt 900 USDC, collateral 0.5275 ETH
+//!
+//! # Circuit Statistics
+//! - Advice columns: 17 (amounts, public parameters, division witnesses, salt, commitment, diff)
+//! - Instance columns: 1 (public inputs)
+//! - Custom gates: settlement arithmetic, Poseidon rounds, comparison
+//! - Lookup tables: 1 (range check)
+//! - Estimated rows: ~2^17 (16-bit lookup table, four Poseidon hashes)
+
+use ff::PrimeField;
+use halo2_proofs::{
+    circuit::{Layouter, SimpleFloorPlanner, Value},
+    plonk::{Advice, Circuit, Column, ConstraintSystem, Error, Expression, Instance, Selector},
+    poly::Rotation,
+};
+use std::marker::PhantomData;
+
+use crate::constants::{LOOKUP_BITS, RANGE_BITS, PRICE_BITS};
+use crate::gadgets::comparison::{ComparisonChip, ComparisonConfig, ComparisonInstruction};
+use crate::gadgets::poseidon::{PoseidonChip, PoseidonConfig};
+
+/// Width of the compared values (`debt * close_factor` needs 135 bits)
+const SCALED_BITS: usize = RANGE_BITS + LOOKUP_BITS;
+const PERCENTAGE_BASE: u64 = 100; // `ZKLendingPool.PERCENTAGE_BASE`
+/// Largest valid close_factor (100%)
+const MAX_CLOSE_FACTOR: u64 = PERCENTAGE_BASE;
+/// Largest valid liquidation bonus (100%)
+const MAX_BONUS: u64 = PERCENTAGE_BASE;
+/// USD value (6 decimals) → collateral (18 decimals) at an 8-decimal price
+pub const SEIZE_SCALE: u128 = 100_000_000_000_000_000_000;
+
+/// Configuration for the LiquidationSettlement circuit
+#[derive(Debug, Clone)]
+pub struct LiquidationSettlementConfig<F: PrimeField> {
+    // Private position
+    pub collateral: Column<Advice>,
+    pub debt: Column<Advice>,
+
+    // Public parameters and amounts (copied from instance)
+    pub price: Column<Advice>,
+    pub close_factor: Column<Advice>,
+    pub bonus: Column<Advice>,
+    pub repay: Column<Advice>,
+    pub seized: Column<Advice>,
+
+    // Intermediate values
+    pub repay_scaled: Column<Advice>,     // repay * 100
+    pub max_repay: Column<Advice>,        // debt * close_factor
+    pub value_usd: Column<Advice>,        // repay * (100 + bonus) / 100
+    pub usd_remainder: Column<Advice>,
+    pub seize_remainder: Column<Advice>,
+    pub new_collateral: Column<Advice>,   // collateral - seized
+    pub new_debt: Column<Advice>,         // debt - repay
+
+    /// Salts for the four commitments (one per row)
+    pub salt: Column<Advice>,
+    /// Poseidon output column (third state column)
+    pub commitment: Column<Advice>,
+
+    // Public inputs
+    pub instance: Column<Instance>,
+
+    // Gates
+    pub q_settlement: Selector,
+
+    /// Poseidon chip config (state = [collateral, salt, commitment])
+    pub poseidon: PoseidonConfig<F>,
+    /// Comparison for the close factor, remainders and range checks
+    pub comparison: ComparisonConfig<F, LOOKUP_BITS>,
+
+    _marker: PhantomData<F>,
+}
+
+/// LiquidationSettlement circuit
+#[derive(Clone)]
+pub struct LiquidationSettlementCircuit<F: PrimeField> {
+    pub collateral: Value<F>,
+    pub debt: Value<F>,
+    pub salt_c: Value<F>,
+    pub salt_d: Value<F>,
+    pub new_salt_c: Value<F>,
+    pub new_salt_d: Value<F>,
+    pub price: Value<F>,
+    pub close_factor: Value<F>,
+    pub bonus: Value<F>,
+    pub repay: Value<F>,
+    /// Collateral seized (public, computed from repay)
+    pub seized: Value<F>,
+    /// Private: `repay * (100 + bonus) / 100`
+    pub value_usd: Value<F>,
+    /// Private: remainder of the USD value division
+    pub usd_remainder: Value<F>,
+    /// Private: remainder of the seizure division
+    pub seize_remainder: Value<F>,
+}
+
+impl<F: PrimeField> Default for LiquidationSettlementCircuit<F> {
+    fn default() -> Self {
+        Self {
+            collateral: Value::unknown(),
+            debt: Value::unknown(),
+            salt_c: Value::unknown(),
+            salt_d: Value::unknown(),
+            new_salt_c: Value::unknown(),
+            new_salt_d: Value::unknown(),
+            price: Value::unknown(),
+            close_factor: Value::unknown(),
+            bonus: Value::unknown(),
+            repay: Value::unknown(),
+            seized: Value::unknown(),
+            value_usd: Value::unknown(),
+            usd_remainder: Value::unknown(),
+            seize_remainder: Value::unknown(),
+        }
+    }
+}
+
+/// Quotients and remainders of the two seizure divisions
+struct Seizure {
+    value_usd: u128,
+    usd_remainder: u128,
+    seized: u128,
+    seize_remainder: u128,
+}
+
+impl<F: PrimeField> LiquidationSettlementCircuit<F> {
+    /// Create a new circuit, computing the seizure witnesses
+    ///
+    /// If the seizure overflows a u128 (or price is zero) the witnesses are zero
+    /// and the proof fails.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        collateral: u128,
+        debt: u128,
+        salt_c: F,
+        salt_d: F,
+        new_salt_c: F,
+        new_salt_d: F,
+        price: u64,
+        close_factor: u64,
+        bonus: u64,
+        repay: u128,
+    ) -> Self {
+        let seizure = Self::divide_seizure(repay, bonus, price).unwrap_or(Seizure {
+            value_usd: 0,
+            usd_remainder: 0,
+            seized: 0,
+            seize_remainder: 0,
+        });
+
+        Self {
+            collateral: Value::known(F::from_u128(collateral)),
+            debt: Value::known(F::from_u128(debt)),
+            salt_c: Value::known(salt_c),
+            salt_d: Value::known(salt_d),
+            new_salt_c: Value::known(new_salt_c),
+            new_salt_d: Value::known(new_salt_d),
+            price: Value::known(F::from(price)),
+            close_factor: Value::known(F::from(close_factor)),
+            bonus: Value::known(F::from(bonus)),
+            repay: Value::known(F::from_u128(repay)),
+            seized: Value::known(F::from_u128(seizure.seized)),
+            value_usd: Value::known(F::from_u128(seizure.value_usd)),
+            usd_remainder: Value::known(F::from_u128(seizure.usd_remainder)),
+            seize_remainder: Value::known(F::from_u128(seizure.seize_remainder)),
+        }
+    }
+
+    /// Largest repayable debt: `debt * close_factor / 100`, rounded down
+    pub fn max_repay(debt: u128, close_factor: u64) -> Option<u128> {
+        let base = PERCENTAGE_BASE as u128;
+        let close_factor = close_factor as u128;
+        (debt / base)
+            .checked_mul(close_factor)?
+            .checked_add(debt % base * close_factor / base)
+    }
+
+    /// Collateral seized for `repay`, rounded down like `ZKLendingPool.liquidate`
+    ///
+    /// `repay * (100 + bonus) / 100 * 10^20 / price`. `None` if price is zero
+    /// or the result does not fit in a u128.
+    pub fn compute_seized(repay: u128, bonus: u64, price: u64) -> Option<u128> {
+        Self::divide_seizure(repay, bonus, price).map(|s| s.seized)
+    }
+
+    /// Both divisions of the seizure, exact even when `value_usd * 10^20`
+    /// exceeds a u128
+    ///
+    /// With `value_usd = hi * price + lo` and `10^20 = 10^10 * 10^10`:
+    /// `value_usd * 10^20 = hi * 10^20 * price + lo * 10^20`, and
+    /// `lo * 10^10 = q * price + r` gives `lo * 10^20 = q * 10^10 * price + r * 10^10`,
+    /// where `lo * 10^10` and `r * 10^10` stay below 2^98.
+    fn divide_seizure(repay: u128, bonus: u64, price: u64) -> Option<Seizure> {
+        if price == 0 {
+            return None;
+        }
+        let base = PERCENTAGE_BASE as u128;
+        let bonus_factor = base + bonus as u128;
+        let scaled = repay.checked_mul(bonus_factor)?;
+        let (value_usd, usd_remainder) = (scaled / base, scaled % base);
+
+        let price = price as u128;
+        let half_scale = 10_000_000_000u128;
+        let (hi, lo) = (value_usd / price, value_usd % price);
+        let (q, r) = (lo * half_scale / price, lo * half_scale % price);
+        let low = r * half_scale;
+
+        let seized = hi
+            .checked_mul(SEIZE_SCALE)?
+            .checked_add(q * half_scale)?
+            .checked_add(low / price)?;
+
+        Some(Seizure {
+            value_usd,
+            usd_remainder,
+            seized,
+            seize_remainder: low % price,
+        })
+    }
+}
+
+impl<F: PrimeField> Circuit<F> for LiquidationSettlementCircuit<F> {
+    type Config = LiquidationSettlementConfig<F>;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        // Advice columns
+        let collateral = meta.advice_column();
+        let debt = meta.advice_column();
+        let price = meta.advice_column();
+        let close_factor = meta.advice_column();
+        let bonus = meta.advice_column();
+        let repay = meta.advice_column();
+        let seized = meta.advice_column();
+        let repay_scaled = meta.advice_column();
+        let max_repay = meta.advice_column();
+        let value_usd = meta.advice_column();
+        let usd_remainder = meta.advice_column();
+        let seize_remainder = meta.advice_column();
+        let new_collateral = meta.advice_column();
+        let new_debt = meta.advice_column();
+        let salt = meta.advice_column();
+        let commitment = meta.advice_column();
+
+        // Instance for public inputs
+        let instance = meta.instance_column();
+
+        // Enable equality
+        for col in [
+            collateral,
+            debt,
+            price,
+            close_factor,
+            bonus,
+            repay,
+            seized,
+            repay_scaled,
+            max_repay,
+            value_usd,
+            usd_remainder,
+            seize_remainder,
+            new_collateral,
+            new_debt,
+            salt,
+            commitment,
+        ] {
+            meta.enable_equality(col);
+        }
+        meta.enable_equality(instance);
+
+        let q_settlement = meta.selector();
+
+        // Settlement gate:
+        //   repay_scaled = repay * 100, max_repay = debt * close_factor
+        //   repay * (100 + bonus) = value_usd * 100 + usd_remainder
+        //   value_usd * 10^20 = seized * price + seize_remainder
+        //   new_debt = debt - repay, new_collateral = collateral - seized
+        meta.create_gate("liquidation settlement", |meta| {
+            let q = meta.query_selector(q_settlement);
+            let coll = meta.query_advice(collateral, Rotation::cur());
+            let d = meta.query_advice(debt, Rotation::cur());
+            let p = meta.query_advice(price, Rotation::cur());
+            let cf = meta.query_advice(close_factor, Rotation::cur());
+            let b = meta.query_advice(bonus, Rotation::cur());
+            let r = meta.query_advice(repay, Rotation::cur());
+            let s = meta.query_advice(seized, Rotation::cur());
+            let rs = meta.query_advice(repay_scaled, Rotation::cur());
+            let mr = meta.query_advice(max_repay, Rotation::cur());
+            let v = meta.query_advice(value_usd, Rotation::cur());
+            let ur = meta.query_advice(usd_remainder, Rotation::cur());
+            let sr = meta.query_advice(seize_remainder, Rotation::cur());
+            let nc = meta.query_advice(new_collateral, Rotation::cur());
+            let nd = meta.query_advice(new_debt, Rotation::cur());
+            let base = Expression::Constant(F::from(PERCENTAGE_BASE));
+            let seize_scale = Expression::Constant(F::from_u128(SEIZE_SCALE));
+
+            vec![
+                q.clone() * (rs - r.clone() * base.clone()),
+                q.clone() * (mr - d.clone() * cf),
+                q.clone() * (r.clone() * (base.clone() + b) - v.clone() * base - ur),
+                q.clone() * (v * seize_scale - s.clone() * p - sr),
+                q.clone() * (nd - d + r),
+                q * (nc - coll + s),
+            ]
+        });
+
+        // Poseidon chip for the four commitments
+        let poseidon = PoseidonChip::configure(meta, [collateral, salt, commitment]);
+
+        // Comparison config for the close factor and remainder bounds
+        let diff = meta.advice_column();
+        meta.enable_equality(diff);
+        let comparison = ComparisonChip::<F, LOOKUP_BITS>::configure_with_range_bits(
+            meta,
+            max_repay,     // a = debt * close_factor
+            repay_scaled,  // b = repay * 100
+            diff,
+            SCALED_BITS,
+        );
+
+        LiquidationSettlementConfig {
+            collateral,
+            debt,
+            price,
+            close_factor,
+            bonus,
+            repay,
+            seized,
+            repay_scaled,
+            max_repay,
+            value_usd,
+            usd_remainder,
+            seize_remainder,
+            new_collateral,
+            new_debt,
+            salt,
+            commitment,
+            instance,
+            q_settlement,
+            poseidon,
+            comparison,
+            _marker: PhantomData,
+        }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        // Load comparison lookup table
+        let comparison_chip = ComparisonChip::<F, LOOKUP_BITS>::construct(config.comparison.clone());
+        comparison_chip.load_table(layouter.namespace(|| "load table"))?;
+
+        // Main region: settlement arithmetic
+        let (amount_cells, param_cells, repay_scaled_cell, max_repay_cell, usd_remainder_cell, seize_remainder_cell) =
+            layouter.assign_region(
+                || "liquidation settlement",
+                |mut region| {
+                    config.q_settlement.enable(&mut region, 0)?;
+
+                    // Assign private position
+                    let collateral_cell =
+                        region.assign_advice(|| "collateral", config.collateral, 0, || self.collateral)?;
+                    let debt_cell = region.assign_advice(|| "debt", config.debt, 0, || self.debt)?;
+
+                    // Assign public parameters and amounts (from instance)
+                    let price_cell = region.assign_advice(|| "price", config.price, 0, || self.price)?;
+                    let cf_cell =
+                        region.assign_advice(|| "close_factor", config.close_factor, 0, || self.close_factor)?;
+                    let bonus_cell = region.assign_advice(|| "bonus", config.bonus, 0, || self.bonus)?;
+                    let repay_cell = region.assign_advice(|| "repay", config.repay, 0, || self.repay)?;
+                    let seized_cell = region.assign_advice(|| "seized", config.seized, 0, || self.seized)?;
+
+                    // repay_scaled = repay * 100, max_repay = debt * close_factor
+                    let base = F::from(PERCENTAGE_BASE);
+                    let repay_scaled_cell = region.assign_advice(
+                        || "repay_scaled",
+                        config.repay_scaled,
+                        0,
+                        || self.repay.map(|r| r * base),
+                    )?;
+                    let max_repay_cell = region.assign_advice(
+                        || "max_repay",
+                        config.max_repay,
+                        0,
+                        || self.debt.zip(self.close_factor).map(|(d, cf)| d * cf),
+                    )?;
+
+                    // Division witnesses
+                    let value_usd_cell =
+                        region.assign_advice(|| "value_usd", config.value_usd, 0, || self.value_usd)?;
+                    let usd_remainder_cell = region.assign_advice(
+                        || "usd_remainder",
+                        config.usd_remainder,
+                        0,
+                        || self.usd_remainder,
+                    )?;
+                    let seize_remainder_cell = region.assign_advice(
+                        || "seize_remainder",
+                        config.seize_remainder,
+                        0,
+                        || self.seize_remainder,
+                    )?;
+
+                    // Post-liquidation position
+                    let new_collateral_cell = region.assign_advice(
+                        || "new_collateral",
+                        config.new_collateral,
+                        0,
+                        || self.collateral.zip(self.seized).map(|(c, s)| c - s),
+                    )?;
+                    let new_debt_cell = region.assign_advice(
+                        || "new_debt",
+                        config.new_debt,
+                        0,
+                        || self.debt.zip(self.repay).map(|(d, r)| d - r),
+                    )?;
+
+                    Ok((
+                        [
+                            collateral_cell,
+                            debt_cell,
+                            repay_cell,
+                            seized_cell,
+                            value_usd_cell,
+                            new_collateral_cell,
+                            new_debt_cell,
+                        ],
+                        [price_cell, cf_cell, bonus_cell],
+                        repay_scaled_cell,
+                        max_repay_cell,
+                        usd_remainder_cell,
+                        seize_remainder_cell,
+                    ))
+                },
+            )?;
+        let [collateral_cell, debt_cell, repay_cell, seized_cell, value_usd_cell, new_collateral_cell, new_debt_cell] =
+            amount_cells;
+        let [price_cell, cf_cell, bonus_cell] = param_cells;
+
+        // Salts
+        let [salt_c_cell, salt_d_cell, new_salt_c_cell, new_salt_d_cell] = layouter.assign_region(
+            || "assign salts",
+            |mut region| {
+                let salt_c = region.assign_advice(|| "salt_c", config.salt, 0, || self.salt_c)?;
+                let salt_d = region.assign_advice(|| "salt_d", config.salt, 1, || self.salt_d)?;
+                let new_salt_c = region.assign_advice(|| "new_salt_c", config.salt, 2, || self.new_salt_c)?;
+                let new_salt_d = region.assign_advice(|| "new_salt_d", config.salt, 3, || self.new_salt_d)?;
+                Ok([salt_c, salt_d, new_salt_c, new_salt_d])
+            },
+        )?;
+
+        // Commitments = Poseidon(value, salt)
+        let poseidon_chip = PoseidonChip::construct(config.poseidon.clone());
+        let coll_comm_cell = poseidon_chip.hash(
+            layouter.namespace(|| "collateral commitment"),
+            collateral_cell.clone(),
+            salt_c_cell,
+        )?;
+        let debt_comm_cell = poseidon_chip.hash(
+            layouter.namespace(|| "debt commitment"),
+            debt_cell.clone(),
+            salt_d_cell,
+        )?;
+        let new_coll_comm_cell = poseidon_chip.hash(
+            layouter.namespace(|| "new collateral commitment"),
+            new_collateral_cell.clone(),
+            new_salt_c_cell,
+        )?;
+        let new_debt_comm_cell = poseidon_chip.hash(
+            layouter.namespace(|| "new debt commitment"),
+            new_debt_cell.clone(),
+            new_salt_d_cell,
+        )?;
+
+        // Constrain public inputs
+        // instance[0] = price
+        // instance[1] = close_factor
+        // instance[2] = bonus
+        // instance[3] = repay
+        // instance[4] = seized
+        // instance[5] = collateral_commitment
+        // instance[6] = debt_commitment
+        // instance[7] = new_collateral_commitment
+        // instance[8] = new_debt_commitment
+        layouter.constrain_instance(price_cell.cell(), config.instance, 0)?;
+        layouter.constrain_instance(cf_cell.cell(), config.instance, 1)?;
+        layouter.constrain_instance(bonus_cell.cell(), config.instance, 2)?;
+        layouter.constrain_instance(repay_cell.cell(), config.instance, 3)?;
+        layouter.constrain_instance(seized_cell.cell(), config.instance, 4)?;
+        layouter.constrain_instance(coll_comm_cell.cell(), config.instance, 5)?;
+        layouter.constrain_instance(debt_comm_cell.cell(), config.instance, 6)?;
+        layouter.constrain_instance(new_coll_comm_cell.cell(), config.instance, 7)?;
+        layouter.constrain_instance(new_debt_comm_cell.cell(), config.instance, 8)?;
+
+        // Public parameter bounds: 0 < price < 2^64, 0 < close_factor <= 100, bonus <= 100
+        comparison_chip.in_range(
+            layouter.namespace(|| "price bounds"),
+            price_cell.clone(),
+            F::ONE,
+            F::from(u64::MAX),
+        )?;
+        comparison_chip.in_range(
+            layouter.namespace(|| "close_factor bounds"),
+            cf_cell,
+            F::ONE,
+            F::from(MAX_CLOSE_FACTOR),
+        )?;
+        comparison_chip.in_range(
+            layouter.namespace(|| "bonus bounds"),
+            bonus_cell,
+            F::ZERO,
+            F::from(MAX_BONUS),
+        )?;
+
+        // Amounts fit in 128 bits: no product wraps the field, and the
+        // post-liquidation balances cannot go below zero
+        for cell in [
+            collateral_cell,
+            debt_cell,
+            repay_cell,
+            seized_cell,
+            value_usd_cell,
+            new_collateral_cell,
+            new_debt_cell,
+        ] {
+            comparison_chip.range_check(layouter.namespace(|| "amount range"), cell, RANGE_BITS)?;
+        }
+
+        // Rounding: 0 <= usd_remainder < 100, 0 <= seize_remainder < price
+        comparison_chip.in_range(
+            layouter.namespace(|| "usd remainder bounds"),
+            usd_remainder_cell,
+            F::ZERO,
+            F::from(PERCENTAGE_BASE - 1),
+        )?;
+        comparison_chip.range_check(
+            layouter.namespace(|| "seize remainder range"),
+            seize_remainder_cell.clone(),
+            PRICE_BITS,
+        )?;
+        comparison_chip.lt(
+            layouter.namespace(|| "seize remainder bound"),
+            seize_remainder_cell,
+            price_cell,
+        )?;
+
+        // Close factor: repay * 100 <= debt * close_factor
+        comparison_chip.gte(
+            layouter.namespace(|| "close factor check"),
+            max_repay_cell,
+            repay_scaled_cell,
+        )?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gadgets::poseidon::compute_commitment;
+    use pasta_curves::Fp;
+    use halo2_proofs::dev::MockProver;
+
+    const ETH: u128 = 1_000_000_000_000_000_000;
+    const USDC: u128 = 1_000_000;
+    const USD: u64 = 100_000_000;
+    const SALTS: [u64; 4] = [11111, 22222, 33333, 44444];
+
+    fn create_settlement_circuit(
+        collateral: u128,
+        debt: u128,
+        price: u64,
+        close_factor: u64,
+        bonus: u64,
+        repay: u128,
+    ) -> (LiquidationSettlementCircuit<Fp>, Vec<Fp>) {
+        let [salt_c, salt_d, new_salt_c, new_salt_d] = SALTS.map(Fp::from);
+        let seized = LiquidationSettlementCircuit::<Fp>::compute_seized(repay, bonus, price).unwrap();
+
+        let circuit = LiquidationSettlementCircuit::new(
+            collateral,
+            debt,
+            salt_c,
+            salt_d,
+            new_salt_c,
+            new_salt_d,
+            price,
+            close_factor,
+            bonus,
+            repay,
+        );
+        let public_inputs = vec![
+            Fp::from(price),
+            Fp::from(close_factor),
+            Fp::from(bonus),
+            Fp::from_u128(repay),
+            Fp::from_u128(seized),
+            compute_commitment(Fp::from_u128(collateral), salt_c),
+            compute_commitment(Fp::from_u128(debt), salt_d),
+            compute_commitment(Fp::from_u128(collateral.wrapping_sub(seized)), new_salt_c),
+            compute_commitment(Fp::from_u128(debt.wrapping_sub(repay)), new_salt_d),
+        ];
+
+        (circuit, public_inputs)
+    }
+
+    #[test]
+    fn test_compute_seized_matches_contract() {
+        // 900 USDC * 105% = 945 USD → 945e6 * 1e20 / 2000e8 = 0.4725 ETH
+        assert_eq!(
+            LiquidationSettlementCircuit::<Fp>::compute_seized(900 * USDC, 5, 2000 * USD),
+            Some(4725 * ETH / 10000)
+        );
+
+        // Rounds down after each division like Solidity
+        let (repay, bonus, price) = (1_234_567u128, 7u64, 1_999_999_999u64);
+        let value_usd = repay * (100 + bonus as u128) / 100;
+        let expected = value_usd * SEIZE_SCALE / price as u128;
+        assert_eq!(
+            LiquidationSettlementCircuit::<Fp>::compute_seized(repay, bonus, price),
+            Some(expected)
+        );
+
+        assert_eq!(LiquidationSettlementCircuit::<Fp>::compute_seized(1, 5, 0), None);
+    }
+
+    #[test]
+    fn test_max_repay() {
+        assert_eq!(LiquidationSettlementCircuit::<Fp>::max_repay(1800 * USDC, 50), Some(900 * USDC));
+        assert_eq!(LiquidationSettlementCircuit::<Fp>::max_repay(199, 50), Some(99));
+        assert_eq!(LiquidationSettlementCircuit::<Fp>::max_repay(u128::MAX, 100), Some(u128::MAX));
+    }
+
+    #[test]
+    fn test_settlement_valid() {
+        let k = 17;
+
+        // Repay half of 1800 USDC with a 5% bonus at $2000/ETH
+        let (circuit, public_inputs) = create_settlement_circuit(ETH, 1800 * USDC, 2000 * USD, 50, 5, 900 * USDC);
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert_eq!(prover.verify(), Ok(()), "Settlement within the close factor should verify");
+    }
+
+    #[test]
+    fn test_settlement_with_rounding() {
+        let k = 17;
+
+        // Both divisions leave a remainder
+        let (circuit, public_inputs) =
+            create_settlement_circuit(ETH, 1800 * USDC, 1_999_999_999, 50, 7, 1_234_567);
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert_eq!(prover.verify(), Ok(()), "Rounded-down seizure should verify");
+    }
+
+    #[test]
+    fn test_settlement_full_close_factor() {
+        let k = 17;
+
+        // close_factor 100% repays the whole debt, as ZKLendingPool.liquidate does
+        let (circuit, public_inputs) = create_settlement_circuit(ETH, 1800 * USDC, 2000 * USD, 100, 5, 1800 * USDC);
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert_eq!(prover.verify(), Ok(()), "Full repayment should verify");
+    }
+
+    #[test]
+    fn test_settlement_exceeds_close_factor() {
+        let k = 17;
+
+        // 901 USDC > 1800 * 50%
+        let (circuit, public_inputs) = create_settlement_circuit(ETH, 1800 * USDC, 2000 * USD, 50, 5, 900 * USDC + 1);
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err(), "Repaying above the close factor should fail");
+    }
+
+    #[test]
+    fn test_settlement_overstated_seizure() {
+        let k = 17;
+
+        // Liquidator claims one more wei of collateral, shifting it out of the remainder
+        let (price, repay) = (1_999_999_999u64, 1_234_567u128);
+        let (mut circuit, mut public_inputs) = create_settlement_circuit(ETH, 1800 * USDC, price, 50, 7, repay);
+        let seized = LiquidationSettlementCircuit::<Fp>::compute_seized(repay, 7, price).unwrap() + 1;
+        circuit.seized = Value::known(Fp::from_u128(seized));
+        circuit.seize_remainder = circuit.seize_remainder.map(|r| r - Fp::from(price));
+        public_inputs[4] = Fp::from_u128(seized);
+        public_inputs[7] =
+            compute_commitment(Fp::from_u128(ETH - seized), Fp::from(SALTS[2]));
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err(), "Seizing more than the bonus allows should fail");
+    }
+
+    #[test]
+    fn test_settlement_seizure_exceeds_collateral() {
+        let k = 17;
+
+        // 0.4725 ETH seized from a 0.4 ETH position
+        let (circuit, public_inputs) =
+            create_settlement_circuit(4 * ETH / 10, 1800 * USDC, 2000 * USD, 50, 5, 900 * USDC);
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err(), "Seizure above the collateral should fail");
+    }
+
+    #[test]
+    fn test_settlement_wrong_new_debt_commitment() {
+        let k = 17;
+
+        // New debt commitment that forgets the repayment
+        let (circuit, mut public_inputs) = create_settlement_circuit(ETH, 1800 * USDC, 2000 * USD, 50, 5, 900 * USDC);
+        public_inputs[8] =
+            compute_commitment(Fp::from_u128(1800 * USDC), Fp::from(SALTS[3]));
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err(), "Stale debt commitment should fail");
+    }
+
+    #[test]
+    fn test_settlement_parameter_bounds() {
+        let k = 17;
+
+        // bonus = 101% is out of range
+        let (circuit, public_inputs) = create_settlement_circuit(ETH, 1800 * USDC, 2000 * USD, 50, 101, 100 * USDC);
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err(), "bonus > 100 must fail");
+
+        // close_factor = 0 allows no repayment at all
+        let (circuit, public_inputs) = create_settlement_circuit(ETH, 1800 * USDC, 2000 * USD, 0, 5, 0);
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err(), "close_factor = 0 must fail");
+    }
+}
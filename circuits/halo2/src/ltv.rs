@@ -6,16 +6,26 @@
 //! Rearranged for integer arithmetic (avoids division in finite fields):
 //! `debt * 100 <= collateral * max_ltv`
 //!
+//! Debt and collateral are compared in the same unit. For collateral and debt
+//! in different tokens (e.g., ETH and USDC), use `PriceLTVCircuit`, which
+//! applies the oracle price and both token decimals.
+//!
 //! # Public Inputs
-//! - `max_ltv`: Maximum allowed LTV ratio (e.g., 80 = 80%)
-//! - `debt_commitment`: Hash(debt, salt_d)
-//! - `collateral_commitment`: Hash(collateral, salt_c)
+//! - `max_ltv`: Maximum allowed LTV ratio (e.g., 80 = 80%), constrained to 0 < max_ltv <= 100
+//! - `debt_commitment`: Poseidon(debt, salt_d)
+//! - `collateral_commitment`: Poseidon(collateral, salt_c)
 //!
 //! # Private Inputs
 //! - `debt`: Borrowed amount
 //! - `collateral`: Collateral amount
 //! - `salt_d`, `salt_c`: Salts for commitments
 //!
+//! # Constraints
+//! 1. `debt_scaled = debt * 100`, `collateral_scaled = collateral * max_ltv`
+//! 2. `collateral_scaled >= debt_scaled`
+//! 3. `1 <= max_ltv <= 100` (a verifier never has to re-check the parameter)
+//! 4. Both commitments == Poseidon(value, salt)
+//!
 //! # Example
 //! - collateral: 100 ETH
 //! - debt: 60 ETH
@@ -23,10 +33,11 @@
 //! - LTV = 60/100 = 60% <= 80% ✓
 //!
 //! # Circuit Statistics
-//! - Advice columns: 8 (debt, collateral, salts, scaled values, commitments)
+//! - Advice columns: 8 (debt, collateral, salt, max_ltv, scaled values, commitment, diff)
 //! - Instance columns: 1 (public inputs)
-//! - Custom gates: 2 (debt scaling, commitments)
+//! - Custom gates: LTV scaling, Poseidon rounds, comparison
 //! - Lookup tables: 1 (range check for comparison)
+//! - Estimated rows: ~2^17 (16-bit lookup table, two Poseidon hashes)
 //! - Constraint optimization: Division transformed to multiplication
 
 use ff::PrimeField;
@@ -35,14 +46,16 @@ use halo2_proofs::{
     plonk::{Advice, Circuit, Column, ConstraintSystem, Error, Instance, Selector},
     poly::Rotation,
 };
-use pasta_curves::Fp;
 use std::marker::PhantomData;
 
+use crate::constants::{LOOKUP_BITS, RANGE_BITS};
 use crate::gadgets::comparison::{ComparisonChip, ComparisonConfig, ComparisonInstruction};
+use crate::gadgets::poseidon::{compute_commitment, PoseidonChip, PoseidonConfig};
 
-/// Note: For production 64-bit range checks, decompose into multiple smaller checks
-/// Using 16 bits for demo/testing (2^16 = 65536 entries fits in lookup table)
-const RANGE_BITS: usize = 16;
+/// Width of the scaled values compared (amount * percentage)
+const SCALED_BITS: usize = RANGE_BITS + LOOKUP_BITS;
+/// Largest valid max_ltv (100%)
+const MAX_LTV_LIMIT: u64 = LTV_PRECISION;
 const LTV_PRECISION: u64 = 100; // LTV in percentage (80 = 80%)
 
 /// Configuration for LTV circuit
@@ -51,24 +64,28 @@ pub struct LTVConfig<F: PrimeField> {
     // Private inputs
     pub debt: Column<Advice>,
     pub collateral: Column<Advice>,
-    pub salt_d: Column<Advice>,
-    pub salt_c: Column<Advice>,
+    /// Salts for both commitments (one per row)
+    pub salt: Column<Advice>,
+
+    // Public parameter (copied from instance)
+    pub max_ltv: Column<Advice>,
 
     // Intermediate values
     pub debt_scaled: Column<Advice>,        // debt * 100
     pub collateral_scaled: Column<Advice>,  // collateral * max_ltv
-    pub debt_commitment: Column<Advice>,
-    pub collateral_commitment: Column<Advice>,
+    /// Poseidon output column (third state column)
+    pub commitment: Column<Advice>,
 
     // Public inputs
     pub instance: Column<Instance>,
 
     // Gates
     pub q_ltv: Selector,
-    pub q_commitment: Selector,
 
-    // Comparison for LTV check
-    pub comparison: ComparisonConfig<F, RANGE_BITS>,
+    /// Poseidon chip config (state = [collateral, salt, commitment])
+    pub poseidon: PoseidonConfig<F>,
+    /// Comparison for LTV check
+    pub comparison: ComparisonConfig<F, LOOKUP_BITS>,
 
     _marker: PhantomData<F>,
 }
@@ -106,98 +123,87 @@ impl<F: PrimeField> LTVCircuit<F> {
         }
     }
 
-    /// Compute commitment for demo/testing
+    /// Compute a debt or collateral commitment off-circuit
     ///
-    /// commitment = value * salt + value
+    /// commitment = Poseidon(value, salt)
     ///
-    /// NOTE: This is a simplified commitment for circuit demonstration.
-    /// For production use, replace with Poseidon hash and implement
-    /// Poseidon verification as circuit constraints.
+    /// Delegates to the shared `compute_commitment`, which matches the in-circuit `PoseidonChip`.
     pub fn compute_commitment(value: F, salt: F) -> F {
-        value * salt + value
+        compute_commitment(value, salt)
     }
 }
 
-impl Circuit<Fp> for LTVCircuit<Fp> {
-    type Config = LTVConfig<Fp>;
+impl<F: PrimeField> Circuit<F> for LTVCircuit<F> {
+    type Config = LTVConfig<F>;
     type FloorPlanner = SimpleFloorPlanner;
 
     fn without_witnesses(&self) -> Self {
         Self::default()
     }
 
-    fn configure(meta: &mut ConstraintSystem<Fp>) -> Self::Config {
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
         // Advice columns
         let debt = meta.advice_column();
         let collateral = meta.advice_column();
-        let salt_d = meta.advice_column();
-        let salt_c = meta.advice_column();
+        let salt = meta.advice_column();
+        let max_ltv = meta.advice_column();
         let debt_scaled = meta.advice_column();
         let collateral_scaled = meta.advice_column();
-        let debt_commitment = meta.advice_column();
-        let collateral_commitment = meta.advice_column();
+        let commitment = meta.advice_column();
 
         // Instance for public inputs
         let instance = meta.instance_column();
 
         // Enable equality
-        for col in [debt, collateral, debt_scaled, collateral_scaled, debt_commitment, collateral_commitment] {
+        for col in [debt, collateral, salt, max_ltv, debt_scaled, collateral_scaled, commitment] {
             meta.enable_equality(col);
         }
         meta.enable_equality(instance);
 
         // Selectors
         let q_ltv = meta.selector();
-        let q_commitment = meta.selector();
 
-        // LTV scaling gate: debt_scaled = debt * 100
-        meta.create_gate("debt scaling", |meta| {
+        // LTV scaling gate: debt_scaled = debt * 100, collateral_scaled = collateral * max_ltv
+        meta.create_gate("ltv scaling", |meta| {
             let q = meta.query_selector(q_ltv);
             let debt = meta.query_advice(debt, Rotation::cur());
             let debt_scaled = meta.query_advice(debt_scaled, Rotation::cur());
-            let precision = halo2_proofs::plonk::Expression::Constant(Fp::from(LTV_PRECISION));
-
-            vec![q * (debt_scaled - debt * precision)]
-        });
-
-        // Commitment gate
-        meta.create_gate("commitments", |meta| {
-            let q = meta.query_selector(q_commitment);
-            let d = meta.query_advice(debt, Rotation::cur());
-            let c = meta.query_advice(collateral, Rotation::cur());
-            let sd = meta.query_advice(salt_d, Rotation::cur());
-            let sc = meta.query_advice(salt_c, Rotation::cur());
-            let dc = meta.query_advice(debt_commitment, Rotation::cur());
-            let cc = meta.query_advice(collateral_commitment, Rotation::cur());
+            let collateral = meta.query_advice(collateral, Rotation::cur());
+            let max_ltv = meta.query_advice(max_ltv, Rotation::cur());
+            let collateral_scaled = meta.query_advice(collateral_scaled, Rotation::cur());
+            let precision = halo2_proofs::plonk::Expression::Constant(F::from(LTV_PRECISION));
 
             vec![
-                q.clone() * (dc - d.clone() * sd - d),
-                q * (cc - c.clone() * sc - c),
+                q.clone() * (debt_scaled - debt * precision),
+                q * (collateral_scaled - collateral * max_ltv),
             ]
         });
 
+        // Poseidon chip for both commitments
+        let poseidon = PoseidonChip::configure(meta, [collateral, salt, commitment]);
+
         // Comparison config for LTV check
         let diff = meta.advice_column();
         meta.enable_equality(diff);
-        let comparison = ComparisonChip::<Fp, RANGE_BITS>::configure(
+        let comparison = ComparisonChip::<F, LOOKUP_BITS>::configure_with_range_bits(
             meta,
             collateral_scaled,  // a = collateral * max_ltv
             debt_scaled,        // b = debt * 100
             diff,
+            SCALED_BITS,
         );
 
         LTVConfig {
             debt,
             collateral,
-            salt_d,
-            salt_c,
+            salt,
+            max_ltv,
             debt_scaled,
             collateral_scaled,
-            debt_commitment,
-            collateral_commitment,
+            commitment,
             instance,
             q_ltv,
-            q_commitment,
+            poseidon,
             comparison,
             _marker: PhantomData,
         }
@@ -206,29 +212,30 @@ impl Circuit<Fp> for LTVCircuit<Fp> {
     fn synthesize(
         &self,
         config: Self::Config,
-        mut layouter: impl Layouter<Fp>,
+        mut layouter: impl Layouter<F>,
     ) -> Result<(), Error> {
         // Load comparison lookup table
-        let comparison_chip = ComparisonChip::<Fp, RANGE_BITS>::construct(config.comparison.clone());
+        let comparison_chip = ComparisonChip::<F, LOOKUP_BITS>::construct(config.comparison.clone());
         comparison_chip.load_table(layouter.namespace(|| "load table"))?;
 
         // Main region: assign values and compute scaled amounts
-        let (debt_scaled_cell, collateral_scaled_cell, debt_comm_cell, coll_comm_cell) =
+        let (debt_cell, collateral_cell, max_ltv_cell, debt_scaled_cell, collateral_scaled_cell) =
             layouter.assign_region(
                 || "LTV computation",
                 |mut region| {
                     // Enable selectors
                     config.q_ltv.enable(&mut region, 0)?;
-                    config.q_commitment.enable(&mut region, 0)?;
 
                     // Assign private inputs
-                    region.assign_advice(|| "debt", config.debt, 0, || self.debt)?;
-                    region.assign_advice(|| "collateral", config.collateral, 0, || self.collateral)?;
-                    region.assign_advice(|| "salt_d", config.salt_d, 0, || self.salt_d)?;
-                    region.assign_advice(|| "salt_c", config.salt_c, 0, || self.salt_c)?;
+                    let debt_cell = region.assign_advice(|| "debt", config.debt, 0, || self.debt)?;
+                    let collateral_cell =
+                        region.assign_advice(|| "collateral", config.collateral, 0, || self.collateral)?;
+
+                    // Assign public parameter (from instance)
+                    let max_ltv_cell = region.assign_advice(|| "max_ltv", config.max_ltv, 0, || self.max_ltv)?;
 
                     // Compute debt_scaled = debt * 100
-                    let debt_scaled_val = self.debt.map(|d| d * Fp::from(LTV_PRECISION));
+                    let debt_scaled_val = self.debt.map(|d| d * F::from(LTV_PRECISION));
                     let debt_scaled_cell = region.assign_advice(
                         || "debt_scaled",
                         config.debt_scaled,
@@ -245,38 +252,63 @@ impl Circuit<Fp> for LTVCircuit<Fp> {
                         || collateral_scaled_val,
                     )?;
 
-                    // Compute commitments
-                    let debt_comm_val = self.debt.zip(self.salt_d).map(|(d, s)| {
-                        Self::compute_commitment(d, s)
-                    });
-                    let debt_comm_cell = region.assign_advice(
-                        || "debt_commitment",
-                        config.debt_commitment,
-                        0,
-                        || debt_comm_val,
-                    )?;
-
-                    let coll_comm_val = self.collateral.zip(self.salt_c).map(|(c, s)| {
-                        Self::compute_commitment(c, s)
-                    });
-                    let coll_comm_cell = region.assign_advice(
-                        || "collateral_commitment",
-                        config.collateral_commitment,
-                        0,
-                        || coll_comm_val,
-                    )?;
-
-                    Ok((debt_scaled_cell, collateral_scaled_cell, debt_comm_cell, coll_comm_cell))
+                    Ok((
+                        debt_cell,
+                        collateral_cell,
+                        max_ltv_cell,
+                        debt_scaled_cell,
+                        collateral_scaled_cell,
+                    ))
                 },
             )?;
 
+        // Salts
+        let (salt_d_cell, salt_c_cell) = layouter.assign_region(
+            || "assign salts",
+            |mut region| {
+                let salt_d = region.assign_advice(|| "salt_d", config.salt, 0, || self.salt_d)?;
+                let salt_c = region.assign_advice(|| "salt_c", config.salt, 1, || self.salt_c)?;
+                Ok((salt_d, salt_c))
+            },
+        )?;
+
+        // Commitments = Poseidon(value, salt)
+        let poseidon_chip = PoseidonChip::construct(config.poseidon.clone());
+        let debt_comm_cell = poseidon_chip.hash(
+            layouter.namespace(|| "debt commitment"),
+            debt_cell.clone(),
+            salt_d_cell,
+        )?;
+        let coll_comm_cell = poseidon_chip.hash(
+            layouter.namespace(|| "collateral commitment"),
+            collateral_cell.clone(),
+            salt_c_cell,
+        )?;
+
         // Constrain public inputs
         // instance[0] = max_ltv
         // instance[1] = debt_commitment
         // instance[2] = collateral_commitment
+        layouter.constrain_instance(max_ltv_cell.cell(), config.instance, 0)?;
         layouter.constrain_instance(debt_comm_cell.cell(), config.instance, 1)?;
         layouter.constrain_instance(coll_comm_cell.cell(), config.instance, 2)?;
 
+        // Public parameter bound: 0 < max_ltv <= 100
+        comparison_chip.in_range(
+            layouter.namespace(|| "max_ltv bounds"),
+            max_ltv_cell,
+            F::ONE,
+            F::from(MAX_LTV_LIMIT),
+        )?;
+
+        // Private amounts must not wrap the field when scaled
+        comparison_chip.range_check(layouter.namespace(|| "debt range"), debt_cell, RANGE_BITS)?;
+        comparison_chip.range_check(
+            layouter.namespace(|| "collateral range"),
+            collateral_cell,
+            RANGE_BITS,
+        )?;
+
         // LTV check: collateral_scaled >= debt_scaled
         // i.e., collateral * max_ltv >= debt * 100
         comparison_chip.gte(
@@ -292,6 +324,7 @@ impl Circuit<Fp> for LTVCircuit<Fp> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use pasta_curves::Fp;
     use halo2_proofs::dev::MockProver;
 
     fn create_ltv_circuit(
@@ -362,6 +395,23 @@ mod tests {
         assert_eq!(prover.verify(), Ok(()), "Zero debt should pass");
     }
 
+    #[test]
+    fn test_ltv_wei_amounts() {
+        let k = 17;
+
+        // 6 ETH debt against 10 ETH collateral (in wei), max_ltv=80%
+        let (circuit, public_inputs) =
+            create_ltv_circuit(6_000_000_000_000_000_000, 10_000_000_000_000_000_000, 80);
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert_eq!(prover.verify(), Ok(()), "Wei-denominated LTV should pass");
+
+        // 8.5 ETH debt exceeds 80% of 10 ETH
+        let (circuit, public_inputs) =
+            create_ltv_circuit(8_500_000_000_000_000_000, 10_000_000_000_000_000_000, 80);
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err(), "Wei-denominated excess LTV should fail");
+    }
+
     #[test]
     fn test_ltv_aave_style() {
         let k = 17;
@@ -374,4 +424,19 @@ mod tests {
         let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
         assert_eq!(prover.verify(), Ok(()), "Aave-style 75% LTV should pass");
     }
+
+    #[test]
+    fn test_ltv_commitments_are_poseidon() {
+        let k = 17;
+
+        let (circuit, public_inputs) = create_ltv_circuit(60, 100, 80);
+        assert_eq!(public_inputs[1], compute_commitment(Fp::from(60u64), Fp::from(11111u64)));
+
+        // The old linear commitment `value * salt + value` no longer opens
+        let (debt, salt_d) = (Fp::from(60u64), Fp::from(11111u64));
+        let mut forged = public_inputs.clone();
+        forged[1] = debt * salt_d + debt;
+        let prover = MockProver::run(k, &circuit, vec![forged]).unwrap();
+        assert!(prover.verify().is_err(), "Linear commitment should be rejected");
+    }
 }
@@ -0,0 +1,839 @@
+//! MultiAssetLTVProof Circuit
+//!
+//! Proves that a position backed by `N` collateral assets covers its debt,
+//! weighting each asset by its oracle price and collateral factor:
+//! `sum(amount_i * price_i * cf_i) >= debt * PRECISION`
+//!
+//! `PRECISION = 100 * 10^8` cancels the percentage collateral factor and the
+//! 8-decimal oracle price. Amounts are committed in each token's native base
+//! units; the circuit normalizes every amount and the debt to `MAX_DECIMALS`
+//! (18) decimals with its public token decimals before comparing:
+//! `sum(amount_i * 10^(18 - decimals_i) * price_i * cf_i) >= debt * 10^(18 - debt_decimals) * PRECISION`
+//!
+//! # Public Inputs
+//! For each asset `i` (four entries, in asset order):
+//! - `collateral_commitment_i`: Poseidon(amount_i, salt_i)
+//! - `price_i`: Oracle price (8 decimals), constrained to 0 < price_i < 2^64
+//! - `cf_i`: Collateral factor (e.g., 75 = 75%), constrained to 0 < cf_i <= 100
+//! - `decimals_i`: Decimals of the asset token (e.g., 18 for ETH, 8 for WBTC)
+//!
+//! Followed by:
+//! - `debt_commitment`: Poseidon(debt, debt_salt)
+//! - `debt_decimals`: Decimals of the debt token (e.g., 6 for USDC)
+//!
+//! # Private Inputs
+//! - `amount_i`, `salt_i`: Collateral amounts (native units) and their salts
+//! - `debt`, `debt_salt`: Borrowed amount (native units) and its salt
+//!
+//! # Constraints
+//! 1. `(18, decimals_i, scale_i)` and `(18, debt_decimals, debt_scale)` are rows
+//!    of `PriceLTVCircuit`'s decimals table, so `decimals <= 18` and
+//!    `scale = 10^(18 - decimals)`
+//! 2. `normalized_i = amount_i * scale_i`, `value_i = normalized_i * price_i * cf_i`
+//! 3. `acc_0 = value_0`, `acc_i = acc_{i-1} + value_i`
+//! 4. `debt_scaled = debt * debt_scale * PRECISION`
+//! 5. `acc_{N-1} >= debt_scaled`
+//! 6. Every commitment == Poseidon(value, salt)
+//! 7. Amounts and debt fit in 128 bits both natively and normalized to 18
+//!    decimals, prices and collateral factors are bounded
+//!
+//! # Example
+//! - 10 ETH (18 decimals) at $2000 (cf 80%) + 1 WBTC (8 decimals) at $40000 (cf 70%)
+//! - weighted collateral = 16000 + 28000 = $44000
+//! - debt 40000 USDC (6 decimals) ✓, debt 45000 USDC ✗
+//!
+//! # Circuit Statistics
+//! - Advice columns: 13 (amount, salt, commitment, price, cf, decimals, scale,
+//!   normalized, value, acc, debt, debt_scaled, diff)
+//! - Instance columns: 1 (public inputs)
+//! - Custom gates: asset value, accumulation, debt scaling, Poseidon rounds, comparison
+//! - Lookup tables: 2 (range check, decimals)
+//! - Estimated rows: ~2^17 (16-bit lookup table, N + 1 Poseidon hashes)
+
+use ff::PrimeField;
+use halo2_proofs::{
+    circuit::{AssignedCell, Layouter, SimpleFloorPlanner, Value},
+    plonk::{
+        Advice, Circuit, Column, ConstraintSystem, Error, Expression, Instance, Selector, TableColumn,
+    },
+    poly::Rotation,
+};
+use std::marker::PhantomData;
+
+use crate::constants::{LOOKUP_BITS, RANGE_BITS, PRICE_BITS, PRICE_PRECISION};
+use crate::gadgets::comparison::{ComparisonChip, ComparisonConfig, ComparisonInstruction};
+use crate::gadgets::poseidon::{PoseidonChip, PoseidonConfig};
+use crate::price_ltv::{decimal_scale_value, load_decimals_table, MAX_DECIMALS};
+
+/// Width of the compared sums; the extra lookup width covers cf <= 100 and
+/// the sum over up to 2^9 assets
+const SCALED_BITS: usize = RANGE_BITS + PRICE_BITS + LOOKUP_BITS;
+/// Collateral factors in percentage (80 = 80%)
+const CF_PRECISION: u64 = 100;
+/// Scale applied to the debt: cancels both the percentage and the price decimals
+pub const PRECISION: u64 = CF_PRECISION * PRICE_PRECISION;
+/// Largest valid collateral factor (100%)
+const MAX_CF: u64 = CF_PRECISION;
+
+/// Configuration for the MultiAssetLTVProof circuit
+#[derive(Debug, Clone)]
+pub struct MultiAssetLTVConfig<F: PrimeField> {
+    // Per-asset rows (row i = asset i; the debt row reuses decimals/scale/normalized)
+    pub amount: Column<Advice>,
+    pub price: Column<Advice>,
+    pub collateral_factor: Column<Advice>,
+    pub decimals: Column<Advice>,
+    pub scale: Column<Advice>,       // 10^(18 - decimals)
+    pub normalized: Column<Advice>,  // amount * scale
+    pub value: Column<Advice>,       // normalized * price * cf
+    pub acc: Column<Advice>,         // running sum of value
+
+    // Debt
+    pub debt: Column<Advice>,
+    pub debt_scaled: Column<Advice>,  // debt * debt_scale * PRECISION
+
+    /// Salts for the asset and debt commitments (one per row)
+    pub salt: Column<Advice>,
+    /// Poseidon output column (third state column)
+    pub commitment: Column<Advice>,
+
+    // Public inputs
+    pub instance: Column<Instance>,
+
+    // Gates (q_asset and q_debt also gate the decimals lookup)
+    pub q_asset: Selector,
+    pub q_first: Selector,
+    pub q_acc: Selector,
+    pub q_debt: Selector,
+
+    /// Decimals table shared with `PriceLTVCircuit`: (cd, dd, 10^(cd - dd))
+    pub decimals_table: [TableColumn; 3],
+
+    /// Poseidon chip config (state = [amount, salt, commitment])
+    pub poseidon: PoseidonConfig<F>,
+    /// Comparison for the coverage check
+    pub comparison: ComparisonConfig<F, LOOKUP_BITS>,
+
+    _marker: PhantomData<F>,
+}
+
+/// MultiAssetLTVProof circuit over `N` collateral assets
+#[derive(Clone)]
+pub struct MultiAssetLTVCircuit<F: PrimeField, const N: usize> {
+    /// Private: collateral amount of each asset (native units)
+    pub amounts: [Value<F>; N],
+    /// Private: salt of each collateral commitment
+    pub salts: [Value<F>; N],
+    /// Public: oracle price of each asset (passed via instance)
+    pub prices: [Value<F>; N],
+    /// Public: collateral factor of each asset (passed via instance)
+    pub collateral_factors: [Value<F>; N],
+    /// Public: token decimals of each asset (passed via instance)
+    pub decimals: [Value<F>; N],
+    /// Private: borrowed amount (native units)
+    pub debt: Value<F>,
+    /// Private: salt of the debt commitment
+    pub debt_salt: Value<F>,
+    /// Public: token decimals of the debt (passed via instance)
+    pub debt_decimals: Value<F>,
+}
+
+impl<F: PrimeField, const N: usize> Default for MultiAssetLTVCircuit<F, N> {
+    fn default() -> Self {
+        Self {
+            amounts: [Value::unknown(); N],
+            salts: [Value::unknown(); N],
+            prices: [Value::unknown(); N],
+            collateral_factors: [Value::unknown(); N],
+            decimals: [Value::unknown(); N],
+            debt: Value::unknown(),
+            debt_salt: Value::unknown(),
+            debt_decimals: Value::unknown(),
+        }
+    }
+}
+
+impl<F: PrimeField, const N: usize> MultiAssetLTVCircuit<F, N> {
+    /// Create a new circuit with the given values
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        amounts: [F; N],
+        salts: [F; N],
+        prices: [F; N],
+        collateral_factors: [F; N],
+        decimals: [u64; N],
+        debt: F,
+        debt_salt: F,
+        debt_decimals: u64,
+    ) -> Self {
+        Self {
+            amounts: amounts.map(Value::known),
+            salts: salts.map(Value::known),
+            prices: prices.map(Value::known),
+            collateral_factors: collateral_factors.map(Value::known),
+            decimals: decimals.map(|d| Value::known(F::from(d))),
+            debt: Value::known(debt),
+            debt_salt: Value::known(debt_salt),
+            debt_decimals: Value::known(F::from(debt_decimals)),
+        }
+    }
+
+    /// Arrange the public inputs in instance order
+    ///
+    /// `[commitment_0, price_0, cf_0, decimals_0, ..., commitment_{N-1}, price_{N-1}, cf_{N-1},
+    /// decimals_{N-1}, debt_commitment, debt_decimals]`
+    pub fn public_inputs(
+        collateral_commitments: [F; N],
+        prices: [F; N],
+        collateral_factors: [F; N],
+        decimals: [u64; N],
+        debt_commitment: F,
+        debt_decimals: u64,
+    ) -> Vec<F> {
+        let mut inputs = Vec::with_capacity(4 * N + 2);
+        for (((commitment, price), cf), decimals) in collateral_commitments
+            .into_iter()
+            .zip(prices)
+            .zip(collateral_factors)
+            .zip(decimals)
+        {
+            inputs.extend([commitment, price, cf, F::from(decimals)]);
+        }
+        inputs.extend([debt_commitment, F::from(debt_decimals)]);
+        inputs
+    }
+}
+
+impl<F: PrimeField, const N: usize> Circuit<F> for MultiAssetLTVCircuit<F, N> {
+    type Config = MultiAssetLTVConfig<F>;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        // Advice columns
+        let amount = meta.advice_column();
+        let price = meta.advice_column();
+        let collateral_factor = meta.advice_column();
+        let decimals = meta.advice_column();
+        let scale = meta.advice_column();
+        let normalized = meta.advice_column();
+        let value = meta.advice_column();
+        let acc = meta.advice_column();
+        let debt = meta.advice_column();
+        let debt_scaled = meta.advice_column();
+        let salt = meta.advice_column();
+        let commitment = meta.advice_column();
+
+        // Instance for public inputs
+        let instance = meta.instance_column();
+
+        // Enable equality
+        for col in [
+            amount,
+            price,
+            collateral_factor,
+            decimals,
+            normalized,
+            value,
+            acc,
+            debt,
+            debt_scaled,
+            salt,
+            commitment,
+        ] {
+            meta.enable_equality(col);
+        }
+        meta.enable_equality(instance);
+
+        // Selectors
+        let q_asset = meta.complex_selector();
+        let q_first = meta.selector();
+        let q_acc = meta.selector();
+        let q_debt = meta.complex_selector();
+        let decimals_table = [
+            meta.lookup_table_column(),
+            meta.lookup_table_column(),
+            meta.lookup_table_column(),
+        ];
+
+        // Asset value gate: normalized = amount * scale, value = normalized * price * cf
+        meta.create_gate("asset value", |meta| {
+            let q = meta.query_selector(q_asset);
+            let amount = meta.query_advice(amount, Rotation::cur());
+            let scale = meta.query_advice(scale, Rotation::cur());
+            let normalized = meta.query_advice(normalized, Rotation::cur());
+            let price = meta.query_advice(price, Rotation::cur());
+            let cf = meta.query_advice(collateral_factor, Rotation::cur());
+            let value = meta.query_advice(value, Rotation::cur());
+
+            vec![
+                q.clone() * (normalized.clone() - amount * scale),
+                q * (value - normalized * price * cf),
+            ]
+        });
+
+        // Accumulation gate: acc starts at the first value and adds one value per row
+        meta.create_gate("asset accumulation", |meta| {
+            let q_first = meta.query_selector(q_first);
+            let q_acc = meta.query_selector(q_acc);
+            let value = meta.query_advice(value, Rotation::cur());
+            let acc = meta.query_advice(acc, Rotation::cur());
+            let acc_prev = meta.query_advice(acc, Rotation::prev());
+
+            vec![
+                q_first * (acc.clone() - value.clone()),
+                q_acc * (acc - acc_prev - value),
+            ]
+        });
+
+        // Debt scaling gate: normalized = debt * scale, debt_scaled = normalized * PRECISION
+        meta.create_gate("debt scaling", |meta| {
+            let q = meta.query_selector(q_debt);
+            let debt = meta.query_advice(debt, Rotation::cur());
+            let scale = meta.query_advice(scale, Rotation::cur());
+            let normalized = meta.query_advice(normalized, Rotation::cur());
+            let debt_scaled = meta.query_advice(debt_scaled, Rotation::cur());
+            let precision = Expression::Constant(F::from(PRECISION));
+
+            vec![
+                q.clone() * (normalized.clone() - debt * scale),
+                q * (debt_scaled - normalized * precision),
+            ]
+        });
+
+        // Decimals lookup: (18, decimals, scale) is a row of the decimals table
+        // With both selectors off the tuple is (0, 0, 1), which is the table's first row
+        meta.lookup("decimal scale", |meta| {
+            let q = meta.query_selector(q_asset) + meta.query_selector(q_debt);
+            let decimals = meta.query_advice(decimals, Rotation::cur());
+            let scale = meta.query_advice(scale, Rotation::cur());
+            let max_decimals = Expression::Constant(F::from(MAX_DECIMALS));
+            let one = Expression::Constant(F::ONE);
+
+            vec![
+                (q.clone() * max_decimals, decimals_table[0]),
+                (q.clone() * decimals, decimals_table[1]),
+                (q.clone() * scale + (one - q), decimals_table[2]),
+            ]
+        });
+
+        // Poseidon chip for all commitments
+        let poseidon = PoseidonChip::configure(meta, [amount, salt, commitment]);
+
+        // Comparison config for the coverage check
+        let diff = meta.advice_column();
+        meta.enable_equality(diff);
+        let comparison = ComparisonChip::<F, LOOKUP_BITS>::configure_with_range_bits(
+            meta,
+            acc,          // a = sum(normalized_i * price_i * cf_i)
+            debt_scaled,  // b = debt * debt_scale * PRECISION
+            diff,
+            SCALED_BITS,
+        );
+
+        MultiAssetLTVConfig {
+            amount,
+            price,
+            collateral_factor,
+            decimals,
+            scale,
+            normalized,
+            value,
+            acc,
+            debt,
+            debt_scaled,
+            salt,
+            commitment,
+            instance,
+            q_asset,
+            q_first,
+            q_acc,
+            q_debt,
+            decimals_table,
+            poseidon,
+            comparison,
+            _marker: PhantomData,
+        }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        if N == 0 {
+            return Err(Error::Synthesis);
+        }
+
+        // Load comparison lookup table
+        let comparison_chip = ComparisonChip::<F, LOOKUP_BITS>::construct(config.comparison.clone());
+        comparison_chip.load_table(layouter.namespace(|| "load table"))?;
+        load_decimals_table(layouter.namespace(|| "decimals table"), config.decimals_table)?;
+
+        // scale = 10^(18 - decimals); decimals above 18 get 0 and fail the lookup
+        let max_decimals = F::from(MAX_DECIMALS);
+        let scale_of = |decimals: Value<F>| decimals.map(|d| decimal_scale_value(max_decimals, d));
+
+        // Asset region: one row per asset, accumulating the weighted values
+        struct AssetCells<F: PrimeField> {
+            amount: AssignedCell<F, F>,
+            price: AssignedCell<F, F>,
+            cf: AssignedCell<F, F>,
+            decimals: AssignedCell<F, F>,
+            normalized: AssignedCell<F, F>,
+        }
+        let (asset_cells, total_cell): (Vec<AssetCells<F>>, _) = layouter.assign_region(
+            || "asset values",
+            |mut region| {
+                let mut cells = Vec::with_capacity(N);
+                let mut acc_val = Value::known(F::ZERO);
+                let mut acc_cell = None;
+
+                for i in 0..N {
+                    config.q_asset.enable(&mut region, i)?;
+                    if i == 0 {
+                        config.q_first.enable(&mut region, i)?;
+                    } else {
+                        config.q_acc.enable(&mut region, i)?;
+                    }
+
+                    let amount =
+                        region.assign_advice(|| format!("amount {}", i), config.amount, i, || self.amounts[i])?;
+                    let price =
+                        region.assign_advice(|| format!("price {}", i), config.price, i, || self.prices[i])?;
+                    let cf = region.assign_advice(
+                        || format!("cf {}", i),
+                        config.collateral_factor,
+                        i,
+                        || self.collateral_factors[i],
+                    )?;
+                    let decimals = region.assign_advice(
+                        || format!("decimals {}", i),
+                        config.decimals,
+                        i,
+                        || self.decimals[i],
+                    )?;
+
+                    // normalized = amount * 10^(18 - decimals)
+                    let scale_val = scale_of(self.decimals[i]);
+                    region.assign_advice(|| format!("scale {}", i), config.scale, i, || scale_val)?;
+                    let normalized_val = self.amounts[i].zip(scale_val).map(|(a, s)| a * s);
+                    let normalized = region.assign_advice(
+                        || format!("normalized {}", i),
+                        config.normalized,
+                        i,
+                        || normalized_val,
+                    )?;
+
+                    // value = normalized * price * cf
+                    let value_val = normalized_val
+                        .zip(self.prices[i])
+                        .zip(self.collateral_factors[i])
+                        .map(|((a, p), cf)| a * p * cf);
+                    region.assign_advice(|| format!("value {}", i), config.value, i, || value_val)?;
+
+                    // acc = acc_prev + value
+                    acc_val = acc_val.zip(value_val).map(|(acc, v)| acc + v);
+                    acc_cell = Some(region.assign_advice(|| format!("acc {}", i), config.acc, i, || acc_val)?);
+
+                    cells.push(AssetCells { amount, price, cf, decimals, normalized });
+                }
+
+                Ok((cells, acc_cell.ok_or(Error::Synthesis)?))
+            },
+        )?;
+
+        // Debt region
+        let (debt_cell, debt_decimals_cell, debt_normalized_cell, debt_scaled_cell) = layouter.assign_region(
+            || "debt scaling",
+            |mut region| {
+                config.q_debt.enable(&mut region, 0)?;
+
+                let debt_cell = region.assign_advice(|| "debt", config.debt, 0, || self.debt)?;
+                let debt_decimals_cell =
+                    region.assign_advice(|| "debt_decimals", config.decimals, 0, || self.debt_decimals)?;
+
+                // normalized = debt * 10^(18 - debt_decimals)
+                let scale_val = scale_of(self.debt_decimals);
+                region.assign_advice(|| "debt_scale", config.scale, 0, || scale_val)?;
+                let normalized_val = self.debt.zip(scale_val).map(|(d, s)| d * s);
+                let debt_normalized_cell = region.assign_advice(
+                    || "debt_normalized",
+                    config.normalized,
+                    0,
+                    || normalized_val,
+                )?;
+
+                // debt_scaled = normalized * PRECISION
+                let debt_scaled_val = normalized_val.map(|d| d * F::from(PRECISION));
+                let debt_scaled_cell = region.assign_advice(
+                    || "debt_scaled",
+                    config.debt_scaled,
+                    0,
+                    || debt_scaled_val,
+                )?;
+
+                Ok((debt_cell, debt_decimals_cell, debt_normalized_cell, debt_scaled_cell))
+            },
+        )?;
+
+        // Salts: rows 0..N for the assets, row N for the debt
+        let (salt_cells, debt_salt_cell) = layouter.assign_region(
+            || "assign salts",
+            |mut region| {
+                let salts = (0..N)
+                    .map(|i| region.assign_advice(|| format!("salt {}", i), config.salt, i, || self.salts[i]))
+                    .collect::<Result<Vec<_>, _>>()?;
+                let debt_salt = region.assign_advice(|| "debt_salt", config.salt, N, || self.debt_salt)?;
+                Ok((salts, debt_salt))
+            },
+        )?;
+
+        // Commitments = Poseidon(value, salt), constrained to the public inputs
+        // instance[4i] = commitment_i, instance[4i + 1] = price_i,
+        // instance[4i + 2] = cf_i, instance[4i + 3] = decimals_i
+        // instance[4N] = debt_commitment, instance[4N + 1] = debt_decimals
+        let poseidon_chip = PoseidonChip::construct(config.poseidon.clone());
+        for (i, (asset, salt_cell)) in asset_cells.iter().zip(salt_cells).enumerate() {
+            let comm_cell = poseidon_chip.hash(
+                layouter.namespace(|| format!("collateral commitment {}", i)),
+                asset.amount.clone(),
+                salt_cell,
+            )?;
+
+            layouter.constrain_instance(comm_cell.cell(), config.instance, 4 * i)?;
+            layouter.constrain_instance(asset.price.cell(), config.instance, 4 * i + 1)?;
+            layouter.constrain_instance(asset.cf.cell(), config.instance, 4 * i + 2)?;
+            layouter.constrain_instance(asset.decimals.cell(), config.instance, 4 * i + 3)?;
+        }
+
+        let debt_comm_cell = poseidon_chip.hash(
+            layouter.namespace(|| "debt commitment"),
+            debt_cell.clone(),
+            debt_salt_cell,
+        )?;
+        layouter.constrain_instance(debt_comm_cell.cell(), config.instance, 4 * N)?;
+        layouter.constrain_instance(debt_decimals_cell.cell(), config.instance, 4 * N + 1)?;
+
+        // Public parameter bounds: 0 < price_i < 2^64, 0 < cf_i <= 100
+        // (decimals are bounded by the decimals table)
+        // Private amounts must not wrap the field when scaled, before or after normalizing
+        for (i, asset) in asset_cells.into_iter().enumerate() {
+            comparison_chip.in_range(
+                layouter.namespace(|| format!("price {} bounds", i)),
+                asset.price,
+                F::ONE,
+                F::from(u64::MAX),
+            )?;
+            comparison_chip.in_range(
+                layouter.namespace(|| format!("cf {} bounds", i)),
+                asset.cf,
+                F::ONE,
+                F::from(MAX_CF),
+            )?;
+            comparison_chip.range_check(
+                layouter.namespace(|| format!("amount {} range", i)),
+                asset.amount,
+                RANGE_BITS,
+            )?;
+            comparison_chip.range_check(
+                layouter.namespace(|| format!("normalized amount {} range", i)),
+                asset.normalized,
+                RANGE_BITS,
+            )?;
+        }
+        comparison_chip.range_check(layouter.namespace(|| "debt range"), debt_cell, RANGE_BITS)?;
+        comparison_chip.range_check(
+            layouter.namespace(|| "normalized debt range"),
+            debt_normalized_cell,
+            RANGE_BITS,
+        )?;
+
+        // Coverage check: sum(normalized_i * price_i * cf_i) >= debt * debt_scale * PRECISION
+        comparison_chip.gte(
+            layouter.namespace(|| "coverage check"),
+            total_cell,
+            debt_scaled_cell,
+        )?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gadgets::poseidon::compute_commitment;
+    use pasta_curves::Fp;
+    use halo2_proofs::dev::MockProver;
+
+    const DEBT_SALT: u64 = 99999;
+    /// $1 with 8 decimals
+    const USD: u64 = PRICE_PRECISION;
+    const ETH: u128 = 1_000_000_000_000_000_000;
+    const WBTC: u128 = 100_000_000;
+    const USDC: u128 = 1_000_000;
+
+    /// Circuit with every asset and the debt in 18 decimals
+    fn create_multi_asset_circuit<const N: usize>(
+        amounts: [u128; N],
+        prices: [u64; N],
+        collateral_factors: [u64; N],
+        debt: u128,
+    ) -> (MultiAssetLTVCircuit<Fp, N>, Vec<Fp>) {
+        create_multi_asset_circuit_with_decimals(amounts, prices, collateral_factors, [18; N], debt, 18)
+    }
+
+    fn create_multi_asset_circuit_with_decimals<const N: usize>(
+        amounts: [u128; N],
+        prices: [u64; N],
+        collateral_factors: [u64; N],
+        decimals: [u64; N],
+        debt: u128,
+        debt_decimals: u64,
+    ) -> (MultiAssetLTVCircuit<Fp, N>, Vec<Fp>) {
+        let amounts_fp = amounts.map(Fp::from_u128);
+        let prices_fp = prices.map(Fp::from);
+        let cfs_fp = collateral_factors.map(Fp::from);
+        let salts: [Fp; N] = std::array::from_fn(|i| Fp::from(11111 * (i as u64 + 1)));
+        let debt_fp = Fp::from_u128(debt);
+        let debt_salt = Fp::from(DEBT_SALT);
+
+        let commitments: [Fp; N] = std::array::from_fn(|i| {
+            compute_commitment(amounts_fp[i], salts[i])
+        });
+        let debt_commitment = compute_commitment(debt_fp, debt_salt);
+
+        let circuit = MultiAssetLTVCircuit::new(
+            amounts_fp,
+            salts,
+            prices_fp,
+            cfs_fp,
+            decimals,
+            debt_fp,
+            debt_salt,
+            debt_decimals,
+        );
+        let public_inputs = MultiAssetLTVCircuit::<Fp, N>::public_inputs(
+            commitments,
+            prices_fp,
+            cfs_fp,
+            decimals,
+            debt_commitment,
+            debt_decimals,
+        );
+
+        (circuit, public_inputs)
+    }
+
+    #[test]
+    fn test_multi_asset_valid() {
+        let k = 17;
+
+        // 10 ETH at $2000 (cf 80%) + 1 BTC at $40000 (cf 70%) = $44000 weighted
+        let (circuit, public_inputs) = create_multi_asset_circuit(
+            [10 * ETH, ETH],
+            [2000 * USD, 40000 * USD],
+            [80, 70],
+            40000 * ETH,
+        );
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert_eq!(prover.verify(), Ok(()), "Covered debt should pass");
+    }
+
+    #[test]
+    fn test_multi_asset_at_limit() {
+        let k = 17;
+
+        let (circuit, public_inputs) = create_multi_asset_circuit(
+            [10 * ETH, ETH],
+            [2000 * USD, 40000 * USD],
+            [80, 70],
+            44000 * ETH,
+        );
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert_eq!(prover.verify(), Ok(()), "Exactly at limit should pass");
+    }
+
+    #[test]
+    fn test_multi_asset_undercollateralized() {
+        let k = 17;
+
+        let (circuit, public_inputs) = create_multi_asset_circuit(
+            [10 * ETH, ETH],
+            [2000 * USD, 40000 * USD],
+            [80, 70],
+            44000 * ETH + 1,
+        );
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err(), "Debt above weighted collateral should fail");
+    }
+
+    #[test]
+    fn test_multi_asset_single_asset() {
+        let k = 17;
+
+        // Matches LTVCircuit with the price folded in: 100 * $1 * 80% >= 80
+        let (circuit, public_inputs) = create_multi_asset_circuit([100], [USD], [80], 80);
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert_eq!(prover.verify(), Ok(()), "Single asset should pass");
+
+        let (circuit, public_inputs) = create_multi_asset_circuit([100], [USD], [80], 81);
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err(), "Single asset above limit should fail");
+    }
+
+    #[test]
+    fn test_multi_asset_wrong_price() {
+        let k = 17;
+
+        // Proof made with a $40000 BTC price cannot be replayed against $30000
+        let (circuit, mut public_inputs) = create_multi_asset_circuit(
+            [10 * ETH, ETH],
+            [2000 * USD, 40000 * USD],
+            [80, 70],
+            40000 * ETH,
+        );
+        public_inputs[5] = Fp::from(30000 * USD);
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err(), "Mismatched public price should fail");
+    }
+
+    #[test]
+    fn test_multi_asset_wrong_commitment() {
+        let k = 17;
+
+        let (circuit, mut public_inputs) = create_multi_asset_circuit(
+            [10 * ETH, ETH],
+            [2000 * USD, 40000 * USD],
+            [80, 70],
+            40000 * ETH,
+        );
+        public_inputs[0] = compute_commitment(Fp::from_u128(20 * ETH), Fp::from(11111u64));
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err(), "Wrong collateral commitment should fail");
+    }
+
+    #[test]
+    fn test_multi_asset_cf_bounds() {
+        let k = 17;
+
+        // Zero debt passes the coverage check for any factor; the bound must still hold
+        let (circuit, public_inputs) =
+            create_multi_asset_circuit([100, 100], [USD, USD], [80, 101], 0);
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err(), "cf > 100 should fail");
+
+        let (circuit, public_inputs) =
+            create_multi_asset_circuit([100, 100], [USD, USD], [0, 80], 0);
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err(), "cf = 0 should fail");
+    }
+
+    #[test]
+    fn test_multi_asset_zero_price() {
+        let k = 17;
+
+        let (circuit, public_inputs) =
+            create_multi_asset_circuit([100, 100], [USD, 0], [80, 80], 0);
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err(), "Zero price should fail");
+    }
+
+    #[test]
+    fn test_multi_asset_three_assets() {
+        let k = 17;
+
+        // 5 ETH * $2000 * 80% + 1000 USDC * $1 * 90% + 2 BTC * $40000 * 70%
+        // = 8000 + 900 + 56000 = $64900
+        let amounts = [5 * ETH, 1000 * ETH, 2 * ETH];
+        let prices = [2000 * USD, USD, 40000 * USD];
+        let cfs = [80, 90, 70];
+
+        let (circuit, public_inputs) = create_multi_asset_circuit(amounts, prices, cfs, 64900 * ETH);
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert_eq!(prover.verify(), Ok(()), "Three assets at limit should pass");
+
+        let (circuit, public_inputs) = create_multi_asset_circuit(amounts, prices, cfs, 65000 * ETH);
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err(), "Three assets above limit should fail");
+    }
+
+    #[test]
+    fn test_multi_asset_native_decimals() {
+        let k = 17;
+
+        // 10 ETH (18 decimals) at $2000 (cf 80%) + 1 WBTC (8 decimals) at $40000 (cf 70%)
+        // = $44000 weighted against USDC debt (6 decimals)
+        let create = |debt| {
+            create_multi_asset_circuit_with_decimals(
+                [10 * ETH, WBTC],
+                [2000 * USD, 40000 * USD],
+                [80, 70],
+                [18, 8],
+                debt,
+                6,
+            )
+        };
+
+        let (circuit, public_inputs) = create(44000 * USDC);
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert_eq!(prover.verify(), Ok(()), "Native-unit amounts at limit should pass");
+
+        let (circuit, public_inputs) = create(44000 * USDC + 1);
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err(), "Native-unit debt above limit should fail");
+    }
+
+    #[test]
+    fn test_multi_asset_wrong_public_decimals() {
+        let k = 17;
+
+        // Claiming WBTC has 18 decimals would shrink it to 10^-10 BTC; claiming
+        // 6 would inflate it 100x. Either mismatch with the witness fails.
+        let (circuit, public_inputs) = create_multi_asset_circuit_with_decimals(
+            [10 * ETH, WBTC],
+            [2000 * USD, 40000 * USD],
+            [80, 70],
+            [18, 8],
+            40000 * USDC,
+            6,
+        );
+
+        let mut wrong_asset = public_inputs.clone();
+        wrong_asset[7] = Fp::from(6u64);
+        let prover = MockProver::run(k, &circuit, vec![wrong_asset]).unwrap();
+        assert!(prover.verify().is_err(), "Mismatched asset decimals should fail");
+
+        let mut wrong_debt = public_inputs;
+        wrong_debt[9] = Fp::from(18u64);
+        let prover = MockProver::run(k, &circuit, vec![wrong_debt]).unwrap();
+        assert!(prover.verify().is_err(), "Mismatched debt decimals should fail");
+    }
+
+    #[test]
+    fn test_multi_asset_decimals_out_of_table() {
+        let k = 17;
+
+        // 19 decimals is not in the decimals table, whatever scale the prover uses
+        let (circuit, public_inputs) = create_multi_asset_circuit_with_decimals(
+            [100, 100],
+            [USD, USD],
+            [80, 80],
+            [18, 19],
+            0,
+            18,
+        );
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err(), "Decimals above 18 should fail");
+    }
+}
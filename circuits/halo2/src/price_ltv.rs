@@ -0,0 +1,677 @@
+//! PriceLTVProof Circuit
+//!
+//! Proves that the USD Loan-to-Value ratio is within bounds when collateral
+//! and debt are different tokens (e.g., ETH collateral in wei, USDC debt
+//! with 6 decimals):
+//! `debt_usd / collateral_usd <= max_ltv / 100`
+//!
+//! With `collateral_usd = collateral * price / 10^(collateral_decimals + 8)`
+//! and `debt_usd = debt / 10^debt_decimals` (the debt token is USD-pegged),
+//! this rearranges to the division-free check:
+//! `debt * 100 * 10^8 * 10^(collateral_decimals - debt_decimals) <= collateral * price * max_ltv`
+//!
+//! # Public Inputs
+//! - `max_ltv`: Maximum allowed LTV ratio (e.g., 80 = 80%), constrained to 0 < max_ltv <= 100
+//! - `price`: Collateral price in USD from the oracle (8 decimals), constrained to 0 < price < 2^64
+//! - `collateral_decimals`: Decimals of the collateral token (e.g., 18 for ETH)
+//! - `debt_decimals`: Decimals of the debt token (e.g., 6 for USDC)
+//! - `debt_commitment`: Poseidon(debt, salt_d)
+//! - `collateral_commitment`: Poseidon(collateral, salt_c)
+//!
+//! # Private Inputs
+//! - `debt`: Borrowed amount (debt token base units)
+//! - `collateral`: Collateral amount (collateral token base units)
+//! - `salt_d`, `salt_c`: Salts for commitments
+//!
+//! # Constraints
+//! 1. `(collateral_decimals, debt_decimals, decimal_scale)` is a row of the
+//!    decimals table: `debt_decimals <= collateral_decimals <= 18` and
+//!    `decimal_scale = 10^(collateral_decimals - debt_decimals)`
+//! 2. `debt_scaled = debt * decimal_scale * 10^10`, `collateral_scaled = collateral * price * max_ltv`
+//! 3. `collateral_scaled >= debt_scaled`
+//! 4. `1 <= max_ltv <= 100`, `1 <= price < 2^64`, amounts fit in 128 bits
+//! 5. Both commitments == Poseidon(value, salt)
+//!
+//! # Example
+//! - collateral: 10 ETH (10^19 wei) at $2000 → $20000
+//! - debt: 15000 USDC (1.5 * 10^10 units), max_ltv: 80%
+//! - LTV = 75% <= 80% ✓
+//!
+//! # Circuit Statistics
+//! - Advice columns: 12 (amounts, salt, price, decimals, scale, max_ltv, scaled values, commitment, diff)
+//! - Instance columns: 1 (public inputs)
+//! - Custom gates: LTV scaling, Poseidon rounds, comparison
+//! - Lookup tables: 2 (range check, decimals)
+//! - Estimated rows: ~2^17 (16-bit lookup table, two Poseidon hashes)
+
+use ff::PrimeField;
+use halo2_proofs::{
+    circuit::{Layouter, SimpleFloorPlanner, Value},
+    plonk::{
+        Advice, Circuit, Column, ConstraintSystem, Error, Expression, Instance, Selector, TableColumn,
+    },
+    poly::Rotation,
+};
+use std::marker::PhantomData;
+
+use crate::constants::{LOOKUP_BITS, RANGE_BITS, PRICE_BITS, PRICE_PRECISION};
+use crate::gadgets::comparison::{ComparisonChip, ComparisonConfig, ComparisonInstruction};
+use crate::gadgets::poseidon::{PoseidonChip, PoseidonConfig};
+
+/// Width of the compared values: `debt * 10^10 * 10^18` needs 222 bits,
+/// `collateral * price * max_ltv` needs 199
+const SCALED_BITS: usize = RANGE_BITS + PRICE_BITS + 2 * LOOKUP_BITS;
+const LTV_PRECISION: u64 = 100; // LTV in percentage (80 = 80%)
+/// Largest valid max_ltv (100%)
+const MAX_LTV_LIMIT: u64 = LTV_PRECISION;
+/// Largest supported token decimals
+pub const MAX_DECIMALS: u64 = 18;
+
+/// Configuration for the PriceLTVProof circuit
+#[derive(Debug, Clone)]
+pub struct PriceLTVConfig<F: PrimeField> {
+    // Private inputs
+    pub debt: Column<Advice>,
+    pub collateral: Column<Advice>,
+    /// Salts for both commitments (one per row)
+    pub salt: Column<Advice>,
+
+    // Public parameters (copied from instance)
+    pub max_ltv: Column<Advice>,
+    pub price: Column<Advice>,
+    pub collateral_decimals: Column<Advice>,
+    pub debt_decimals: Column<Advice>,
+
+    // Intermediate values
+    pub decimal_scale: Column<Advice>,      // 10^(collateral_decimals - debt_decimals)
+    pub debt_scaled: Column<Advice>,        // debt * decimal_scale * 10^10
+    pub collateral_scaled: Column<Advice>,  // collateral * price * max_ltv
+    /// Poseidon output column (third state column)
+    pub commitment: Column<Advice>,
+
+    // Public inputs
+    pub instance: Column<Instance>,
+
+    // Gates
+    pub q_ltv: Selector,
+
+    /// Decimals table: (collateral_decimals, debt_decimals, 10^difference)
+    pub decimals_table: [TableColumn; 3],
+
+    /// Poseidon chip config (state = [collateral, salt, commitment])
+    pub poseidon: PoseidonConfig<F>,
+    /// Comparison for the LTV check
+    pub comparison: ComparisonConfig<F, LOOKUP_BITS>,
+
+    _marker: PhantomData<F>,
+}
+
+/// PriceLTVProof circuit
+#[derive(Clone)]
+pub struct PriceLTVCircuit<F: PrimeField> {
+    pub debt: Value<F>,
+    pub collateral: Value<F>,
+    pub salt_d: Value<F>,
+    pub salt_c: Value<F>,
+    pub max_ltv: Value<F>,
+    pub price: Value<F>,
+    pub collateral_decimals: Value<F>,
+    pub debt_decimals: Value<F>,
+}
+
+impl<F: PrimeField> Default for PriceLTVCircuit<F> {
+    fn default() -> Self {
+        Self {
+            debt: Value::unknown(),
+            collateral: Value::unknown(),
+            salt_d: Value::unknown(),
+            salt_c: Value::unknown(),
+            max_ltv: Value::unknown(),
+            price: Value::unknown(),
+            collateral_decimals: Value::unknown(),
+            debt_decimals: Value::unknown(),
+        }
+    }
+}
+
+impl<F: PrimeField> PriceLTVCircuit<F> {
+    /// Create a new circuit with the given values
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        debt: F,
+        collateral: F,
+        salt_d: F,
+        salt_c: F,
+        max_ltv: F,
+        price: F,
+        collateral_decimals: u64,
+        debt_decimals: u64,
+    ) -> Self {
+        Self {
+            debt: Value::known(debt),
+            collateral: Value::known(collateral),
+            salt_d: Value::known(salt_d),
+            salt_c: Value::known(salt_c),
+            max_ltv: Value::known(max_ltv),
+            price: Value::known(price),
+            collateral_decimals: Value::known(F::from(collateral_decimals)),
+            debt_decimals: Value::known(F::from(debt_decimals)),
+        }
+    }
+
+    /// Decimal scale 10^(collateral_decimals - debt_decimals)
+    ///
+    /// Returns `None` outside `debt_decimals <= collateral_decimals <= MAX_DECIMALS`,
+    /// the pairs the circuit accepts.
+    pub fn decimal_scale(collateral_decimals: u64, debt_decimals: u64) -> Option<u64> {
+        if collateral_decimals > MAX_DECIMALS || debt_decimals > collateral_decimals {
+            return None;
+        }
+        Some(10u64.pow((collateral_decimals - debt_decimals) as u32))
+    }
+}
+
+/// Load the decimals table: every `(cd, dd, 10^(cd - dd))` with
+/// `dd <= cd <= MAX_DECIMALS`, starting at `(0, 0, 1)` so a disabled lookup matches
+pub(crate) fn load_decimals_table<F: PrimeField>(
+    mut layouter: impl Layouter<F>,
+    decimals_table: [TableColumn; 3],
+) -> Result<(), Error> {
+    layouter.assign_table(
+        || "decimals table",
+        |mut table| {
+            let mut row = 0;
+            for cd in 0..=MAX_DECIMALS {
+                for dd in 0..=cd {
+                    let scale = 10u64.pow((cd - dd) as u32);
+                    table.assign_cell(|| "cd", decimals_table[0], row, || Value::known(F::from(cd)))?;
+                    table.assign_cell(|| "dd", decimals_table[1], row, || Value::known(F::from(dd)))?;
+                    table.assign_cell(|| "scale", decimals_table[2], row, || Value::known(F::from(scale)))?;
+                    row += 1;
+                }
+            }
+            Ok(())
+        },
+    )
+}
+
+/// Witness for the table's scale column: `10^(cd - dd)`, or 0 (which fails
+/// the lookup) when `(cd, dd)` is not a table row
+pub(crate) fn decimal_scale_value<F: PrimeField>(cd: F, dd: F) -> F {
+    let to_decimals = |f: F| (0..=MAX_DECIMALS).find(|d| F::from(*d) == f);
+    to_decimals(cd)
+        .zip(to_decimals(dd))
+        .and_then(|(cd, dd)| PriceLTVCircuit::<F>::decimal_scale(cd, dd))
+        .map(F::from)
+        .unwrap_or(F::ZERO)
+}
+
+impl<F: PrimeField> Circuit<F> for PriceLTVCircuit<F> {
+    type Config = PriceLTVConfig<F>;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        // Advice columns
+        let debt = meta.advice_column();
+        let collateral = meta.advice_column();
+        let salt = meta.advice_column();
+        let max_ltv = meta.advice_column();
+        let price = meta.advice_column();
+        let collateral_decimals = meta.advice_column();
+        let debt_decimals = meta.advice_column();
+        let decimal_scale = meta.advice_column();
+        let debt_scaled = meta.advice_column();
+        let collateral_scaled = meta.advice_column();
+        let commitment = meta.advice_column();
+
+        // Instance for public inputs
+        let instance = meta.instance_column();
+
+        // Enable equality
+        for col in [
+            debt,
+            collateral,
+            salt,
+            max_ltv,
+            price,
+            collateral_decimals,
+            debt_decimals,
+            debt_scaled,
+            collateral_scaled,
+            commitment,
+        ] {
+            meta.enable_equality(col);
+        }
+        meta.enable_equality(instance);
+
+        // Selectors (q_ltv also gates the decimals lookup)
+        let q_ltv = meta.complex_selector();
+        let decimals_table = [
+            meta.lookup_table_column(),
+            meta.lookup_table_column(),
+            meta.lookup_table_column(),
+        ];
+
+        // LTV scaling gate:
+        //   debt_scaled = debt * decimal_scale * 100 * 10^8
+        //   collateral_scaled = collateral * price * max_ltv
+        meta.create_gate("price ltv scaling", |meta| {
+            let q = meta.query_selector(q_ltv);
+            let debt = meta.query_advice(debt, Rotation::cur());
+            let collateral = meta.query_advice(collateral, Rotation::cur());
+            let price = meta.query_advice(price, Rotation::cur());
+            let max_ltv = meta.query_advice(max_ltv, Rotation::cur());
+            let scale = meta.query_advice(decimal_scale, Rotation::cur());
+            let debt_scaled = meta.query_advice(debt_scaled, Rotation::cur());
+            let collateral_scaled = meta.query_advice(collateral_scaled, Rotation::cur());
+            let precision = Expression::Constant(F::from(LTV_PRECISION * PRICE_PRECISION));
+
+            vec![
+                q.clone() * (debt_scaled - debt * scale * precision),
+                q * (collateral_scaled - collateral * price * max_ltv),
+            ]
+        });
+
+        // Decimals lookup: (collateral_decimals, debt_decimals, decimal_scale) is a table row
+        // With q_ltv off the tuple is (0, 0, 1), which is the table's first row
+        meta.lookup("decimal scale", |meta| {
+            let q = meta.query_selector(q_ltv);
+            let cd = meta.query_advice(collateral_decimals, Rotation::cur());
+            let dd = meta.query_advice(debt_decimals, Rotation::cur());
+            let scale = meta.query_advice(decimal_scale, Rotation::cur());
+            let one = Expression::Constant(F::ONE);
+
+            vec![
+                (q.clone() * cd, decimals_table[0]),
+                (q.clone() * dd, decimals_table[1]),
+                (q.clone() * scale + (one - q), decimals_table[2]),
+            ]
+        });
+
+        // Poseidon chip for both commitments
+        let poseidon = PoseidonChip::configure(meta, [collateral, salt, commitment]);
+
+        // Comparison config for the LTV check
+        let diff = meta.advice_column();
+        meta.enable_equality(diff);
+        let comparison = ComparisonChip::<F, LOOKUP_BITS>::configure_with_range_bits(
+            meta,
+            collateral_scaled,  // a = collateral * price * max_ltv
+            debt_scaled,        // b = debt * decimal_scale * 10^10
+            diff,
+            SCALED_BITS,
+        );
+
+        PriceLTVConfig {
+            debt,
+            collateral,
+            salt,
+            max_ltv,
+            price,
+            collateral_decimals,
+            debt_decimals,
+            decimal_scale,
+            debt_scaled,
+            collateral_scaled,
+            commitment,
+            instance,
+            q_ltv,
+            decimals_table,
+            poseidon,
+            comparison,
+            _marker: PhantomData,
+        }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        // Load comparison lookup table
+        let comparison_chip = ComparisonChip::<F, LOOKUP_BITS>::construct(config.comparison.clone());
+        comparison_chip.load_table(layouter.namespace(|| "load table"))?;
+
+        load_decimals_table(layouter.namespace(|| "decimals table"), config.decimals_table)?;
+
+        // Main region: assign values and compute scaled amounts
+        let (
+            debt_cell,
+            collateral_cell,
+            max_ltv_cell,
+            price_cell,
+            cd_cell,
+            dd_cell,
+            debt_scaled_cell,
+            collateral_scaled_cell,
+        ) = layouter.assign_region(
+            || "price LTV computation",
+            |mut region| {
+                config.q_ltv.enable(&mut region, 0)?;
+
+                // Assign private inputs
+                let debt_cell = region.assign_advice(|| "debt", config.debt, 0, || self.debt)?;
+                let collateral_cell =
+                    region.assign_advice(|| "collateral", config.collateral, 0, || self.collateral)?;
+
+                // Assign public parameters (from instance)
+                let max_ltv_cell = region.assign_advice(|| "max_ltv", config.max_ltv, 0, || self.max_ltv)?;
+                let price_cell = region.assign_advice(|| "price", config.price, 0, || self.price)?;
+                let cd_cell = region.assign_advice(
+                    || "collateral_decimals",
+                    config.collateral_decimals,
+                    0,
+                    || self.collateral_decimals,
+                )?;
+                let dd_cell = region.assign_advice(
+                    || "debt_decimals",
+                    config.debt_decimals,
+                    0,
+                    || self.debt_decimals,
+                )?;
+
+                // decimal_scale = 10^(cd - dd); out-of-table pairs get 0 and fail the lookup
+                let scale_val = self
+                    .collateral_decimals
+                    .zip(self.debt_decimals)
+                    .map(|(cd, dd)| decimal_scale_value(cd, dd));
+                region.assign_advice(|| "decimal_scale", config.decimal_scale, 0, || scale_val)?;
+
+                // debt_scaled = debt * decimal_scale * 10^10
+                let debt_scaled_val = self
+                    .debt
+                    .zip(scale_val)
+                    .map(|(d, s)| d * s * F::from(LTV_PRECISION * PRICE_PRECISION));
+                let debt_scaled_cell = region.assign_advice(
+                    || "debt_scaled",
+                    config.debt_scaled,
+                    0,
+                    || debt_scaled_val,
+                )?;
+
+                // collateral_scaled = collateral * price * max_ltv
+                let collateral_scaled_val = self
+                    .collateral
+                    .zip(self.price)
+                    .zip(self.max_ltv)
+                    .map(|((c, p), ltv)| c * p * ltv);
+                let collateral_scaled_cell = region.assign_advice(
+                    || "collateral_scaled",
+                    config.collateral_scaled,
+                    0,
+                    || collateral_scaled_val,
+                )?;
+
+                Ok((
+                    debt_cell,
+                    collateral_cell,
+                    max_ltv_cell,
+                    price_cell,
+                    cd_cell,
+                    dd_cell,
+                    debt_scaled_cell,
+                    collateral_scaled_cell,
+                ))
+            },
+        )?;
+
+        // Salts
+        let (salt_d_cell, salt_c_cell) = layouter.assign_region(
+            || "assign salts",
+            |mut region| {
+                let salt_d = region.assign_advice(|| "salt_d", config.salt, 0, || self.salt_d)?;
+                let salt_c = region.assign_advice(|| "salt_c", config.salt, 1, || self.salt_c)?;
+                Ok((salt_d, salt_c))
+            },
+        )?;
+
+        // Commitments = Poseidon(value, salt)
+        let poseidon_chip = PoseidonChip::construct(config.poseidon.clone());
+        let debt_comm_cell = poseidon_chip.hash(
+            layouter.namespace(|| "debt commitment"),
+            debt_cell.clone(),
+            salt_d_cell,
+        )?;
+        let coll_comm_cell = poseidon_chip.hash(
+            layouter.namespace(|| "collateral commitment"),
+            collateral_cell.clone(),
+            salt_c_cell,
+        )?;
+
+        // Constrain public inputs
+        // instance[0] = max_ltv
+        // instance[1] = price
+        // instance[2] = collateral_decimals
+        // instance[3] = debt_decimals
+        // instance[4] = debt_commitment
+        // instance[5] = collateral_commitment
+        layouter.constrain_instance(max_ltv_cell.cell(), config.instance, 0)?;
+        layouter.constrain_instance(price_cell.cell(), config.instance, 1)?;
+        layouter.constrain_instance(cd_cell.cell(), config.instance, 2)?;
+        layouter.constrain_instance(dd_cell.cell(), config.instance, 3)?;
+        layouter.constrain_instance(debt_comm_cell.cell(), config.instance, 4)?;
+        layouter.constrain_instance(coll_comm_cell.cell(), config.instance, 5)?;
+
+        // Public parameter bounds: 0 < max_ltv <= 100, 0 < price < 2^64
+        // (decimals are bounded by the decimals table)
+        comparison_chip.in_range(
+            layouter.namespace(|| "max_ltv bounds"),
+            max_ltv_cell,
+            F::ONE,
+            F::from(MAX_LTV_LIMIT),
+        )?;
+        comparison_chip.in_range(
+            layouter.namespace(|| "price bounds"),
+            price_cell,
+            F::ONE,
+            F::from(u64::MAX),
+        )?;
+
+        // Private amounts must not wrap the field when scaled
+        comparison_chip.range_check(layouter.namespace(|| "debt range"), debt_cell, RANGE_BITS)?;
+        comparison_chip.range_check(
+            layouter.namespace(|| "collateral range"),
+            collateral_cell,
+            RANGE_BITS,
+        )?;
+
+        // LTV check: collateral * price * max_ltv >= debt * decimal_scale * 10^10
+        comparison_chip.gte(
+            layouter.namespace(|| "LTV check"),
+            collateral_scaled_cell,
+            debt_scaled_cell,
+        )?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gadgets::poseidon::compute_commitment;
+    use pasta_curves::Fp;
+    use halo2_proofs::dev::MockProver;
+
+    const SALT_D: u64 = 11111;
+    const SALT_C: u64 = 22222;
+    /// $1 with 8 decimals
+    const USD: u64 = PRICE_PRECISION;
+    const ETH: u128 = 1_000_000_000_000_000_000;
+    const USDC: u128 = 1_000_000;
+
+    fn create_price_ltv_circuit(
+        debt: u128,
+        collateral: u128,
+        max_ltv: u64,
+        price: u64,
+        collateral_decimals: u64,
+        debt_decimals: u64,
+    ) -> (PriceLTVCircuit<Fp>, Vec<Fp>) {
+        let debt_fp = Fp::from_u128(debt);
+        let collateral_fp = Fp::from_u128(collateral);
+        let max_ltv_fp = Fp::from(max_ltv);
+        let price_fp = Fp::from(price);
+        let salt_d = Fp::from(SALT_D);
+        let salt_c = Fp::from(SALT_C);
+
+        let debt_commitment = compute_commitment(debt_fp, salt_d);
+        let collateral_commitment = compute_commitment(collateral_fp, salt_c);
+
+        let circuit = PriceLTVCircuit::new(
+            debt_fp,
+            collateral_fp,
+            salt_d,
+            salt_c,
+            max_ltv_fp,
+            price_fp,
+            collateral_decimals,
+            debt_decimals,
+        );
+        let public_inputs = vec![
+            max_ltv_fp,
+            price_fp,
+            Fp::from(collateral_decimals),
+            Fp::from(debt_decimals),
+            debt_commitment,
+            collateral_commitment,
+        ];
+
+        (circuit, public_inputs)
+    }
+
+    #[test]
+    fn test_price_ltv_eth_usdc() {
+        let k = 17;
+
+        // 10 ETH at $2000 = $20000, 15000 USDC debt → 75% <= 80% ✓
+        let (circuit, public_inputs) =
+            create_price_ltv_circuit(15000 * USDC, 10 * ETH, 80, 2000 * USD, 18, 6);
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert_eq!(prover.verify(), Ok(()), "75% USD LTV should pass");
+    }
+
+    #[test]
+    fn test_price_ltv_at_limit() {
+        let k = 17;
+
+        // 16000 USDC against $20000 → exactly 80%
+        let (circuit, public_inputs) =
+            create_price_ltv_circuit(16000 * USDC, 10 * ETH, 80, 2000 * USD, 18, 6);
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert_eq!(prover.verify(), Ok(()), "Exactly at limit should pass");
+
+        // One USDC unit more exceeds it
+        let (circuit, public_inputs) =
+            create_price_ltv_circuit(16000 * USDC + 1, 10 * ETH, 80, 2000 * USD, 18, 6);
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err(), "Just above limit should fail");
+    }
+
+    #[test]
+    fn test_price_ltv_price_drop() {
+        let k = 17;
+
+        // Same position at $1800: 15000 / 18000 ≈ 83% > 80% ✗
+        let (circuit, public_inputs) =
+            create_price_ltv_circuit(15000 * USDC, 10 * ETH, 80, 1800 * USD, 18, 6);
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err(), "Price drop should break the LTV");
+    }
+
+    #[test]
+    fn test_price_ltv_unit_blind_amounts_rejected() {
+        let k = 17;
+
+        // 10^17 USDC units against 1 ETH: 10% on raw units (the old LTVCircuit check),
+        // but $10^11 of debt against $2000 of collateral
+        let (circuit, public_inputs) =
+            create_price_ltv_circuit(ETH / 10, ETH, 80, 2000 * USD, 18, 6);
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err(), "Raw-unit LTV must not pass as USD LTV");
+    }
+
+    #[test]
+    fn test_price_ltv_wbtc() {
+        let k = 17;
+
+        // 1 WBTC (8 decimals) at $40000, 28000 USDC → 70% <= 75% ✓
+        let (circuit, public_inputs) =
+            create_price_ltv_circuit(28000 * USDC, 100_000_000, 75, 40000 * USD, 8, 6);
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert_eq!(prover.verify(), Ok(()), "WBTC collateral should pass");
+    }
+
+    #[test]
+    fn test_price_ltv_wrong_public_decimals() {
+        let k = 17;
+
+        // Claiming 8 collateral decimals divides the collateral value by 10^10 less
+        let (circuit, mut public_inputs) =
+            create_price_ltv_circuit(15000 * USDC, 10 * ETH, 80, 2000 * USD, 18, 6);
+        public_inputs[2] = Fp::from(8u64);
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err(), "Mismatched public decimals should fail");
+    }
+
+    #[test]
+    fn test_price_ltv_decimals_out_of_table() {
+        let k = 17;
+
+        // Debt decimals above collateral decimals are not in the table
+        let (circuit, public_inputs) =
+            create_price_ltv_circuit(0, 10 * ETH, 80, 2000 * USD, 6, 18);
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err(), "debt_decimals > collateral_decimals should fail");
+
+        let (circuit, public_inputs) =
+            create_price_ltv_circuit(0, 10 * ETH, 80, 2000 * USD, 19, 6);
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err(), "Decimals above 18 should fail");
+    }
+
+    #[test]
+    fn test_price_ltv_forged_scale() {
+        let k = 17;
+
+        // A prover cannot shrink the decimal scale to hide the debt
+        let (mut circuit, public_inputs) =
+            create_price_ltv_circuit(30000 * USDC, 10 * ETH, 80, 2000 * USD, 18, 6);
+        circuit.collateral_decimals = Value::known(Fp::from(18u64));
+        circuit.debt_decimals = Value::known(Fp::from(12u64));
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err(), "Private decimals must match the public ones");
+    }
+
+    #[test]
+    fn test_price_ltv_parameter_bounds() {
+        let k = 17;
+
+        let (circuit, public_inputs) =
+            create_price_ltv_circuit(0, 10 * ETH, 101, 2000 * USD, 18, 6);
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err(), "max_ltv > 100 should fail");
+
+        let (circuit, public_inputs) = create_price_ltv_circuit(0, 10 * ETH, 80, 0, 18, 6);
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err(), "Zero price should fail");
+    }
+
+    #[test]
+    fn test_decimal_scale() {
+        assert_eq!(PriceLTVCircuit::<Fp>::decimal_scale(18, 6), Some(1_000_000_000_000));
+        assert_eq!(PriceLTVCircuit::<Fp>::decimal_scale(6, 6), Some(1));
+        assert_eq!(PriceLTVCircuit::<Fp>::decimal_scale(6, 18), None);
+        assert_eq!(PriceLTVCircuit::<Fp>::decimal_scale(19, 6), None);
+    }
+}
@@ -0,0 +1,795 @@
+//! Solvency Circuit
+//!
+//! Proves that the protocol as a whole is solvent without revealing any
+//! individual position: the positions committed under a public Merkle root
+//! hold collateral worth at least their combined debt at the oracle price,
+//! `sum(collateral_i) * price >= sum(debt_i) * 10^20`
+//!
+//! Units follow `ZKLendingPool`: collateral in 18 decimals, debt in 6 decimals,
+//! price in 8 decimals, so `10^20` brings both sides to the same scale.
+//!
+//! # Position Tree
+//! The tree has `2^DEPTH` slots. Slot `i` holds the leaf
+//! `Poseidon(collateral_commitment_i, debt_commitment_i)` of position `i`,
+//! where both commitments are `Poseidon(value, salt)` as in every other
+//! circuit. Unused slots hold the empty leaf `0`, so the root equals
+//! `IncrementalMerkleTree::root` over the position leaves. The circuit hashes
+//! the whole tree, not a membership path, so the public root proves that
+//! every position is counted in the totals.
+//!
+//! # Public Inputs
+//! - `root`: Merkle root of the position leaves
+//! - `price`: Collateral price from oracle (8 decimals), 0 < price < 2^64
+//! - `total_collateral`: Sum of the committed collateral
+//! - `total_debt`: Sum of the committed debt
+//!
+//! # Private Inputs
+//! - `collateral_i`, `debt_i`: Amounts of each position
+//! - `collateral_salt_i`, `debt_salt_i`: Salts of the position commitments
+//! - `active_i`: 1 for occupied slots, 0 for empty ones
+//!
+//! # Constraints
+//! 1. `active_i` is boolean; empty slots hold zero collateral and zero debt
+//! 2. `leaf_i = active_i * Poseidon(Poseidon(collateral_i, collateral_salt_i), Poseidon(debt_i, debt_salt_i))`
+//! 3. The tree built from `leaf_i` hashes to `root`
+//! 4. `total_collateral = sum(collateral_i)`, `total_debt = sum(debt_i)`
+//! 5. `total_collateral * price >= total_debt * 10^20`
+//! 6. Amounts fit in 128 bits
+//!
+//! # Example
+//! - positions: (10 ETH, 15000 USDC), (2 ETH, 1000 USDC), (5 ETH, 9000 USDC)
+//! - 17 ETH at $2000 = $34000 >= $25000 debt ✓
+//! - at $1400: $23800 < $25000 ✗
+//!
+//! # Circuit Statistics
+//! - Advice columns: 12 (amounts, active, sums, leaf, price, scaled totals, salt, commitment, diff)
+//! - Instance columns: 1 (public inputs)
+//! - Custom gates: slot, accumulation, leaf, totals scaling, Poseidon rounds, comparison
+//! - Lookup tables: 1 (range check for comparison)
+//! - Estimated rows: ~2^17 (16-bit lookup table, `4 * 2^DEPTH - 1` Poseidon hashes)
+
+use ff::PrimeField;
+use halo2_proofs::{
+    circuit::{AssignedCell, Layouter, SimpleFloorPlanner, Value},
+    plonk::{Advice, Circuit, Column, ConstraintSystem, Error, Expression, Instance, Selector},
+    poly::Rotation,
+};
+use std::marker::PhantomData;
+
+use crate::constants::{LOOKUP_BITS, RANGE_BITS, PRICE_BITS};
+use crate::error::{CircuitError, CircuitResult};
+use crate::gadgets::comparison::{ComparisonChip, ComparisonConfig, ComparisonInstruction};
+use crate::gadgets::merkle::IncrementalMerkleTree;
+use crate::gadgets::poseidon::{compute_commitment, poseidon_hash, PoseidonChip, PoseidonConfig};
+
+/// Width of the compared values; the extra lookup width covers the 67-bit
+/// scale and sums over up to 2^13 positions
+const SCALED_BITS: usize = RANGE_BITS + PRICE_BITS + LOOKUP_BITS;
+/// Collateral (18 decimals) at an 8-decimal price → debt (6 decimals)
+pub const SOLVENCY_SCALE: u128 = 100_000_000_000_000_000_000;
+/// Deepest tree whose `4 * 2^DEPTH - 1` Poseidon hashes fit in 2^17 rows
+pub const MAX_SOLVENCY_DEPTH: usize = 8;
+
+/// Configuration for the Solvency circuit
+#[derive(Debug, Clone)]
+pub struct SolvencyConfig<F: PrimeField> {
+    // Per-slot rows (row i = slot i)
+    pub collateral: Column<Advice>,
+    pub debt: Column<Advice>,
+    pub active: Column<Advice>,
+    pub collateral_acc: Column<Advice>, // running sum of collateral
+    pub debt_acc: Column<Advice>,       // running sum of debt
+    pub leaf: Column<Advice>,           // active * position hash
+
+    // Totals
+    pub price: Column<Advice>,
+    pub collateral_value: Column<Advice>, // total_collateral * price
+    pub debt_scaled: Column<Advice>,      // total_debt * SOLVENCY_SCALE
+
+    /// Salts for the position commitments (two per slot)
+    pub salt: Column<Advice>,
+    /// Poseidon output column (third state column)
+    pub commitment: Column<Advice>,
+
+    // Public inputs
+    pub instance: Column<Instance>,
+
+    // Gates
+    pub q_slot: Selector,
+    pub q_first: Selector,
+    pub q_acc: Selector,
+    pub q_leaf: Selector,
+    pub q_totals: Selector,
+
+    /// Poseidon chip config (state = [collateral, salt, commitment])
+    pub poseidon: PoseidonConfig<F>,
+    /// Comparison for the solvency check
+    pub comparison: ComparisonConfig<F, LOOKUP_BITS>,
+
+    _marker: PhantomData<F>,
+}
+
+/// One position as seen by the solvency prover
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SolvencyPosition<F: PrimeField> {
+    pub collateral: F,
+    pub debt: F,
+    pub collateral_salt: F,
+    pub debt_salt: F,
+}
+
+impl<F: PrimeField> SolvencyPosition<F> {
+    pub fn new(collateral: F, debt: F, collateral_salt: F, debt_salt: F) -> Self {
+        Self {
+            collateral,
+            debt,
+            collateral_salt,
+            debt_salt,
+        }
+    }
+
+    /// Poseidon(collateral, collateral_salt)
+    pub fn collateral_commitment(&self) -> F {
+        compute_commitment(self.collateral, self.collateral_salt)
+    }
+
+    /// Poseidon(debt, debt_salt)
+    pub fn debt_commitment(&self) -> F {
+        compute_commitment(self.debt, self.debt_salt)
+    }
+
+    /// Tree leaf of this position
+    pub fn leaf(&self) -> F {
+        position_leaf(self.collateral_commitment(), self.debt_commitment())
+    }
+}
+
+/// Tree leaf of a position: Poseidon(collateral_commitment, debt_commitment)
+pub fn position_leaf<F: PrimeField>(collateral_commitment: F, debt_commitment: F) -> F {
+    poseidon_hash(collateral_commitment, debt_commitment)
+}
+
+/// Solvency circuit over a position tree of depth `DEPTH`
+///
+/// Every witness vector has one entry per slot (`2^DEPTH`).
+#[derive(Clone)]
+pub struct SolvencyCircuit<F: PrimeField, const DEPTH: usize> {
+    /// Private: collateral of each slot
+    pub collaterals: Vec<Value<F>>,
+    /// Private: debt of each slot
+    pub debts: Vec<Value<F>>,
+    /// Private: salt of each collateral commitment
+    pub collateral_salts: Vec<Value<F>>,
+    /// Private: salt of each debt commitment
+    pub debt_salts: Vec<Value<F>>,
+    /// Private: 1 if the slot holds a position, 0 otherwise
+    pub active: Vec<Value<F>>,
+    /// Public: collateral price (passed via instance)
+    pub price: Value<F>,
+}
+
+impl<F: PrimeField, const DEPTH: usize> Default for SolvencyCircuit<F, DEPTH> {
+    fn default() -> Self {
+        let unknown = vec![Value::unknown(); 1 << DEPTH];
+        Self {
+            collaterals: unknown.clone(),
+            debts: unknown.clone(),
+            collateral_salts: unknown.clone(),
+            debt_salts: unknown.clone(),
+            active: unknown,
+            price: Value::unknown(),
+        }
+    }
+}
+
+impl<F: PrimeField, const DEPTH: usize> SolvencyCircuit<F, DEPTH> {
+    /// Create a new circuit; positions fill the first slots in order
+    pub fn new(positions: &[SolvencyPosition<F>], price: F) -> CircuitResult<Self> {
+        let capacity = Self::capacity();
+        if positions.len() > capacity {
+            return Err(CircuitError::ValueOutOfRange {
+                value: positions.len() as u64,
+                max: capacity as u64,
+                field: "solvency positions".to_string(),
+            });
+        }
+
+        let slot = |f: fn(&SolvencyPosition<F>) -> F, empty: F| -> Vec<Value<F>> {
+            (0..capacity)
+                .map(|i| Value::known(positions.get(i).map_or(empty, f)))
+                .collect()
+        };
+
+        Ok(Self {
+            collaterals: slot(|p| p.collateral, F::ZERO),
+            debts: slot(|p| p.debt, F::ZERO),
+            collateral_salts: slot(|p| p.collateral_salt, F::ZERO),
+            debt_salts: slot(|p| p.debt_salt, F::ZERO),
+            active: slot(|_| F::ONE, F::ZERO),
+            price: Value::known(price),
+        })
+    }
+
+    /// Number of slots in the tree (2^DEPTH)
+    pub fn capacity() -> usize {
+        1 << DEPTH
+    }
+
+    /// Merkle root of the position leaves, matching the circuit
+    pub fn compute_root(positions: &[SolvencyPosition<F>]) -> CircuitResult<F> {
+        let mut tree = IncrementalMerkleTree::new(DEPTH)?;
+        for position in positions {
+            tree.insert(position.leaf())?;
+        }
+        Ok(tree.root())
+    }
+
+    /// Arrange the public inputs in instance order
+    ///
+    /// `[root, price, total_collateral, total_debt]`
+    pub fn public_inputs(root: F, price: F, total_collateral: F, total_debt: F) -> Vec<F> {
+        vec![root, price, total_collateral, total_debt]
+    }
+
+    /// Collateral value in debt units: `total_collateral * price / 10^20`, rounded down
+    ///
+    /// Exact even when `total_collateral * price` exceeds a u128: with
+    /// `total_collateral = hi * 10^20 + a * 10^10 + b` and `b * price = q * 10^10 + r`,
+    /// the value is `hi * price + (a * price + q) / 10^10`, where every product
+    /// stays below 2^126.
+    pub fn collateral_value(total_collateral: u128, price: u64) -> u128 {
+        let half_scale = 10_000_000_000u128;
+        let price = price as u128;
+        let (hi, lo) = (total_collateral / SOLVENCY_SCALE, total_collateral % SOLVENCY_SCALE);
+        let (a, b) = (lo / half_scale, lo % half_scale);
+        let q = b * price / half_scale;
+        hi * price + (a * price + q) / half_scale
+    }
+
+    /// Whether `total_collateral * price >= total_debt * 10^20`
+    pub fn is_solvent(total_collateral: u128, total_debt: u128, price: u64) -> bool {
+        Self::collateral_value(total_collateral, price) >= total_debt
+    }
+}
+
+impl<F: PrimeField, const DEPTH: usize> Circuit<F> for SolvencyCircuit<F, DEPTH> {
+    type Config = SolvencyConfig<F>;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        // Advice columns
+        let collateral = meta.advice_column();
+        let debt = meta.advice_column();
+        let active = meta.advice_column();
+        let collateral_acc = meta.advice_column();
+        let debt_acc = meta.advice_column();
+        let leaf = meta.advice_column();
+        let price = meta.advice_column();
+        let collateral_value = meta.advice_column();
+        let debt_scaled = meta.advice_column();
+        let salt = meta.advice_column();
+        let commitment = meta.advice_column();
+
+        // Instance for public inputs
+        let instance = meta.instance_column();
+
+        // Enable equality
+        for col in [
+            collateral,
+            debt,
+            active,
+            collateral_acc,
+            debt_acc,
+            leaf,
+            price,
+            collateral_value,
+            debt_scaled,
+            salt,
+            commitment,
+        ] {
+            meta.enable_equality(col);
+        }
+        meta.enable_equality(instance);
+
+        // Selectors
+        let q_slot = meta.selector();
+        let q_first = meta.selector();
+        let q_acc = meta.selector();
+        let q_leaf = meta.selector();
+        let q_totals = meta.selector();
+
+        // Slot gate: active is boolean, empty slots hold nothing
+        meta.create_gate("solvency slot", |meta| {
+            let q = meta.query_selector(q_slot);
+            let collateral = meta.query_advice(collateral, Rotation::cur());
+            let debt = meta.query_advice(debt, Rotation::cur());
+            let active = meta.query_advice(active, Rotation::cur());
+            let inactive = Expression::Constant(F::ONE) - active.clone();
+
+            vec![
+                q.clone() * active * inactive.clone(),
+                q.clone() * inactive.clone() * collateral,
+                q * inactive * debt,
+            ]
+        });
+
+        // Accumulation gate: both sums start at the first slot and add one slot per row
+        meta.create_gate("solvency accumulation", |meta| {
+            let q_first = meta.query_selector(q_first);
+            let q_acc = meta.query_selector(q_acc);
+            let collateral = meta.query_advice(collateral, Rotation::cur());
+            let debt = meta.query_advice(debt, Rotation::cur());
+            let collateral_acc_cur = meta.query_advice(collateral_acc, Rotation::cur());
+            let collateral_acc_prev = meta.query_advice(collateral_acc, Rotation::prev());
+            let debt_acc_cur = meta.query_advice(debt_acc, Rotation::cur());
+            let debt_acc_prev = meta.query_advice(debt_acc, Rotation::prev());
+
+            vec![
+                q_first.clone() * (collateral_acc_cur.clone() - collateral.clone()),
+                q_first * (debt_acc_cur.clone() - debt.clone()),
+                q_acc.clone() * (collateral_acc_cur - collateral_acc_prev - collateral),
+                q_acc * (debt_acc_cur - debt_acc_prev - debt),
+            ]
+        });
+
+        // Leaf gate: leaf = active * position hash (position hash in the commitment column)
+        meta.create_gate("solvency leaf", |meta| {
+            let q = meta.query_selector(q_leaf);
+            let active = meta.query_advice(active, Rotation::cur());
+            let position_hash = meta.query_advice(commitment, Rotation::cur());
+            let leaf = meta.query_advice(leaf, Rotation::cur());
+
+            vec![q * (leaf - active * position_hash)]
+        });
+
+        // Totals gate: scale both sides of the solvency check
+        meta.create_gate("solvency totals", |meta| {
+            let q = meta.query_selector(q_totals);
+            let total_collateral = meta.query_advice(collateral_acc, Rotation::cur());
+            let total_debt = meta.query_advice(debt_acc, Rotation::cur());
+            let price = meta.query_advice(price, Rotation::cur());
+            let collateral_value = meta.query_advice(collateral_value, Rotation::cur());
+            let debt_scaled = meta.query_advice(debt_scaled, Rotation::cur());
+            let scale = Expression::Constant(F::from_u128(SOLVENCY_SCALE));
+
+            vec![
+                q.clone() * (collateral_value - total_collateral * price),
+                q * (debt_scaled - total_debt * scale),
+            ]
+        });
+
+        // Poseidon chip for commitments, leaves and tree nodes
+        let poseidon = PoseidonChip::configure(meta, [collateral, salt, commitment]);
+
+        // Comparison config for the solvency check
+        let diff = meta.advice_column();
+        meta.enable_equality(diff);
+        let comparison = ComparisonChip::<F, LOOKUP_BITS>::configure_with_range_bits(
+            meta,
+            collateral_value, // a = total_collateral * price
+            debt_scaled,      // b = total_debt * 10^20
+            diff,
+            SCALED_BITS,
+        );
+
+        SolvencyConfig {
+            collateral,
+            debt,
+            active,
+            collateral_acc,
+            debt_acc,
+            leaf,
+            price,
+            collateral_value,
+            debt_scaled,
+            salt,
+            commitment,
+            instance,
+            q_slot,
+            q_first,
+            q_acc,
+            q_leaf,
+            q_totals,
+            poseidon,
+            comparison,
+            _marker: PhantomData,
+        }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let capacity = Self::capacity();
+        if DEPTH == 0 || DEPTH > MAX_SOLVENCY_DEPTH {
+            return Err(Error::Synthesis);
+        }
+        if [&self.collaterals, &self.debts, &self.collateral_salts, &self.debt_salts, &self.active]
+            .iter()
+            .any(|slots| slots.len() != capacity)
+        {
+            return Err(Error::Synthesis);
+        }
+
+        // Load comparison lookup table
+        let comparison_chip = ComparisonChip::<F, LOOKUP_BITS>::construct(config.comparison.clone());
+        comparison_chip.load_table(layouter.namespace(|| "load table"))?;
+
+        // Slot region: one row per slot, accumulating collateral and debt
+        type SlotCells = (AssignedCell<F, F>, AssignedCell<F, F>, AssignedCell<F, F>);
+        let (slot_cells, total_collateral_cell, total_debt_cell): (Vec<SlotCells>, _, _) =
+            layouter.assign_region(
+                || "positions",
+                |mut region| {
+                    let mut cells = Vec::with_capacity(capacity);
+                    let mut collateral_acc = Value::known(F::ZERO);
+                    let mut debt_acc = Value::known(F::ZERO);
+                    let mut acc_cells = None;
+
+                    for i in 0..capacity {
+                        config.q_slot.enable(&mut region, i)?;
+                        if i == 0 {
+                            config.q_first.enable(&mut region, i)?;
+                        } else {
+                            config.q_acc.enable(&mut region, i)?;
+                        }
+
+                        let collateral_cell = region.assign_advice(
+                            || format!("collateral {}", i),
+                            config.collateral,
+                            i,
+                            || self.collaterals[i],
+                        )?;
+                        let debt_cell =
+                            region.assign_advice(|| format!("debt {}", i), config.debt, i, || self.debts[i])?;
+                        let active_cell =
+                            region.assign_advice(|| format!("active {}", i), config.active, i, || self.active[i])?;
+
+                        collateral_acc = collateral_acc.zip(self.collaterals[i]).map(|(acc, c)| acc + c);
+                        debt_acc = debt_acc.zip(self.debts[i]).map(|(acc, d)| acc + d);
+                        acc_cells = Some((
+                            region.assign_advice(
+                                || format!("collateral_acc {}", i),
+                                config.collateral_acc,
+                                i,
+                                || collateral_acc,
+                            )?,
+                            region.assign_advice(|| format!("debt_acc {}", i), config.debt_acc, i, || debt_acc)?,
+                        ));
+
+                        cells.push((collateral_cell, debt_cell, active_cell));
+                    }
+
+                    let (total_collateral, total_debt) = acc_cells.ok_or(Error::Synthesis)?;
+                    Ok((cells, total_collateral, total_debt))
+                },
+            )?;
+
+        // Salts: rows 2i and 2i + 1 for the collateral and debt of slot i
+        let salt_cells = layouter.assign_region(
+            || "assign salts",
+            |mut region| {
+                (0..capacity)
+                    .map(|i| -> Result<_, Error> {
+                        let collateral_salt = region.assign_advice(
+                            || format!("collateral_salt {}", i),
+                            config.salt,
+                            2 * i,
+                            || self.collateral_salts[i],
+                        )?;
+                        let debt_salt = region.assign_advice(
+                            || format!("debt_salt {}", i),
+                            config.salt,
+                            2 * i + 1,
+                            || self.debt_salts[i],
+                        )?;
+                        Ok((collateral_salt, debt_salt))
+                    })
+                    .collect::<Result<Vec<_>, Error>>()
+            },
+        )?;
+
+        // Position hash = Poseidon(Poseidon(collateral, salt_c), Poseidon(debt, salt_d))
+        let poseidon_chip = PoseidonChip::construct(config.poseidon.clone());
+        let mut position_hashes = Vec::with_capacity(capacity);
+        for (i, ((collateral_cell, debt_cell, _), (collateral_salt, debt_salt))) in
+            slot_cells.iter().zip(salt_cells).enumerate()
+        {
+            let collateral_comm = poseidon_chip.hash(
+                layouter.namespace(|| format!("collateral commitment {}", i)),
+                collateral_cell.clone(),
+                collateral_salt,
+            )?;
+            let debt_comm = poseidon_chip.hash(
+                layouter.namespace(|| format!("debt commitment {}", i)),
+                debt_cell.clone(),
+                debt_salt,
+            )?;
+            position_hashes.push(poseidon_chip.hash(
+                layouter.namespace(|| format!("position hash {}", i)),
+                collateral_comm,
+                debt_comm,
+            )?);
+        }
+
+        // Leaves: empty slots hash to the empty leaf 0
+        let leaves = layouter.assign_region(
+            || "leaves",
+            |mut region| {
+                slot_cells
+                    .iter()
+                    .zip(&position_hashes)
+                    .enumerate()
+                    .map(|(i, ((_, _, active_cell), position_hash))| {
+                        config.q_leaf.enable(&mut region, i)?;
+
+                        let active = active_cell.copy_advice(|| format!("active {}", i), &mut region, config.active, i)?;
+                        let hash = position_hash.copy_advice(
+                            || format!("position hash {}", i),
+                            &mut region,
+                            config.commitment,
+                            i,
+                        )?;
+
+                        let leaf_val = active.value().copied().zip(hash.value().copied()).map(|(a, h)| a * h);
+                        region.assign_advice(|| format!("leaf {}", i), config.leaf, i, || leaf_val)
+                    })
+                    .collect::<Result<Vec<_>, Error>>()
+            },
+        )?;
+
+        // Hash the tree level by level; node j of a level is Poseidon(child 2j, child 2j + 1)
+        let mut level = leaves;
+        for height in 0..DEPTH {
+            level = level
+                .chunks(2)
+                .enumerate()
+                .map(|(j, pair)| {
+                    poseidon_chip.hash(
+                        layouter.namespace(|| format!("node {} {}", height + 1, j)),
+                        pair[0].clone(),
+                        pair[1].clone(),
+                    )
+                })
+                .collect::<Result<Vec<_>, Error>>()?;
+        }
+        let root_cell = level.pop().ok_or(Error::Synthesis)?;
+
+        // Totals region: scale both sides of the solvency check
+        let (price_cell, collateral_value_cell, debt_scaled_cell) = layouter.assign_region(
+            || "solvency totals",
+            |mut region| {
+                config.q_totals.enable(&mut region, 0)?;
+
+                let total_collateral =
+                    total_collateral_cell.copy_advice(|| "total_collateral", &mut region, config.collateral_acc, 0)?;
+                let total_debt = total_debt_cell.copy_advice(|| "total_debt", &mut region, config.debt_acc, 0)?;
+                let price_cell = region.assign_advice(|| "price", config.price, 0, || self.price)?;
+
+                // collateral_value = total_collateral * price
+                let collateral_value_val = total_collateral.value().copied().zip(self.price).map(|(c, p)| c * p);
+                let collateral_value_cell = region.assign_advice(
+                    || "collateral_value",
+                    config.collateral_value,
+                    0,
+                    || collateral_value_val,
+                )?;
+
+                // debt_scaled = total_debt * 10^20
+                let debt_scaled_val = total_debt.value().map(|d| *d * F::from_u128(SOLVENCY_SCALE));
+                let debt_scaled_cell =
+                    region.assign_advice(|| "debt_scaled", config.debt_scaled, 0, || debt_scaled_val)?;
+
+                Ok((price_cell, collateral_value_cell, debt_scaled_cell))
+            },
+        )?;
+
+        // instance[0] = root
+        layouter.constrain_instance(root_cell.cell(), config.instance, 0)?;
+        // instance[1] = price
+        layouter.constrain_instance(price_cell.cell(), config.instance, 1)?;
+        // instance[2] = total_collateral
+        layouter.constrain_instance(total_collateral_cell.cell(), config.instance, 2)?;
+        // instance[3] = total_debt
+        layouter.constrain_instance(total_debt_cell.cell(), config.instance, 3)?;
+
+        // Public price bounds: 0 < price < 2^64
+        comparison_chip.in_range(
+            layouter.namespace(|| "price bounds"),
+            price_cell,
+            F::ONE,
+            F::from(u64::MAX),
+        )?;
+
+        // Private amounts must not wrap the field when summed and scaled
+        for (i, (collateral_cell, debt_cell, _)) in slot_cells.into_iter().enumerate() {
+            comparison_chip.range_check(
+                layouter.namespace(|| format!("collateral {} range", i)),
+                collateral_cell,
+                RANGE_BITS,
+            )?;
+            comparison_chip.range_check(layouter.namespace(|| format!("debt {} range", i)), debt_cell, RANGE_BITS)?;
+        }
+
+        // Solvency check: total_collateral * price >= total_debt * 10^20
+        comparison_chip.gte(
+            layouter.namespace(|| "solvency check"),
+            collateral_value_cell,
+            debt_scaled_cell,
+        )?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ff::Field;
+    use pasta_curves::Fp;
+    use halo2_proofs::dev::MockProver;
+
+    /// $1 with 8 decimals
+    const USD: u64 = 100_000_000;
+    const ETH: u128 = 1_000_000_000_000_000_000;
+    const USDC: u128 = 1_000_000;
+
+    fn position(collateral: u128, debt: u128, i: u64) -> SolvencyPosition<Fp> {
+        SolvencyPosition::new(
+            Fp::from_u128(collateral),
+            Fp::from_u128(debt),
+            Fp::from(11111 * (i + 1)),
+            Fp::from(22222 * (i + 1)),
+        )
+    }
+
+    fn create_solvency_circuit<const DEPTH: usize>(
+        positions: &[(u128, u128)],
+        price: u64,
+    ) -> (SolvencyCircuit<Fp, DEPTH>, Vec<Fp>) {
+        let positions: Vec<_> = positions
+            .iter()
+            .enumerate()
+            .map(|(i, (c, d))| position(*c, *d, i as u64))
+            .collect();
+        let total_collateral: Fp = positions.iter().map(|p| p.collateral).sum();
+        let total_debt: Fp = positions.iter().map(|p| p.debt).sum();
+        let root = SolvencyCircuit::<Fp, DEPTH>::compute_root(&positions).unwrap();
+
+        let circuit = SolvencyCircuit::new(&positions, Fp::from(price)).unwrap();
+        let public_inputs =
+            SolvencyCircuit::<Fp, DEPTH>::public_inputs(root, Fp::from(price), total_collateral, total_debt);
+
+        (circuit, public_inputs)
+    }
+
+    fn example_positions() -> Vec<(u128, u128)> {
+        vec![
+            (10 * ETH, 15000 * USDC),
+            (2 * ETH, 1000 * USDC),
+            (5 * ETH, 9000 * USDC),
+        ]
+    }
+
+    #[test]
+    fn test_solvency_valid() {
+        let k = 17;
+
+        // 17 ETH at $2000 = $34000 >= $25000
+        let (circuit, public_inputs) = create_solvency_circuit::<2>(&example_positions(), 2000 * USD);
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert_eq!(prover.verify(), Ok(()), "Solvent protocol should pass");
+    }
+
+    #[test]
+    fn test_solvency_at_limit() {
+        let k = 17;
+
+        // 17 ETH at $2000 = $34000 exactly
+        let positions = [(10 * ETH, 20000 * USDC), (7 * ETH, 14000 * USDC)];
+        let (circuit, public_inputs) = create_solvency_circuit::<2>(&positions, 2000 * USD);
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert_eq!(prover.verify(), Ok(()), "Exactly covered debt should pass");
+    }
+
+    #[test]
+    fn test_solvency_insolvent() {
+        let k = 17;
+
+        // 17 ETH at $1400 = $23800 < $25000
+        let (circuit, public_inputs) = create_solvency_circuit::<2>(&example_positions(), 1400 * USD);
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err(), "Insolvent protocol should fail");
+    }
+
+    #[test]
+    fn test_solvency_full_tree() {
+        let k = 17;
+
+        let positions: Vec<_> = (0..8).map(|i| ((i + 1) * ETH, (i + 1) * 1500 * USDC)).collect();
+        let (circuit, public_inputs) = create_solvency_circuit::<3>(&positions, 2000 * USD);
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert_eq!(prover.verify(), Ok(()), "Full tree should pass");
+    }
+
+    #[test]
+    fn test_solvency_empty_tree() {
+        let k = 17;
+
+        let (circuit, public_inputs) = create_solvency_circuit::<2>(&[], 2000 * USD);
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert_eq!(prover.verify(), Ok(()), "Empty tree has no debt to cover");
+    }
+
+    #[test]
+    fn test_solvency_understated_debt_fails() {
+        let k = 17;
+
+        let (circuit, mut public_inputs) = create_solvency_circuit::<2>(&example_positions(), 1400 * USD);
+        public_inputs[3] = Fp::from_u128(20000 * USDC);
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err(), "Published total must match the committed debt");
+    }
+
+    #[test]
+    fn test_solvency_hidden_position_fails() {
+        let k = 17;
+
+        // Mark the largest borrower's slot empty: its leaf becomes 0 and its debt drops out
+        let (mut circuit, public_inputs) = create_solvency_circuit::<2>(&example_positions(), 1400 * USD);
+        circuit.active[0] = Value::known(Fp::ZERO);
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err(), "Empty slots must not hold debt");
+    }
+
+    #[test]
+    fn test_solvency_wrong_root_fails() {
+        let k = 17;
+
+        let (circuit, mut public_inputs) = create_solvency_circuit::<2>(&example_positions(), 2000 * USD);
+        let positions: Vec<_> = example_positions()
+            .iter()
+            .enumerate()
+            .take(2)
+            .map(|(i, (c, d))| position(*c, *d, i as u64))
+            .collect();
+        public_inputs[0] = SolvencyCircuit::<Fp, 2>::compute_root(&positions).unwrap();
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err(), "Root must cover every position");
+    }
+
+    #[test]
+    fn test_solvency_too_many_positions() {
+        let positions: Vec<_> = (0..5).map(|i| position(ETH, USDC, i)).collect();
+        assert!(SolvencyCircuit::<Fp, 2>::new(&positions, Fp::from(2000 * USD)).is_err());
+    }
+
+    #[test]
+    fn test_collateral_value() {
+        type Solvency = SolvencyCircuit<Fp, 2>;
+
+        assert_eq!(Solvency::collateral_value(17 * ETH, 2000 * USD), 34000 * USDC);
+        // 1 wei at $1 rounds down to zero
+        assert_eq!(Solvency::collateral_value(1, USD), 0);
+        // 10^30 * 10^12 overflows a u128, the value 10^22 does not
+        assert_eq!(Solvency::collateral_value(10u128.pow(30), 10u64.pow(12)), 10u128.pow(22));
+        // Rounding of the low limbs: (10^20 + 10^10 + 1) * 3 / 10^20
+        assert_eq!(Solvency::collateral_value(SOLVENCY_SCALE + 10_000_000_001, 3), 3);
+
+        assert!(Solvency::is_solvent(17 * ETH, 34000 * USDC, 2000 * USD));
+        assert!(!Solvency::is_solvent(17 * ETH, 34000 * USDC + 1, 2000 * USD));
+    }
+}
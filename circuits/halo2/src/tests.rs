@@ -9,9 +9,11 @@ mod integration_tests {
     use crate::error::validation::{
         validate_collateral, validate_ltv, validate_liquidation, validate_range, validate_salt,
     };
+    use crate::gadgets::poseidon::{compute_commitment, poseidon_hash};
     use crate::gadgets::poseidon::simple::SimpleCommitmentChip;
     use crate::liquidation::LiquidationCircuit;
     use crate::ltv::LTVCircuit;
+    use ff::{Field, PrimeField};
     use halo2_proofs::dev::MockProver;
     use pasta_curves::Fp;
 
@@ -223,11 +225,11 @@ mod integration_tests {
         fn test_large_values() {
             let k = 17;
 
-            // Values within 16-bit range (max 65535 for current range check)
-            // Note: For production with 64-bit support, decompose into multiple range checks
-            let collateral = Fp::from(60000u64);
+            // Wei-denominated values, far beyond the 16-bit lookup table
+            // (range checks decompose into 16-bit limbs)
+            let collateral = Fp::from_u128(1_000_000 * 10u128.pow(18)); // 1M ETH
             let salt = Fp::from(12345u64);
-            let threshold = Fp::from(30000u64);
+            let threshold = Fp::from_u128(u64::MAX as u128 + 1); // 2^64
             let commitment = CollateralCircuit::compute_commitment(collateral, salt);
 
             let circuit = CollateralCircuit::new(collateral, salt, threshold, commitment);
@@ -265,6 +267,29 @@ mod integration_tests {
             );
         }
 
+        #[test]
+        fn test_cannot_wrap_around_field() {
+            let k = 17;
+            let salt = Fp::from(12345u64);
+
+            // collateral = 2^64 - 1 < threshold = 2^64: diff wraps to p - 1
+            let collateral = Fp::from(u64::MAX);
+            let threshold = Fp::from_u128(u64::MAX as u128 + 1);
+            let commitment = CollateralCircuit::compute_commitment(collateral, salt);
+            let circuit = CollateralCircuit::new(collateral, salt, threshold, commitment);
+            let prover = MockProver::run(k, &circuit, vec![vec![threshold, commitment]]).unwrap();
+            assert!(prover.verify().is_err(), "Wrapped difference must fail");
+
+            // threshold = p - 1 makes collateral - threshold = collateral + 1 look small,
+            // so the threshold itself must be range-checked
+            let collateral = Fp::ZERO;
+            let threshold = -Fp::ONE;
+            let commitment = CollateralCircuit::compute_commitment(collateral, salt);
+            let circuit = CollateralCircuit::new(collateral, salt, threshold, commitment);
+            let prover = MockProver::run(k, &circuit, vec![vec![threshold, commitment]]).unwrap();
+            assert!(prover.verify().is_err(), "Field-sized threshold must fail");
+        }
+
         #[test]
         fn test_cannot_fake_commitment() {
             let k = 17;
@@ -287,6 +312,28 @@ mod integration_tests {
             );
         }
 
+        #[test]
+        fn test_cannot_open_commitment_with_wrong_salt() {
+            let k = 17;
+
+            let collateral = Fp::from(1000u64);
+            let threshold = Fp::from(500u64);
+
+            // Honest commitment published on-chain
+            let commitment = CollateralCircuit::compute_commitment(collateral, Fp::from(12345u64));
+
+            // Prover witnesses a different salt against the same commitment
+            let circuit =
+                CollateralCircuit::new(collateral, Fp::from(12346u64), threshold, commitment);
+            let public_inputs = vec![threshold, commitment];
+
+            let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+            assert!(
+                prover.verify().is_err(),
+                "Wrong salt should not open the commitment"
+            );
+        }
+
         #[test]
         fn test_cannot_exceed_ltv() {
             let k = 17;
@@ -321,16 +368,17 @@ mod integration_tests {
             // HF = (100 * 100 * 85) / (50 * 10000) = 850000 / 500000 = 1.7 > 1
             let collateral_fp = Fp::from(100u64);
             let debt_fp = Fp::from(50u64);
-            let salt = Fp::from(99999u64);
+            let salt_c = Fp::from(22222u64);
+            let salt_d = Fp::from(11111u64);
             let price_fp = Fp::from(100u64);
             let lt_fp = Fp::from(85u64);
 
-            let position_hash =
-                LiquidationCircuit::compute_position_hash(collateral_fp, debt_fp, salt);
+            let collateral_commitment = compute_commitment(collateral_fp, salt_c);
+            let debt_commitment = compute_commitment(debt_fp, salt_d);
 
             let circuit =
-                LiquidationCircuit::new(collateral_fp, debt_fp, salt, price_fp, lt_fp);
-            let public_inputs = vec![price_fp, lt_fp, position_hash];
+                LiquidationCircuit::new(collateral_fp, debt_fp, salt_c, salt_d, price_fp, lt_fp);
+            let public_inputs = vec![price_fp, lt_fp, collateral_commitment, debt_commitment];
 
             let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
             assert!(
@@ -340,6 +388,115 @@ mod integration_tests {
         }
     }
 
+    // =============================================================
+    // Public Parameter Bound Tests (Negative Tests)
+    // =============================================================
+
+    mod parameter_bounds_tests {
+        use super::*;
+
+        fn verify_ltv(debt: u64, collateral: u64, max_ltv: Fp, public_max_ltv: Fp) -> bool {
+            let debt_fp = Fp::from(debt);
+            let collateral_fp = Fp::from(collateral);
+            let salt_d = Fp::from(11111u64);
+            let salt_c = Fp::from(22222u64);
+
+            let debt_commitment = LTVCircuit::compute_commitment(debt_fp, salt_d);
+            let collateral_commitment = LTVCircuit::compute_commitment(collateral_fp, salt_c);
+
+            let circuit = LTVCircuit::new(debt_fp, collateral_fp, salt_d, salt_c, max_ltv);
+            let public_inputs = vec![public_max_ltv, debt_commitment, collateral_commitment];
+
+            let prover = MockProver::run(17, &circuit, vec![public_inputs]).unwrap();
+            prover.verify().is_ok()
+        }
+
+        fn verify_liquidation(collateral: u64, debt: u64, price: Fp, threshold: Fp) -> bool {
+            let collateral_fp = Fp::from(collateral);
+            let debt_fp = Fp::from(debt);
+            let salt_c = Fp::from(22222u64);
+            let salt_d = Fp::from(11111u64);
+
+            let collateral_commitment = compute_commitment(collateral_fp, salt_c);
+            let debt_commitment = compute_commitment(debt_fp, salt_d);
+
+            let circuit =
+                LiquidationCircuit::new(collateral_fp, debt_fp, salt_c, salt_d, price, threshold);
+            let public_inputs = vec![price, threshold, collateral_commitment, debt_commitment];
+
+            let prover = MockProver::run(17, &circuit, vec![public_inputs]).unwrap();
+            prover.verify().is_ok()
+        }
+
+        #[test]
+        fn test_ltv_bounds_accept_valid_range() {
+            // Both ends of 0 < max_ltv <= 100
+            assert!(verify_ltv(1, 100, Fp::from(1u64), Fp::from(1u64)));
+            assert!(verify_ltv(100, 100, Fp::from(100u64), Fp::from(100u64)));
+        }
+
+        #[test]
+        fn test_cannot_use_max_ltv_above_100() {
+            // 150% LTV "passes" the ratio check with max_ltv = 10_000
+            let max_ltv = Fp::from(10_000u64);
+            assert!(!verify_ltv(150, 100, max_ltv, max_ltv), "max_ltv = 10000 must fail");
+
+            let max_ltv = Fp::from(101u64);
+            assert!(!verify_ltv(50, 100, max_ltv, max_ltv), "max_ltv = 101 must fail");
+        }
+
+        #[test]
+        fn test_cannot_use_zero_max_ltv() {
+            assert!(!verify_ltv(0, 100, Fp::ZERO, Fp::ZERO), "max_ltv = 0 must fail");
+        }
+
+        #[test]
+        fn test_cannot_use_field_sized_max_ltv() {
+            // -1 is "small" only modulo p
+            assert!(!verify_ltv(0, 100, -Fp::ONE, -Fp::ONE), "max_ltv = p - 1 must fail");
+        }
+
+        #[test]
+        fn test_cannot_swap_public_max_ltv() {
+            // Proof made with 100% cannot be presented as an 80% proof
+            assert!(!verify_ltv(90, 100, Fp::from(100u64), Fp::from(80u64)));
+        }
+
+        #[test]
+        fn test_liquidation_bounds_accept_valid_range() {
+            assert!(verify_liquidation(100, 90, Fp::from(1u64), Fp::from(85u64)));
+            assert!(verify_liquidation(100, 101, Fp::from(1u64), Fp::from(100u64)));
+            assert!(verify_liquidation(1, 100, Fp::from(1u64), Fp::from(1u64)));
+        }
+
+        #[test]
+        fn test_cannot_use_threshold_out_of_bounds() {
+            let price = Fp::from(1u64);
+
+            // Zero threshold makes every indebted position "liquidatable"
+            assert!(!verify_liquidation(100, 1, price, Fp::ZERO), "threshold = 0 must fail");
+            assert!(
+                !verify_liquidation(100, 200, price, Fp::from(101u64)),
+                "threshold = 101 must fail"
+            );
+        }
+
+        #[test]
+        fn test_cannot_use_zero_price() {
+            // Zero price makes every indebted position "liquidatable"
+            assert!(!verify_liquidation(100, 1, Fp::ZERO, Fp::from(85u64)), "price = 0 must fail");
+        }
+
+        #[test]
+        fn test_cannot_use_out_of_range_price() {
+            let threshold = Fp::from(85u64);
+
+            let price = Fp::from_u128(1u128 << 64);
+            assert!(!verify_liquidation(1, 100, price, threshold), "price = 2^64 must fail");
+            assert!(!verify_liquidation(1, 100, -Fp::ONE, threshold), "price = p - 1 must fail");
+        }
+    }
+
     // =============================================================
     // Commitment Function Tests
     // =============================================================
@@ -370,6 +527,21 @@ mod integration_tests {
             assert_ne!(c1, c2, "Different inputs should produce different commitments");
         }
 
+        #[test]
+        fn test_collateral_commitment_is_poseidon() {
+            let collateral = Fp::from(1000u64);
+            let salt = Fp::from(12345u64);
+
+            let commitment = CollateralCircuit::compute_commitment(collateral, salt);
+
+            assert_eq!(commitment, poseidon_hash(collateral, salt));
+            // Must not be recoverable as commitment / (salt + 1)
+            assert_ne!(
+                commitment * (salt + Fp::ONE).invert().unwrap(),
+                collateral
+            );
+        }
+
         #[test]
         fn test_commitment_formula() {
             let a = Fp::from(100u64);
@@ -429,16 +601,17 @@ mod integration_tests {
 
             let collateral_fp = Fp::from(10u64);
             let debt_fp = Fp::from(70u64);
-            let salt = Fp::from(99999u64);
+            let salt_c = Fp::from(22222u64);
+            let salt_d = Fp::from(11111u64);
             let price_fp = Fp::from(8u64); // Price crashed
             let lt_fp = Fp::from(85u64);
 
-            let position_hash =
-                LiquidationCircuit::compute_position_hash(collateral_fp, debt_fp, salt);
+            let collateral_commitment = compute_commitment(collateral_fp, salt_c);
+            let debt_commitment = compute_commitment(debt_fp, salt_d);
 
             let circuit =
-                LiquidationCircuit::new(collateral_fp, debt_fp, salt, price_fp, lt_fp);
-            let public_inputs = vec![price_fp, lt_fp, position_hash];
+                LiquidationCircuit::new(collateral_fp, debt_fp, salt_c, salt_d, price_fp, lt_fp);
+            let public_inputs = vec![price_fp, lt_fp, collateral_commitment, debt_commitment];
 
             let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
             assert_eq!(
@@ -471,4 +644,105 @@ mod integration_tests {
             assert_eq!(prover.verify(), Ok(()));
         }
     }
+
+    // =============================================================
+    // BN254 Tests (KZG backend)
+    // =============================================================
+
+    mod bn254_tests {
+        use super::*;
+        use crate::constants::COMMITMENT_TREE_DEPTH;
+        use crate::gadgets::merkle::IncrementalMerkleTree;
+        use crate::gadgets::poseidon::compute_nullifier;
+        use crate::solvency::{SolvencyCircuit, SolvencyPosition};
+        use crate::withdraw::WithdrawCircuit;
+        use halo2_proofs::halo2curves::bn256::Fr;
+
+        const ETH: u128 = 1_000_000_000_000_000_000;
+        const USDC: u128 = 1_000_000;
+        const USD: u64 = 100_000_000;
+
+        fn collateral_circuit(collateral: u64, threshold: u64) -> (CollateralCircuit<Fr>, Vec<Fr>) {
+            let collateral = Fr::from(collateral);
+            let salt = Fr::from(12345u64);
+            let threshold = Fr::from(threshold);
+            let commitment = CollateralCircuit::compute_commitment(collateral, salt);
+
+            let circuit = CollateralCircuit::new(collateral, salt, threshold, commitment);
+            (circuit, vec![threshold, commitment])
+        }
+
+        #[test]
+        fn test_collateral_on_bn254() {
+            let k = 17;
+
+            let (circuit, public_inputs) = collateral_circuit(1000, 500);
+            let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+            assert_eq!(prover.verify(), Ok(()));
+
+            let (circuit, public_inputs) = collateral_circuit(400, 500);
+            let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+            assert!(prover.verify().is_err(), "Insufficient collateral should fail on BN254");
+        }
+
+        #[test]
+        fn test_withdraw_on_bn254() {
+            let k = 17;
+
+            // 100 collateral, 60 debt, withdraw 20 → LTV = 75% <= 80%
+            let (old, amount, debt, max_ltv) = (Fr::from(100u64), Fr::from(20u64), Fr::from(60u64), Fr::from(80u64));
+            let (old_salt, new_salt, debt_salt) = (Fr::from(11111u64), Fr::from(22222u64), Fr::from(33333u64));
+
+            let old_commitment = compute_commitment(old, old_salt);
+            let mut tree = IncrementalMerkleTree::new(COMMITMENT_TREE_DEPTH).unwrap();
+            let index = tree.insert(old_commitment).unwrap();
+            let old_path = tree.path(index).unwrap();
+            let public_inputs = vec![
+                tree.root(),
+                amount,
+                compute_commitment(old - amount, new_salt),
+                compute_commitment(debt, debt_salt),
+                max_ltv,
+                compute_nullifier(old_salt, old_commitment),
+            ];
+            let circuit = WithdrawCircuit::new(old, old_salt, &old_path, new_salt, debt, debt_salt, amount, max_ltv);
+
+            let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+            assert_eq!(prover.verify(), Ok(()));
+        }
+
+        #[test]
+        fn test_solvency_on_bn254() {
+            let k = 17;
+
+            // 12 ETH at $2000 = $24000 >= $16000
+            let positions: Vec<_> = [(10 * ETH, 15000 * USDC), (2 * ETH, 1000 * USDC)]
+                .iter()
+                .enumerate()
+                .map(|(i, (c, d))| {
+                    let i = i as u64 + 1;
+                    SolvencyPosition::new(Fr::from_u128(*c), Fr::from_u128(*d), Fr::from(11111 * i), Fr::from(22222 * i))
+                })
+                .collect();
+            let price = Fr::from(2000 * USD);
+            let total_collateral: Fr = positions.iter().map(|p| p.collateral).sum();
+            let total_debt: Fr = positions.iter().map(|p| p.debt).sum();
+            let root = SolvencyCircuit::<Fr, 2>::compute_root(&positions).unwrap();
+
+            let circuit = SolvencyCircuit::<Fr, 2>::new(&positions, price).unwrap();
+            let public_inputs = SolvencyCircuit::<Fr, 2>::public_inputs(root, price, total_collateral, total_debt);
+
+            let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+            assert_eq!(prover.verify(), Ok(()));
+        }
+
+        #[test]
+        fn test_commitments_are_field_specific() {
+            // Same value and salt, different field and Poseidon parameters
+            let pasta = CollateralCircuit::compute_commitment(Fp::from(1000u64), Fp::from(12345u64));
+            let bn254 = CollateralCircuit::compute_commitment(Fr::from(1000u64), Fr::from(12345u64));
+
+            assert_ne!(pasta.to_repr().as_ref(), bn254.to_repr().as_ref());
+        }
+    }
 }
@@ -0,0 +1,428 @@
+//! EVM Transcript
+//!
+//! Keccak256 transcript with the byte layout that Solidity verifiers generated by
+//! `halo2-solidity-verifier` and `snark-verifier` (`EvmTranscript`) read:
+//!
+//! - Points are written and absorbed uncompressed as `x || y`, each coordinate
+//!   32 bytes big-endian
+//! - Scalars are written and absorbed as 32 bytes big-endian
+//! - A challenge is `keccak256(buf) mod r`; the hash then replaces `buf`, and
+//!   squeezing again with nothing absorbed hashes `hash || 0x01`
+//!
+//! halo2's own `Keccak256Write` prefixes every absorbed item and writes
+//! compressed points, so those verifiers cannot check its proofs. The KZG
+//! backends prove with this transcript instead. No Solidity verifier is
+//! generated in this repository: this fixes the proof format a generated
+//! verifier expects, it does not provide one.
+//!
+//! # Usage
+//! ```ignore
+//! let mut transcript = EvmTranscript::<G1Affine, _>::new(vec![]);
+//! create_proof::<KZGCommitmentScheme<Bn256>, ProverSHPLONK<'_, Bn256>, _, _, _, _>(
+//!     &params, &pk, &[circuit], &[instances], OsRng, &mut transcript,
+//! )?;
+//! let proof = transcript.finalize();
+//! ```
+
+use ff::{FromUniformBytes, PrimeField};
+use halo2_proofs::{
+    arithmetic::CurveAffine,
+    transcript::{
+        EncodedChallenge, Transcript, TranscriptRead, TranscriptReadBuffer, TranscriptWrite,
+        TranscriptWriterBuffer,
+    },
+};
+use sha3::{Digest, Keccak256};
+use std::io::{self, Read, Write};
+use std::marker::PhantomData;
+
+/// Challenge squeezed from an `EvmTranscript`: the Keccak256 output read
+/// big-endian and reduced modulo the scalar field
+#[derive(Debug, Clone, Copy)]
+pub struct ChallengeEvm<C: CurveAffine>(C::Scalar);
+
+impl<C> EncodedChallenge<C> for ChallengeEvm<C>
+where
+    C: CurveAffine,
+    C::Scalar: FromUniformBytes<64>,
+{
+    type Input = [u8; 32];
+
+    fn new(hash: &[u8; 32]) -> Self {
+        // Little-endian and zero-extended, so the wide reduction is `hash mod r`
+        let mut bytes = [0u8; 64];
+        bytes[..32].copy_from_slice(hash);
+        bytes[..32].reverse();
+        ChallengeEvm(C::Scalar::from_uniform_bytes(&bytes))
+    }
+
+    fn get_scalar(&self) -> C::Scalar {
+        self.0
+    }
+}
+
+/// Keccak256 transcript in the EVM verifier encoding
+///
+/// Field elements are assumed to have a little-endian `Repr`, as on BN254.
+#[derive(Debug)]
+pub struct EvmTranscript<C: CurveAffine, S> {
+    stream: S,
+    /// Bytes absorbed since the last challenge (starting with that challenge)
+    buf: Vec<u8>,
+    _marker: PhantomData<C>,
+}
+
+impl<C: CurveAffine, S> EvmTranscript<C, S> {
+    /// Start a transcript over a proof stream: `Vec<u8>` to write, `&[u8]` to read
+    pub fn new(stream: S) -> Self {
+        Self {
+            stream,
+            buf: Vec::new(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<C, S> Transcript<C, ChallengeEvm<C>> for EvmTranscript<C, S>
+where
+    C: CurveAffine,
+    C::Scalar: FromUniformBytes<64>,
+{
+    fn squeeze_challenge(&mut self) -> ChallengeEvm<C> {
+        // Only the previous challenge: domain-separate consecutive squeezes
+        if self.buf.len() == 32 {
+            self.buf.push(1);
+        }
+
+        let hash: [u8; 32] = Keccak256::digest(&self.buf).into();
+        self.buf = hash.to_vec();
+        ChallengeEvm::new(&hash)
+    }
+
+    fn common_point(&mut self, point: C) -> io::Result<()> {
+        self.buf.extend(point_to_bytes(point)?);
+        Ok(())
+    }
+
+    fn common_scalar(&mut self, scalar: C::Scalar) -> io::Result<()> {
+        self.buf.extend(to_be_bytes(&scalar));
+        Ok(())
+    }
+}
+
+impl<C, W: Write> TranscriptWrite<C, ChallengeEvm<C>> for EvmTranscript<C, W>
+where
+    C: CurveAffine,
+    C::Scalar: FromUniformBytes<64>,
+{
+    fn write_point(&mut self, point: C) -> io::Result<()> {
+        let bytes = point_to_bytes(point)?;
+        self.buf.extend(&bytes);
+        self.stream.write_all(&bytes)
+    }
+
+    fn write_scalar(&mut self, scalar: C::Scalar) -> io::Result<()> {
+        let bytes = to_be_bytes(&scalar);
+        self.buf.extend(&bytes);
+        self.stream.write_all(&bytes)
+    }
+}
+
+impl<C, W: Write> TranscriptWriterBuffer<W, C, ChallengeEvm<C>> for EvmTranscript<C, W>
+where
+    C: CurveAffine,
+    C::Scalar: FromUniformBytes<64>,
+{
+    fn init(writer: W) -> Self {
+        Self::new(writer)
+    }
+
+    fn finalize(self) -> W {
+        self.stream
+    }
+}
+
+impl<C, R: Read> TranscriptRead<C, ChallengeEvm<C>> for EvmTranscript<C, R>
+where
+    C: CurveAffine,
+    C::Scalar: FromUniformBytes<64>,
+{
+    fn read_point(&mut self) -> io::Result<C> {
+        let x = read_be_bytes::<C::Base>(&mut self.stream)?;
+        let y = read_be_bytes::<C::Base>(&mut self.stream)?;
+        let point: Option<C> = C::from_xy(x, y).into();
+        let point = point.ok_or_else(|| invalid_data("Point is not on the curve"))?;
+
+        self.common_point(point)?;
+        Ok(point)
+    }
+
+    fn read_scalar(&mut self) -> io::Result<C::Scalar> {
+        let scalar = read_be_bytes::<C::Scalar>(&mut self.stream)?;
+
+        self.common_scalar(scalar)?;
+        Ok(scalar)
+    }
+}
+
+impl<C, R: Read> TranscriptReadBuffer<R, C, ChallengeEvm<C>> for EvmTranscript<C, R>
+where
+    C: CurveAffine,
+    C::Scalar: FromUniformBytes<64>,
+{
+    fn init(reader: R) -> Self {
+        Self::new(reader)
+    }
+}
+
+/// `x || y`, each coordinate big-endian
+fn point_to_bytes<C: CurveAffine>(point: C) -> io::Result<Vec<u8>> {
+    let coordinates: Option<_> = point.coordinates().into();
+    let coordinates =
+        coordinates.ok_or_else(|| invalid_data("Cannot encode the point at infinity"))?;
+
+    let mut bytes = to_be_bytes(coordinates.x());
+    bytes.extend(to_be_bytes(coordinates.y()));
+    Ok(bytes)
+}
+
+fn to_be_bytes<F: PrimeField>(value: &F) -> Vec<u8> {
+    let mut repr = value.to_repr();
+    repr.as_mut().reverse();
+    repr.as_ref().to_vec()
+}
+
+fn read_be_bytes<F: PrimeField>(reader: &mut impl Read) -> io::Result<F> {
+    let mut repr = F::Repr::default();
+    reader.read_exact(repr.as_mut())?;
+    repr.as_mut().reverse();
+
+    Option::from(F::from_repr(repr)).ok_or_else(|| invalid_data("Field element is not canonical"))
+}
+
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ff::Field;
+    use halo2_proofs::{
+        circuit::{Layouter, SimpleFloorPlanner, Value},
+        halo2curves::bn256::{Bn256, Fq, Fr, G1Affine},
+        plonk::{
+            create_proof, keygen_pk, keygen_vk, verify_proof, Advice, Circuit, Column,
+            ConstraintSystem, Error, Instance, ProvingKey, Selector,
+        },
+        poly::{
+            kzg::{
+                commitment::{KZGCommitmentScheme, ParamsKZG},
+                multiopen::{ProverGWC, ProverSHPLONK, VerifierGWC, VerifierSHPLONK},
+                strategy::SingleStrategy,
+            },
+            Rotation, VerificationStrategy,
+        },
+        transcript::{Challenge255, Keccak256Read},
+    };
+    use rand::rngs::OsRng;
+
+    /// `a * b == c` with `c` public
+    #[derive(Clone, Default)]
+    struct MulCircuit {
+        a: Value<Fr>,
+        b: Value<Fr>,
+    }
+
+    impl Circuit<Fr> for MulCircuit {
+        type Config = ([Column<Advice>; 3], Column<Instance>, Selector);
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self::default()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<Fr>) -> Self::Config {
+            let advice = [(); 3].map(|_| meta.advice_column());
+            let instance = meta.instance_column();
+            let q_mul = meta.selector();
+            meta.enable_equality(advice[2]);
+            meta.enable_equality(instance);
+
+            meta.create_gate("mul", |meta| {
+                let q = meta.query_selector(q_mul);
+                let [a, b, c] = advice.map(|col| meta.query_advice(col, Rotation::cur()));
+                vec![q * (a * b - c)]
+            });
+
+            (advice, instance, q_mul)
+        }
+
+        fn synthesize(
+            &self,
+            (advice, instance, q_mul): Self::Config,
+            mut layouter: impl Layouter<Fr>,
+        ) -> Result<(), Error> {
+            let c = layouter.assign_region(
+                || "mul",
+                |mut region| {
+                    q_mul.enable(&mut region, 0)?;
+                    region.assign_advice(|| "a", advice[0], 0, || self.a)?;
+                    region.assign_advice(|| "b", advice[1], 0, || self.b)?;
+                    region.assign_advice(|| "c", advice[2], 0, || self.a * self.b)
+                },
+            )?;
+
+            layouter.constrain_instance(c.cell(), instance, 0)
+        }
+    }
+
+    fn setup() -> (ParamsKZG<Bn256>, ProvingKey<G1Affine>) {
+        let params = ParamsKZG::<Bn256>::setup(4, OsRng);
+        let vk = keygen_vk(&params, &MulCircuit::default()).unwrap();
+        let pk = keygen_pk(&params, vk, &MulCircuit::default()).unwrap();
+        (params, pk)
+    }
+
+    fn circuit() -> MulCircuit {
+        MulCircuit {
+            a: Value::known(Fr::from(6u64)),
+            b: Value::known(Fr::from(7u64)),
+        }
+    }
+
+    /// `bytes` read big-endian, reduced modulo r
+    fn be_mod_r(bytes: &[u8]) -> Fr {
+        bytes.iter().fold(Fr::ZERO, |acc, byte| {
+            acc * Fr::from(256u64) + Fr::from(*byte as u64)
+        })
+    }
+
+    #[test]
+    fn test_evm_encoding() {
+        // BN254 generator (1, 2)
+        let point = G1Affine::from_xy(Fq::ONE, Fq::from(2u64)).unwrap();
+        let scalar = Fr::from(0x0102u64);
+
+        let mut transcript = EvmTranscript::<G1Affine, _>::new(vec![]);
+        transcript.write_point(point).unwrap();
+        transcript.write_scalar(scalar).unwrap();
+        let challenge = transcript.squeeze_challenge().get_scalar();
+        let next = transcript.squeeze_challenge().get_scalar();
+        let proof = transcript.finalize();
+
+        // x, y and the scalar, each 32 bytes big-endian
+        let mut expected = vec![0u8; 96];
+        expected[31] = 1;
+        expected[63] = 2;
+        expected[94] = 1;
+        expected[95] = 2;
+        assert_eq!(proof, expected);
+
+        // keccak256(buf) mod r, then keccak256(hash || 0x01) mod r
+        let hash = Keccak256::digest(&expected);
+        assert_eq!(challenge, be_mod_r(&hash));
+        let next_hash = Keccak256::digest([&hash[..], &[1u8][..]].concat());
+        assert_eq!(next, be_mod_r(&next_hash));
+
+        // Reading absorbs the same bytes
+        let mut reader = EvmTranscript::<G1Affine, _>::new(&proof[..]);
+        assert_eq!(reader.read_point().unwrap(), point);
+        assert_eq!(reader.read_scalar().unwrap(), scalar);
+        assert_eq!(reader.squeeze_challenge().get_scalar(), challenge);
+    }
+
+    #[test]
+    fn test_rejects_invalid_encodings() {
+        // (1, 3) is not on the curve
+        let mut bytes = vec![0u8; 64];
+        bytes[31] = 1;
+        bytes[63] = 3;
+        let mut reader = EvmTranscript::<G1Affine, _>::new(&bytes[..]);
+        assert!(reader.read_point().is_err());
+
+        // Scalar above the modulus
+        let bytes = [0xffu8; 32];
+        let mut reader = EvmTranscript::<G1Affine, _>::new(&bytes[..]);
+        assert!(reader.read_scalar().is_err());
+    }
+
+    #[test]
+    fn test_shplonk_round_trip() {
+        let (params, pk) = setup();
+        let instances: &[&[Fr]] = &[&[Fr::from(42u64)]];
+
+        let mut transcript = EvmTranscript::<G1Affine, _>::new(vec![]);
+        create_proof::<KZGCommitmentScheme<Bn256>, ProverSHPLONK<'_, Bn256>, _, _, _, _>(
+            &params,
+            &pk,
+            &[circuit()],
+            &[instances],
+            OsRng,
+            &mut transcript,
+        )
+        .unwrap();
+        let proof = transcript.finalize();
+
+        let verify = |proof: &[u8], instances: &[&[Fr]]| {
+            let mut transcript = EvmTranscript::<G1Affine, _>::new(proof);
+            verify_proof::<KZGCommitmentScheme<Bn256>, VerifierSHPLONK<'_, Bn256>, _, _, _>(
+                &params,
+                pk.get_vk(),
+                SingleStrategy::new(&params),
+                &[instances],
+                &mut transcript,
+            )
+        };
+        assert!(verify(&proof, instances).is_ok());
+        assert!(
+            verify(&proof, &[&[Fr::from(43u64)]]).is_err(),
+            "Wrong instance should fail"
+        );
+
+        // halo2's Keccak256 transcript reads a different format
+        let mut transcript = Keccak256Read::<_, G1Affine, Challenge255<G1Affine>>::init(&proof[..]);
+        let result = verify_proof::<KZGCommitmentScheme<Bn256>, VerifierSHPLONK<'_, Bn256>, _, _, _>(
+            &params,
+            pk.get_vk(),
+            SingleStrategy::new(&params),
+            &[instances],
+            &mut transcript,
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_gwc_round_trip() {
+        let (params, pk) = setup();
+        let instances: &[&[Fr]] = &[&[Fr::from(42u64)]];
+
+        let mut transcript = EvmTranscript::<G1Affine, _>::new(vec![]);
+        create_proof::<KZGCommitmentScheme<Bn256>, ProverGWC<'_, Bn256>, _, _, _, _>(
+            &params,
+            &pk,
+            &[circuit()],
+            &[instances],
+            OsRng,
+            &mut transcript,
+        )
+        .unwrap();
+        let proof = transcript.finalize();
+
+        let verify = |instances: &[&[Fr]]| {
+            let mut transcript = EvmTranscript::<G1Affine, _>::new(&proof[..]);
+            verify_proof::<KZGCommitmentScheme<Bn256>, VerifierGWC<'_, Bn256>, _, _, _>(
+                &params,
+                pk.get_vk(),
+                SingleStrategy::new(&params),
+                &[instances],
+                &mut transcript,
+            )
+        };
+        assert!(verify(instances).is_ok());
+        assert!(
+            verify(&[&[Fr::from(43u64)]]).is_err(),
+            "Wrong instance should fail"
+        );
+    }
+}
@@ -0,0 +1,578 @@
+//! CommitmentTransition Circuit
+//!
+//! Proves that a new commitment opens to the old committed value plus or minus
+//! a public `delta`, linking consecutive commitments of the same position
+//! (deposit top-ups, partial repayments) without revealing either value.
+//!
+//! # Public Inputs
+//! - `commitment_root`: Root of the registered commitment tree that holds the
+//!   old commitment; the old commitment itself stays private
+//! - `new_commitment`: Poseidon(new_value, new_salt)
+//! - `delta`: Amount added or removed
+//! - `increase`: 1 if `delta` is added (top-up), 0 if subtracted (repayment)
+//! - `nullifier`: Poseidon(old_salt, old_commitment) - retires the old commitment
+//!
+//! # Private Inputs
+//! - `old_value`: Committed value before the update
+//! - `old_salt`, `new_salt`: Salts for commitments
+//! - Merkle path of the old commitment (siblings and index bits,
+//!   `COMMITMENT_TREE_DEPTH` levels)
+//!
+//! # Constraints
+//! 1. `increase * (1 - increase) == 0`
+//! 2. `new_value = old_value + delta` if `increase`, else `old_value - delta`
+//! 3. `old_value`, `new_value`, `delta` fit in 128 bits (no wrap in either direction)
+//! 4. `old_commitment = Poseidon(old_value, old_salt)` and hashing it up the
+//!    Merkle path gives `commitment_root`
+//! 5. `new_commitment == Poseidon(new_value, new_salt)`
+//! 6. `nullifier == Poseidon(old_salt, old_commitment)`, with the same `old_salt`
+//!    that opens `old_commitment`
+//!
+//! # Circuit Statistics
+//! - Advice columns: 7 (values, delta, direction, salt, commitment, range check;
+//!   the Merkle path reuses all but `old_value` and the range check column)
+//! - Instance columns: 1 (public inputs)
+//! - Custom gates: transition arithmetic, Poseidon rounds, Merkle swap, range check shift
+//! - Lookup tables: 1 (range check)
+//! - Estimated rows: ~2^17 (16-bit lookup table)
+
+use ff::PrimeField;
+use halo2_proofs::{
+    circuit::{Layouter, SimpleFloorPlanner, Value},
+    plonk::{Advice, Circuit, Column, ConstraintSystem, Error, Expression, Instance, Selector},
+    poly::Rotation,
+};
+use std::marker::PhantomData;
+
+use crate::constants::{COMMITMENT_TREE_DEPTH, LOOKUP_BITS, RANGE_BITS};
+use crate::gadgets::merkle::{MerkleChip, MerkleConfig, MerklePath};
+use crate::gadgets::poseidon::{PoseidonChip, PoseidonConfig};
+use crate::gadgets::range_check::{RangeCheckChip, RangeCheckConfig, RangeCheckInstruction};
+
+/// Configuration for the CommitmentTransition circuit
+#[derive(Debug, Clone)]
+pub struct TransitionConfig<F: PrimeField> {
+    // Values
+    pub old_value: Column<Advice>,
+    pub new_value: Column<Advice>,
+
+    // Public parameters (copied from instance)
+    pub delta: Column<Advice>,
+    pub increase: Column<Advice>,
+
+    /// Salts for the two commitments (one per row)
+    pub salt: Column<Advice>,
+    /// Poseidon output column (third state column)
+    pub commitment: Column<Advice>,
+
+    // Public inputs
+    pub instance: Column<Instance>,
+
+    // Gates
+    pub q_transition: Selector,
+
+    /// Poseidon chip config (state = [old_value, salt, commitment])
+    pub poseidon: PoseidonConfig<F>,
+    /// Merkle path chip proving the old commitment is registered
+    pub merkle: MerkleConfig<F>,
+    /// Range checks for values and delta
+    pub range_check: RangeCheckConfig<F, LOOKUP_BITS>,
+
+    _marker: PhantomData<F>,
+}
+
+/// CommitmentTransition circuit
+#[derive(Clone)]
+pub struct CommitmentTransitionCircuit<F: PrimeField> {
+    /// Private: committed value before the update
+    pub old_value: Value<F>,
+    /// Private: salt of the old commitment
+    pub old_salt: Value<F>,
+    /// Private: siblings of the old commitment in the commitment tree (leaf level first)
+    pub old_siblings: Vec<Value<F>>,
+    /// Private: index bits of the old commitment (1 = right child)
+    pub old_path_bits: Vec<Value<F>>,
+    /// Private: salt of the new commitment
+    pub new_salt: Value<F>,
+    /// Public: amount added or removed (passed via instance)
+    pub delta: Value<F>,
+    /// Public: 1 for an increase, 0 for a decrease (passed via instance)
+    pub increase: Value<F>,
+}
+
+impl<F: PrimeField> Default for CommitmentTransitionCircuit<F> {
+    fn default() -> Self {
+        Self {
+            old_value: Value::unknown(),
+            old_salt: Value::unknown(),
+            old_siblings: vec![Value::unknown(); COMMITMENT_TREE_DEPTH],
+            old_path_bits: vec![Value::unknown(); COMMITMENT_TREE_DEPTH],
+            new_salt: Value::unknown(),
+            delta: Value::unknown(),
+            increase: Value::unknown(),
+        }
+    }
+}
+
+impl<F: PrimeField> CommitmentTransitionCircuit<F> {
+    /// Create a new circuit with the given values
+    ///
+    /// `old_path` is the path of the old commitment in the commitment tree and
+    /// must have `COMMITMENT_TREE_DEPTH` levels.
+    pub fn new(
+        old_value: F,
+        old_salt: F,
+        old_path: &MerklePath<F>,
+        new_salt: F,
+        delta: F,
+        increase: bool,
+    ) -> Self {
+        Self {
+            old_value: Value::known(old_value),
+            old_salt: Value::known(old_salt),
+            old_siblings: old_path.siblings.iter().map(|s| Value::known(*s)).collect(),
+            old_path_bits: old_path.path_bits_as_field().into_iter().map(Value::known).collect(),
+            new_salt: Value::known(new_salt),
+            delta: Value::known(delta),
+            increase: Value::known(Self::direction(increase)),
+        }
+    }
+
+    /// Field encoding of the `increase` public input
+    pub fn direction(increase: bool) -> F {
+        if increase {
+            F::ONE
+        } else {
+            F::ZERO
+        }
+    }
+
+    /// Value after applying `delta` in the given direction
+    pub fn apply_delta(old_value: F, delta: F, increase: bool) -> F {
+        if increase {
+            old_value + delta
+        } else {
+            old_value - delta
+        }
+    }
+}
+
+impl<F: PrimeField> Circuit<F> for CommitmentTransitionCircuit<F> {
+    type Config = TransitionConfig<F>;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        // Advice columns
+        let old_value = meta.advice_column();
+        let new_value = meta.advice_column();
+        let delta = meta.advice_column();
+        let increase = meta.advice_column();
+        let salt = meta.advice_column();
+        let commitment = meta.advice_column();
+
+        // Instance for public inputs
+        let instance = meta.instance_column();
+
+        // Enable equality
+        for col in [old_value, new_value, delta, increase, salt, commitment] {
+            meta.enable_equality(col);
+        }
+        meta.enable_equality(instance);
+
+        let q_transition = meta.selector();
+
+        // Transition gate:
+        //   increase is boolean
+        //   new_value = old_value + delta * (2 * increase - 1)
+        meta.create_gate("commitment transition", |meta| {
+            let q = meta.query_selector(q_transition);
+            let old_value = meta.query_advice(old_value, Rotation::cur());
+            let new_value = meta.query_advice(new_value, Rotation::cur());
+            let delta = meta.query_advice(delta, Rotation::cur());
+            let increase = meta.query_advice(increase, Rotation::cur());
+            let one = Expression::Constant(F::ONE);
+            let two = Expression::Constant(F::from(2u64));
+
+            vec![
+                q.clone() * increase.clone() * (one.clone() - increase.clone()),
+                q * (new_value - old_value - delta * (two * increase - one)),
+            ]
+        });
+
+        // Poseidon chip for both commitments
+        let poseidon = PoseidonChip::configure(meta, [old_value, salt, commitment]);
+
+        // Merkle path chip on existing columns ([cur, sibling, bit, left, right])
+        let merkle = MerkleChip::configure(meta, [new_value, delta, increase, salt, commitment]);
+
+        // Range check on a dedicated column
+        let range_value = meta.advice_column();
+        meta.enable_equality(range_value);
+        let range_check = RangeCheckChip::<F, LOOKUP_BITS>::configure(meta, range_value);
+
+        TransitionConfig {
+            old_value,
+            new_value,
+            delta,
+            increase,
+            salt,
+            commitment,
+            instance,
+            q_transition,
+            poseidon,
+            merkle,
+            range_check,
+            _marker: PhantomData,
+        }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        // The path length fixes the circuit shape, so it must match the keys
+        if self.old_siblings.len() != COMMITMENT_TREE_DEPTH {
+            return Err(Error::Synthesis);
+        }
+
+        // Load range check lookup table
+        let range_chip = RangeCheckChip::<F, LOOKUP_BITS>::construct(config.range_check.clone());
+        range_chip.load_table(layouter.namespace(|| "load table"))?;
+
+        // Main region: values, delta and direction
+        let (old_cell, new_cell, delta_cell, increase_cell) = layouter.assign_region(
+            || "transition computation",
+            |mut region| {
+                config.q_transition.enable(&mut region, 0)?;
+
+                let old_cell =
+                    region.assign_advice(|| "old_value", config.old_value, 0, || self.old_value)?;
+                let delta_cell = region.assign_advice(|| "delta", config.delta, 0, || self.delta)?;
+                let increase_cell =
+                    region.assign_advice(|| "increase", config.increase, 0, || self.increase)?;
+
+                // new_value = old_value ± delta
+                let new_val = self
+                    .old_value
+                    .zip(self.delta)
+                    .zip(self.increase)
+                    .map(|((v, d), inc)| v + d * (inc + inc - F::ONE));
+                let new_cell = region.assign_advice(|| "new_value", config.new_value, 0, || new_val)?;
+
+                Ok((old_cell, new_cell, delta_cell, increase_cell))
+            },
+        )?;
+
+        // Salts
+        let (old_salt_cell, new_salt_cell) = layouter.assign_region(
+            || "assign salts",
+            |mut region| {
+                let old_salt = region.assign_advice(|| "old_salt", config.salt, 0, || self.old_salt)?;
+                let new_salt = region.assign_advice(|| "new_salt", config.salt, 1, || self.new_salt)?;
+                Ok((old_salt, new_salt))
+            },
+        )?;
+
+        // Commitments = Poseidon(value, salt)
+        let poseidon_chip = PoseidonChip::construct(config.poseidon.clone());
+        let old_comm_cell = poseidon_chip.hash(
+            layouter.namespace(|| "old commitment"),
+            old_cell.clone(),
+            old_salt_cell.clone(),
+        )?;
+        let new_comm_cell = poseidon_chip.hash(
+            layouter.namespace(|| "new commitment"),
+            new_cell.clone(),
+            new_salt_cell,
+        )?;
+
+        // nullifier = Poseidon(old_salt, old_commitment), reusing the salt cell
+        // that opened the old commitment
+        let nullifier_cell = poseidon_chip.nullifier(
+            layouter.namespace(|| "nullifier"),
+            old_salt_cell,
+            old_comm_cell.clone(),
+        )?;
+
+        // commitment_root = MerkleRoot(old_commitment, path)
+        let merkle_chip = MerkleChip::construct(config.merkle.clone());
+        let root_cell = merkle_chip.root(
+            layouter.namespace(|| "old commitment membership"),
+            old_comm_cell,
+            &self.old_siblings,
+            &self.old_path_bits,
+        )?;
+
+        // Constrain public inputs
+        // instance[0] = commitment_root
+        // instance[1] = new_commitment
+        // instance[2] = delta
+        // instance[3] = increase
+        // instance[4] = nullifier
+        layouter.constrain_instance(root_cell.cell(), config.instance, 0)?;
+        layouter.constrain_instance(new_comm_cell.cell(), config.instance, 1)?;
+        layouter.constrain_instance(delta_cell.cell(), config.instance, 2)?;
+        layouter.constrain_instance(increase_cell.cell(), config.instance, 3)?;
+        layouter.constrain_instance(nullifier_cell.cell(), config.instance, 4)?;
+
+        // With old_value and delta bounded, a 128-bit new_value rules out both
+        // underflow (repaying more than owed) and overflow past the u128 range
+        range_chip.check(layouter.namespace(|| "old value range"), old_cell, RANGE_BITS)?;
+        range_chip.check(layouter.namespace(|| "delta range"), delta_cell, RANGE_BITS)?;
+        range_chip.check(layouter.namespace(|| "new value range"), new_cell, RANGE_BITS)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gadgets::merkle::IncrementalMerkleTree;
+    use crate::gadgets::poseidon::{compute_commitment, compute_nullifier};
+    use ff::Field;
+    use pasta_curves::Fp;
+    use halo2_proofs::dev::MockProver;
+
+    const OLD_SALT: u64 = 11111;
+    const NEW_SALT: u64 = 22222;
+
+    /// Commitment tree holding a few other commitments and then `commitment`
+    fn registered_tree(commitment: Fp) -> (IncrementalMerkleTree<Fp>, MerklePath<Fp>) {
+        let mut tree = IncrementalMerkleTree::new(COMMITMENT_TREE_DEPTH).unwrap();
+        for i in 0..3u64 {
+            tree.insert(compute_commitment(Fp::from(1000 + i), Fp::from(44444u64))).unwrap();
+        }
+        let index = tree.insert(commitment).unwrap();
+        let path = tree.path(index).unwrap();
+        (tree, path)
+    }
+
+    fn create_transition_circuit(
+        old_value: u128,
+        delta: u128,
+        increase: bool,
+    ) -> (CommitmentTransitionCircuit<Fp>, Vec<Fp>) {
+        let old_fp = Fp::from_u128(old_value);
+        let delta_fp = Fp::from_u128(delta);
+        let (old_salt, new_salt) = (Fp::from(OLD_SALT), Fp::from(NEW_SALT));
+
+        let new_fp = CommitmentTransitionCircuit::apply_delta(old_fp, delta_fp, increase);
+        let old_commitment = compute_commitment(old_fp, old_salt);
+        let new_commitment = compute_commitment(new_fp, new_salt);
+
+        let nullifier = compute_nullifier(old_salt, old_commitment);
+        let (tree, old_path) = registered_tree(old_commitment);
+
+        let circuit = CommitmentTransitionCircuit::new(old_fp, old_salt, &old_path, new_salt, delta_fp, increase);
+        let public_inputs = vec![
+            tree.root(),
+            new_commitment,
+            delta_fp,
+            CommitmentTransitionCircuit::direction(increase),
+            nullifier,
+        ];
+
+        (circuit, public_inputs)
+    }
+
+    #[test]
+    fn test_transition_top_up() {
+        let k = 17;
+
+        // Deposit top-up: 100 + 50 = 150
+        let (circuit, public_inputs) = create_transition_circuit(100, 50, true);
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert_eq!(prover.verify(), Ok(()), "Top-up should pass");
+    }
+
+    #[test]
+    fn test_transition_repayment() {
+        let k = 17;
+
+        // Partial repayment: 100 - 40 = 60
+        let (circuit, public_inputs) = create_transition_circuit(100, 40, false);
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert_eq!(prover.verify(), Ok(()), "Repayment should pass");
+
+        // Full repayment down to zero
+        let (circuit, public_inputs) = create_transition_circuit(100, 100, false);
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert_eq!(prover.verify(), Ok(()), "Full repayment should pass");
+    }
+
+    #[test]
+    fn test_transition_wei_amounts() {
+        let k = 17;
+
+        let eth = 1_000_000_000_000_000_000u128;
+        let (circuit, public_inputs) = create_transition_circuit(10 * eth, 3 * eth, true);
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert_eq!(prover.verify(), Ok(()), "Wei amounts should work");
+    }
+
+    #[test]
+    fn test_transition_underflow() {
+        let k = 17;
+
+        // 100 - 101 wraps to p - 1
+        let (circuit, public_inputs) = create_transition_circuit(100, 101, false);
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err(), "Decrease below zero should fail");
+    }
+
+    #[test]
+    fn test_transition_overflow() {
+        let k = 17;
+
+        // u128::MAX + 1 no longer fits in 128 bits
+        let (circuit, public_inputs) = create_transition_circuit(u128::MAX, 1, true);
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err(), "Increase past u128 should fail");
+    }
+
+    #[test]
+    fn test_transition_wrong_direction() {
+        let k = 17;
+
+        // Proof for a top-up presented as a repayment
+        let (circuit, mut public_inputs) = create_transition_circuit(100, 50, true);
+        public_inputs[3] = Fp::ZERO;
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err(), "Mismatched direction should fail");
+    }
+
+    #[test]
+    fn test_transition_non_boolean_direction() {
+        let k = 17;
+
+        // increase = 2 would mean new = old + 3 * delta
+        let old_fp = Fp::from(100u64);
+        let delta_fp = Fp::from(10u64);
+        let increase = Fp::from(2u64);
+        let (old_salt, new_salt) = (Fp::from(OLD_SALT), Fp::from(NEW_SALT));
+        let old_commitment = compute_commitment(old_fp, old_salt);
+        let (tree, old_path) = registered_tree(old_commitment);
+
+        let circuit = CommitmentTransitionCircuit {
+            increase: Value::known(increase),
+            ..CommitmentTransitionCircuit::new(old_fp, old_salt, &old_path, new_salt, delta_fp, true)
+        };
+        let public_inputs = vec![
+            tree.root(),
+            compute_commitment(Fp::from(130u64), new_salt),
+            delta_fp,
+            increase,
+            compute_nullifier(old_salt, old_commitment),
+        ];
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err(), "Non-boolean direction should fail");
+    }
+
+    #[test]
+    fn test_transition_wrong_delta() {
+        let k = 17;
+
+        let (circuit, mut public_inputs) = create_transition_circuit(100, 50, true);
+        public_inputs[2] = Fp::from(60u64);
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err(), "Mismatched delta should fail");
+    }
+
+    #[test]
+    fn test_transition_wrong_new_commitment() {
+        let k = 17;
+
+        // New commitment to more than old + delta
+        let (circuit, mut public_inputs) = create_transition_circuit(100, 50, true);
+        public_inputs[1] =
+            compute_commitment(Fp::from(200u64), Fp::from(NEW_SALT));
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err(), "Wrong new commitment should fail");
+    }
+
+    #[test]
+    fn test_transition_wrong_old_salt() {
+        let k = 17;
+
+        let (mut circuit, public_inputs) = create_transition_circuit(100, 50, true);
+        circuit.old_salt = Value::known(Fp::from(99999u64));
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err(), "Wrong old salt should fail");
+    }
+
+    #[test]
+    fn test_transition_wrong_root() {
+        let k = 17;
+
+        // The old commitment is proven against the registered root, not revealed
+        let (circuit, mut public_inputs) = create_transition_circuit(100, 50, true);
+        public_inputs[0] = compute_commitment(Fp::from(100u64), Fp::from(OLD_SALT));
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err(), "Old commitment in place of the root should fail");
+    }
+
+    #[test]
+    fn test_transition_unregistered_commitment() {
+        let k = 17;
+
+        // Tree of other commitments only: no path opens the old commitment
+        let mut tree = IncrementalMerkleTree::new(COMMITMENT_TREE_DEPTH).unwrap();
+        for i in 0..3u64 {
+            tree.insert(compute_commitment(Fp::from(1000 + i), Fp::from(44444u64))).unwrap();
+        }
+        let other_path = tree.path(0).unwrap();
+
+        let (mut circuit, mut public_inputs) = create_transition_circuit(100, 50, true);
+        circuit.old_siblings = other_path.siblings.iter().map(|s| Value::known(*s)).collect();
+        circuit.old_path_bits = other_path.path_bits_as_field().into_iter().map(Value::known).collect();
+        public_inputs[0] = tree.root();
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err(), "Unregistered old commitment should fail");
+    }
+
+    #[test]
+    fn test_transition_wrong_nullifier() {
+        let k = 17;
+
+        let (circuit, mut public_inputs) = create_transition_circuit(100, 50, true);
+        public_inputs[4] = Fp::from(12345u64);
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err(), "Unrelated nullifier should fail");
+    }
+
+    #[test]
+    fn test_transition_second_nullifier_key_fails() {
+        let k = 17;
+
+        // A second nullifier for the same commitment needs a second salt that
+        // still opens it, which binding rules out
+        let (mut circuit, mut public_inputs) = create_transition_circuit(100, 50, true);
+        let other_salt = Fp::from(99999u64);
+        circuit.old_salt = Value::known(other_salt);
+        let old_commitment = compute_commitment(Fp::from(100u64), Fp::from(OLD_SALT));
+        public_inputs[4] = compute_nullifier(other_salt, old_commitment);
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err(), "Second nullifier for the same commitment should fail");
+    }
+}
@@ -7,18 +7,61 @@
 //! import init, { compute_commitment, get_circuit_info } from 'zk-private-lending-circuits';
 //!
 //! await init();
-//! const commitment = compute_commitment(amount, salt);
+//! // Must be the API server's PROVING_BACKEND: "ipa" hashes over Pasta Fp,
+//! // "kzg-shplonk" / "kzg-gwc" over BN254 Fr
+//! const backend = (await fetch('/proof/params', { method: 'HEAD' })).headers.get('x-proving-backend');
+//! const commitment = compute_commitment(backend, amount, salt);
 //! console.log(get_circuit_info());
 //! ```
 //!
-//! Note: Full proof generation requires proving keys which are not included in WASM.
-//! For production, use the API server for proof generation.
+//! Most proofs are generated by the API server. Health factor bucket proofs are
+//! generated here instead, so the exact health factor never leaves the browser:
+//!
+//! ```javascript
+//! import init, { prove_health_factor_bucket } from 'zk-private-lending-circuits';
+//!
+//! await init();
+//! // SRS of the server's backend, named by the `x-proving-backend` header
+//! const response = await fetch('/proof/params');
+//! const backend = response.headers.get('x-proving-backend');
+//! const params = new Uint8Array(await response.arrayBuffer());
+//! const proof = JSON.parse(prove_health_factor_bucket(
+//!   backend, params, collateral, debt, collateralSalt, debtSalt, price, '80', '10000', '12000',
+//! ));
+//! await fetch('/proof/health-factor/verify', { method: 'POST', body: JSON.stringify({ address, ...proof }) });
+//! ```
 
 #[cfg(feature = "wasm")]
 use wasm_bindgen::prelude::*;
 
 #[cfg(feature = "wasm")]
-use crate::{CollateralCircuit, LTVCircuit, LiquidationCircuit};
+use crate::transcript::EvmTranscript;
+#[cfg(feature = "wasm")]
+use ff::PrimeField;
+#[cfg(feature = "wasm")]
+use halo2_proofs::{
+    halo2curves::bn256::{Bn256, Fr, G1Affine},
+    plonk::{create_proof, keygen_pk, keygen_vk, Circuit},
+    poly::{
+        commitment::Params,
+        ipa::{
+            commitment::{IPACommitmentScheme, ParamsIPA},
+            multiopen::ProverIPA,
+        },
+        kzg::{
+            commitment::{KZGCommitmentScheme, ParamsKZG},
+            multiopen::{ProverGWC, ProverSHPLONK},
+        },
+    },
+    transcript::{Blake2bWrite, Challenge255, TranscriptWriterBuffer},
+};
+#[cfg(feature = "wasm")]
+use pasta_curves::{EqAffine, Fp};
+#[cfg(feature = "wasm")]
+use rand::rngs::OsRng;
+
+#[cfg(feature = "wasm")]
+use crate::{HealthFactorBucketCircuit, LiquidationCircuit};
 
 /// Initialize WASM module with panic hook for better error messages
 #[cfg(feature = "wasm")]
@@ -120,35 +163,291 @@ pub fn is_liquidatable(
 
 /// Compute commitment hash
 ///
-/// Uses the same commitment formula as the circuits:
-/// commitment = amount * salt + amount
+/// Uses the same commitment as every circuit and the API server:
+/// commitment = Poseidon(amount, salt), over the field of `backend`.
 ///
-/// Note: This is a simplified commitment for demonstration.
-/// Production would use Poseidon hash.
+/// The IPA backend hashes over Pasta Fp and the KZG backends over BN254 Fr, so
+/// the same inputs give different commitments. A deployment must pass the
+/// backend of its API server (`PROVING_BACKEND`, also sent as the
+/// `x-proving-backend` header of `GET /proof/params`); commitments computed for
+/// another backend never match the registry or open in the server's proofs.
 ///
 /// # Arguments
+/// * `backend` - Server backend: "ipa", "kzg-shplonk" or "kzg-gwc"
 /// * `amount` - Amount as string
 /// * `salt` - Random salt as string
 ///
 /// # Returns
-/// Commitment value as string
+/// Commitment as 0x-prefixed hex of its little-endian repr, the encoding of
+/// the server's `POST /commitment/create`
 #[cfg(feature = "wasm")]
 #[wasm_bindgen]
-pub fn compute_commitment(amount: &str, salt: &str) -> Result<String, JsError> {
-    use pasta_curves::Fp;
+pub fn compute_commitment(backend: &str, amount: &str, salt: &str) -> Result<String, JsError> {
+    let backend = Backend::parse(backend)?;
+    let amount = parse_u128(amount, "amount")?;
+    let salt = parse_u128(salt, "salt")?;
 
-    let amount_value: u64 = amount.parse()
-        .map_err(|_| JsError::new("Invalid amount"))?;
-    let salt_value: u64 = salt.parse()
-        .map_err(|_| JsError::new("Invalid salt"))?;
+    let commitment = match backend {
+        Backend::Ipa => encode_field(crate::compute_commitment(Fp::from_u128(amount), Fp::from_u128(salt))),
+        Backend::KzgShplonk | Backend::KzgGwc => {
+            encode_field(crate::compute_commitment(Fr::from_u128(amount), Fr::from_u128(salt)))
+        }
+    };
+
+    Ok(commitment)
+}
+
+/// Proving backend of the API server (`PROVING_BACKEND`)
+///
+/// Circuits run over a different field per backend, so proofs and commitments
+/// only verify against a server configured with the same backend.
+#[cfg(feature = "wasm")]
+#[derive(Debug, Clone, Copy)]
+enum Backend {
+    /// IPA over Pasta (Vesta), Blake2b transcript
+    Ipa,
+    /// KZG over BN254, SHPLONK multiopen, Keccak256 transcript in the EVM encoding
+    KzgShplonk,
+    /// KZG over BN254, GWC multiopen, Keccak256 transcript in the EVM encoding
+    KzgGwc,
+}
+
+#[cfg(feature = "wasm")]
+impl Backend {
+    /// Parse the server's backend name (same spelling as `PROVING_BACKEND`)
+    fn parse(backend: &str) -> Result<Self, JsError> {
+        match backend.to_lowercase().as_str() {
+            "ipa" => Ok(Backend::Ipa),
+            "kzg-shplonk" | "kzg" => Ok(Backend::KzgShplonk),
+            "kzg-gwc" => Ok(Backend::KzgGwc),
+            _ => Err(JsError::new("Unknown backend (ipa | kzg-shplonk | kzg-gwc)")),
+        }
+    }
+}
+
+/// Prove that a committed position's health factor is in `[lo, hi)`
+///
+/// Runs entirely in the browser: the server only receives the proof and its
+/// public inputs (bucket, price, threshold and both commitments), and
+/// registers the liquidation warning via `POST /proof/health-factor/verify`.
+///
+/// # Arguments
+/// * `backend` - Server backend: "ipa", "kzg-shplonk" or "kzg-gwc"
+/// * `params` - Server SRS bytes from `GET /proof/params`; the proving key is
+///   derived from it, so it matches the server's verifying key
+/// * `collateral`, `debt` - Position amounts as strings
+/// * `collateral_salt`, `debt_salt` - Salts of the position's commitments
+/// * `price` - Oracle price (8 decimals); the server rejects proofs more than
+///   1% away from its oracle
+/// * `liquidation_threshold` - The protocol's liquidation threshold ("80")
+/// * `lo`, `hi` - Bucket bounds in basis points (1.0 = "10000")
+///
+/// # Returns
+/// JSON `{ "proof": "0x...", "public_inputs": ["0x...", ...] }`, with public
+/// inputs in the server's `fp_to_hex` encoding
+#[cfg(feature = "wasm")]
+#[wasm_bindgen]
+#[allow(clippy::too_many_arguments)]
+pub fn prove_health_factor_bucket(
+    backend: &str,
+    params: &[u8],
+    collateral: &str,
+    debt: &str,
+    collateral_salt: &str,
+    debt_salt: &str,
+    price: &str,
+    liquidation_threshold: &str,
+    lo: &str,
+    hi: &str,
+) -> Result<String, JsError> {
+    let backend = Backend::parse(backend)?;
+    let bucket = BucketInputs {
+        collateral: parse_u128(collateral, "collateral")?,
+        debt: parse_u128(debt, "debt")?,
+        collateral_salt: parse_u128(collateral_salt, "collateral salt")?,
+        debt_salt: parse_u128(debt_salt, "debt salt")?,
+        price: parse_u64(price, "price")?,
+        liquidation_threshold: parse_u64(liquidation_threshold, "liquidation threshold")?,
+        lo: parse_u64(lo, "lo")?,
+        hi: parse_u64(hi, "hi")?,
+    };
+
+    // create_proof does not check constraints, so reject inputs that would
+    // only produce a proof the server rejects
+    if bucket.liquidation_threshold == 0 || bucket.liquidation_threshold > 100 {
+        return Err(JsError::new("Liquidation threshold must be between 1 and 100"));
+    }
+    if bucket.price == 0 {
+        return Err(JsError::new("Price must be positive"));
+    }
+    let health_factor = HealthFactorBucketCircuit::<Fp>::health_factor(
+        bucket.collateral,
+        bucket.debt,
+        bucket.price,
+        bucket.liquidation_threshold,
+    );
+    match health_factor {
+        Some(hf) if (bucket.lo as u128) <= hf && hf < bucket.hi as u128 => {}
+        _ => return Err(JsError::new("Health factor is not in the bucket")),
+    }
+
+    let (proof, public_inputs) = match backend {
+        Backend::Ipa => {
+            let (circuit, instance) = bucket.circuit::<Fp>();
+            (prove_ipa(params, circuit, &instance)?, encode_instance(&instance))
+        }
+        Backend::KzgShplonk | Backend::KzgGwc => {
+            let (circuit, instance) = bucket.circuit::<Fr>();
+            (prove_kzg(backend, params, circuit, &instance)?, encode_instance(&instance))
+        }
+    };
 
-    let amount_fp = Fp::from(amount_value);
-    let salt_fp = Fp::from(salt_value);
+    let response = serde_json::json!({
+        "proof": format!("0x{}", encode_hex(&proof)),
+        "public_inputs": public_inputs,
+    });
+    Ok(response.to_string())
+}
+
+/// Private and public values of a health factor bucket proof
+#[cfg(feature = "wasm")]
+struct BucketInputs {
+    collateral: u128,
+    debt: u128,
+    collateral_salt: u128,
+    debt_salt: u128,
+    price: u64,
+    liquidation_threshold: u64,
+    lo: u64,
+    hi: u64,
+}
+
+#[cfg(feature = "wasm")]
+impl BucketInputs {
+    /// Circuit and public inputs, in the order the server verifies them:
+    /// `[lo, hi, price, liquidation_threshold, collateral_commitment, debt_commitment]`
+    fn circuit<F: PrimeField>(&self) -> (HealthFactorBucketCircuit<F>, Vec<F>) {
+        let collateral = F::from_u128(self.collateral);
+        let debt = F::from_u128(self.debt);
+        let collateral_salt = F::from_u128(self.collateral_salt);
+        let debt_salt = F::from_u128(self.debt_salt);
+        let price = F::from(self.price);
+        let liquidation_threshold = F::from(self.liquidation_threshold);
+        let (lo, hi) = (F::from(self.lo), F::from(self.hi));
+
+        let circuit = HealthFactorBucketCircuit::new(
+            collateral,
+            debt,
+            collateral_salt,
+            debt_salt,
+            price,
+            liquidation_threshold,
+            lo,
+            hi,
+        );
+        let instance = vec![
+            lo,
+            hi,
+            price,
+            liquidation_threshold,
+            crate::compute_commitment(collateral, collateral_salt),
+            crate::compute_commitment(debt, debt_salt),
+        ];
+
+        (circuit, instance)
+    }
+}
+
+/// Generate keys from the server's IPA SRS and prove with a Blake2b transcript
+#[cfg(feature = "wasm")]
+fn prove_ipa<C: Circuit<Fp> + Default>(params: &[u8], circuit: C, instance: &[Fp]) -> Result<Vec<u8>, JsError> {
+    let params = ParamsIPA::<EqAffine>::read(&mut &params[..])
+        .map_err(|e| JsError::new(&format!("Invalid IPA params: {}", e)))?;
+    let vk = keygen_vk(&params, &C::default()).map_err(proof_error)?;
+    let pk = keygen_pk(&params, vk, &C::default()).map_err(proof_error)?;
+
+    let mut transcript = Blake2bWrite::<Vec<u8>, EqAffine, Challenge255<EqAffine>>::init(vec![]);
+    let instances: &[&[Fp]] = &[instance];
+    create_proof::<IPACommitmentScheme<EqAffine>, ProverIPA<'_, EqAffine>, _, _, _, _>(
+        &params,
+        &pk,
+        &[circuit],
+        &[instances],
+        OsRng,
+        &mut transcript,
+    )
+    .map_err(proof_error)?;
+
+    Ok(transcript.finalize())
+}
 
-    // Use the same formula as CollateralCircuit::compute_commitment
-    let commitment = CollateralCircuit::compute_commitment(amount_fp, salt_fp);
+/// Generate keys from the server's KZG SRS and prove with `EvmTranscript`, as the server does
+#[cfg(feature = "wasm")]
+fn prove_kzg<C: Circuit<Fr> + Default>(
+    backend: Backend,
+    params: &[u8],
+    circuit: C,
+    instance: &[Fr],
+) -> Result<Vec<u8>, JsError> {
+    let params = ParamsKZG::<Bn256>::read(&mut &params[..])
+        .map_err(|e| JsError::new(&format!("Invalid KZG params: {}", e)))?;
+    let vk = keygen_vk(&params, &C::default()).map_err(proof_error)?;
+    let pk = keygen_pk(&params, vk, &C::default()).map_err(proof_error)?;
 
-    Ok(format!("{:?}", commitment))
+    let mut transcript = EvmTranscript::<G1Affine, _>::new(vec![]);
+    let instances: &[&[Fr]] = &[instance];
+    match backend {
+        Backend::KzgGwc => create_proof::<KZGCommitmentScheme<Bn256>, ProverGWC<'_, Bn256>, _, _, _, _>(
+            &params,
+            &pk,
+            &[circuit],
+            &[instances],
+            OsRng,
+            &mut transcript,
+        ),
+        _ => create_proof::<KZGCommitmentScheme<Bn256>, ProverSHPLONK<'_, Bn256>, _, _, _, _>(
+            &params,
+            &pk,
+            &[circuit],
+            &[instances],
+            OsRng,
+            &mut transcript,
+        ),
+    }
+    .map_err(proof_error)?;
+
+    Ok(transcript.finalize())
+}
+
+#[cfg(feature = "wasm")]
+fn proof_error(e: halo2_proofs::plonk::Error) -> JsError {
+    JsError::new(&format!("Proof generation failed: {:?}", e))
+}
+
+#[cfg(feature = "wasm")]
+fn parse_u128(value: &str, name: &str) -> Result<u128, JsError> {
+    value.parse().map_err(|_| JsError::new(&format!("Invalid {}", name)))
+}
+
+#[cfg(feature = "wasm")]
+fn parse_u64(value: &str, name: &str) -> Result<u64, JsError> {
+    value.parse().map_err(|_| JsError::new(&format!("Invalid {}", name)))
+}
+
+/// Field elements as 0x-prefixed hex of their little-endian repr (the server's `fp_to_hex`)
+#[cfg(feature = "wasm")]
+fn encode_instance<F: PrimeField>(instance: &[F]) -> Vec<String> {
+    instance.iter().copied().map(encode_field).collect()
+}
+
+#[cfg(feature = "wasm")]
+fn encode_field<F: PrimeField>(value: F) -> String {
+    format!("0x{}", encode_hex(value.to_repr().as_ref()))
+}
+
+#[cfg(feature = "wasm")]
+fn encode_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
 }
 
 /// Get circuit parameters (for debugging/info)
@@ -162,22 +461,31 @@ pub fn get_circuit_info() -> JsValue {
         "version": env!("CARGO_PKG_VERSION"),
         "circuits": {
             "collateral": {
-                "description": "Proves knowledge of collateral amount matching commitment",
-                "public_inputs": ["commitment"],
-                "private_inputs": ["amount", "salt"]
+                "description": "Proves committed collateral is at least the threshold",
+                "public_inputs": ["threshold", "commitment"],
+                "private_inputs": ["collateral", "salt"]
             },
             "ltv": {
-                "description": "Proves LTV ratio is within bounds without revealing amounts",
-                "public_inputs": ["commitment", "max_ltv"],
-                "private_inputs": ["collateral", "salt", "borrow_amount", "eth_price"]
+                "description": "Proves the USD LTV ratio is within bounds without revealing amounts",
+                "public_inputs": ["max_ltv", "price", "collateral_decimals", "debt_decimals", "debt_commitment", "collateral_commitment"],
+                "private_inputs": ["collateral", "debt", "collateral_salt", "debt_salt"]
             },
             "liquidation": {
                 "description": "Proves position is liquidatable (health_factor < 1)",
-                "public_inputs": ["commitment", "threshold"],
-                "private_inputs": ["collateral", "salt", "debt", "eth_price"]
+                "public_inputs": ["price", "liquidation_threshold", "collateral_commitment", "debt_commitment"],
+                "private_inputs": ["collateral", "debt", "collateral_salt", "debt_salt"]
+            },
+            "health_factor": {
+                "description": "Proves the health factor is in a bucket [lo, hi), proven in the browser",
+                "public_inputs": ["lo", "hi", "price", "liquidation_threshold", "collateral_commitment", "debt_commitment"],
+                "private_inputs": ["collateral", "debt", "collateral_salt", "debt_salt"]
             }
         },
-        "curve": "Pasta (Pallas/Vesta)",
+        "backends": {
+            "ipa": "Pasta (Pallas/Vesta), commitments over Pasta Fp",
+            "kzg-shplonk": "BN254, commitments over BN254 Fr",
+            "kzg-gwc": "BN254, commitments over BN254 Fr"
+        },
         "proof_system": "Halo2 (PSE fork)"
     });
 
@@ -0,0 +1,671 @@
+//! WithdrawProof Circuit
+//!
+//! Proves that withdrawing `amount` from a committed collateral position leaves
+//! a valid, still-healthy position, without revealing the balances:
+//! `new_collateral = old_collateral - amount`, `new_collateral >= 0` and
+//! `debt * 100 <= new_collateral * max_ltv`
+//!
+//! # Public Inputs
+//! - `commitment_root`: Root of the registered commitment tree that holds the
+//!   position before withdrawal; the old commitment itself stays private
+//! - `amount`: Amount being withdrawn (the contract transfers it, so it is public)
+//! - `new_commitment`: Poseidon(new_collateral, new_salt) - position after withdrawal
+//! - `debt_commitment`: Poseidon(debt, debt_salt) - outstanding debt
+//! - `max_ltv`: Maximum allowed LTV ratio (e.g., 80 = 80%), constrained to 0 < max_ltv <= 100
+//! - `nullifier`: Poseidon(old_salt, old_commitment) - spends the old commitment once
+//!
+//! # Private Inputs
+//! - `old_collateral`: Collateral before withdrawal
+//! - `debt`: Borrowed amount
+//! - `old_salt`, `new_salt`, `debt_salt`: Salts for commitments
+//! - Merkle path of the old commitment (siblings and index bits,
+//!   `COMMITMENT_TREE_DEPTH` levels)
+//!
+//! # Constraints
+//! 1. `old_commitment = Poseidon(old_collateral, old_salt)` and hashing it up the
+//!    Merkle path gives `commitment_root`
+//! 2. `new_collateral = old_collateral - amount`
+//! 3. `new_collateral`, `amount`, `old_collateral`, `debt` fit in 128 bits
+//!    (so the subtraction cannot wrap: `amount <= old_collateral`)
+//! 4. `new_commitment == Poseidon(new_collateral, new_salt)`
+//! 5. `debt_commitment == Poseidon(debt, debt_salt)`
+//! 6. `new_collateral * max_ltv >= debt * 100`, with `1 <= max_ltv <= 100`
+//! 7. `nullifier == Poseidon(old_salt, old_commitment)`, with the same `old_salt`
+//!    that opens `old_commitment`, so each commitment has exactly one nullifier
+//!
+//! # Example
+//! - old collateral: 100 ETH, debt: 60 ETH, max_ltv: 80%
+//! - withdraw 20 ETH → new collateral 80 ETH, LTV = 75% <= 80% ✓
+//! - withdraw 30 ETH → new collateral 70 ETH, LTV ≈ 86% > 80% ✗
+//!
+//! # Circuit Statistics
+//! - Advice columns: 10 (amounts, salt, max_ltv, scaled values, commitment, diff;
+//!   the Merkle path reuses the balance columns)
+//! - Instance columns: 1 (public inputs)
+//! - Custom gates: withdraw arithmetic, Poseidon rounds, Merkle swap, comparison
+//! - Lookup tables: 1 (range check for comparison)
+//! - Estimated rows: ~2^17 (16-bit lookup table, four Poseidon hashes plus one
+//!   per tree level)
+
+use ff::PrimeField;
+use halo2_proofs::{
+    circuit::{Layouter, SimpleFloorPlanner, Value},
+    plonk::{Advice, Circuit, Column, ConstraintSystem, Error, Expression, Instance, Selector},
+    poly::Rotation,
+};
+use std::marker::PhantomData;
+
+use crate::constants::{COMMITMENT_TREE_DEPTH, LOOKUP_BITS, RANGE_BITS};
+use crate::gadgets::comparison::{ComparisonChip, ComparisonConfig, ComparisonInstruction};
+use crate::gadgets::merkle::{MerkleChip, MerkleConfig, MerklePath};
+use crate::gadgets::poseidon::{PoseidonChip, PoseidonConfig};
+
+/// Width of the scaled values compared (amount * percentage)
+const SCALED_BITS: usize = RANGE_BITS + LOOKUP_BITS;
+/// Largest valid max_ltv (100%)
+const MAX_LTV_LIMIT: u64 = LTV_PRECISION;
+const LTV_PRECISION: u64 = 100; // LTV in percentage (80 = 80%)
+
+/// Configuration for the WithdrawProof circuit
+#[derive(Debug, Clone)]
+pub struct WithdrawConfig<F: PrimeField> {
+    // Balances (private, except the withdrawn amount)
+    pub old_collateral: Column<Advice>,
+    pub amount: Column<Advice>,
+    pub new_collateral: Column<Advice>,
+    pub debt: Column<Advice>,
+
+    /// Salts for the three commitments (one per row)
+    pub salt: Column<Advice>,
+
+    // Public parameter (copied from instance)
+    pub max_ltv: Column<Advice>,
+
+    // Intermediate values
+    pub debt_scaled: Column<Advice>,        // debt * 100
+    pub collateral_scaled: Column<Advice>,  // new_collateral * max_ltv
+    /// Poseidon output column (third state column)
+    pub commitment: Column<Advice>,
+
+    // Public inputs
+    pub instance: Column<Instance>,
+
+    // Gates
+    pub q_withdraw: Selector,
+
+    /// Poseidon chip config (state = [old_collateral, salt, commitment])
+    pub poseidon: PoseidonConfig<F>,
+    /// Merkle path chip proving the old commitment is registered
+    pub merkle: MerkleConfig<F>,
+    /// Comparison for the post-withdrawal LTV check
+    pub comparison: ComparisonConfig<F, LOOKUP_BITS>,
+
+    _marker: PhantomData<F>,
+}
+
+/// WithdrawProof circuit
+#[derive(Clone)]
+pub struct WithdrawCircuit<F: PrimeField> {
+    /// Private: collateral before withdrawal
+    pub old_collateral: Value<F>,
+    /// Private: salt of the old collateral commitment
+    pub old_salt: Value<F>,
+    /// Private: siblings of the old commitment in the commitment tree (leaf level first)
+    pub old_siblings: Vec<Value<F>>,
+    /// Private: index bits of the old commitment (1 = right child)
+    pub old_path_bits: Vec<Value<F>>,
+    /// Private: salt of the new collateral commitment
+    pub new_salt: Value<F>,
+    /// Private: outstanding debt
+    pub debt: Value<F>,
+    /// Private: salt of the debt commitment
+    pub debt_salt: Value<F>,
+    /// Public: amount withdrawn (passed via instance)
+    pub amount: Value<F>,
+    /// Public: maximum LTV percentage (passed via instance)
+    pub max_ltv: Value<F>,
+}
+
+impl<F: PrimeField> Default for WithdrawCircuit<F> {
+    fn default() -> Self {
+        Self {
+            old_collateral: Value::unknown(),
+            old_salt: Value::unknown(),
+            old_siblings: vec![Value::unknown(); COMMITMENT_TREE_DEPTH],
+            old_path_bits: vec![Value::unknown(); COMMITMENT_TREE_DEPTH],
+            new_salt: Value::unknown(),
+            debt: Value::unknown(),
+            debt_salt: Value::unknown(),
+            amount: Value::unknown(),
+            max_ltv: Value::unknown(),
+        }
+    }
+}
+
+impl<F: PrimeField> WithdrawCircuit<F> {
+    /// Create a new circuit with the given values
+    ///
+    /// `old_path` is the path of the old commitment in the commitment tree and
+    /// must have `COMMITMENT_TREE_DEPTH` levels.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        old_collateral: F,
+        old_salt: F,
+        old_path: &MerklePath<F>,
+        new_salt: F,
+        debt: F,
+        debt_salt: F,
+        amount: F,
+        max_ltv: F,
+    ) -> Self {
+        Self {
+            old_collateral: Value::known(old_collateral),
+            old_salt: Value::known(old_salt),
+            old_siblings: old_path.siblings.iter().map(|s| Value::known(*s)).collect(),
+            old_path_bits: old_path.path_bits_as_field().into_iter().map(Value::known).collect(),
+            new_salt: Value::known(new_salt),
+            debt: Value::known(debt),
+            debt_salt: Value::known(debt_salt),
+            amount: Value::known(amount),
+            max_ltv: Value::known(max_ltv),
+        }
+    }
+}
+
+impl<F: PrimeField> Circuit<F> for WithdrawCircuit<F> {
+    type Config = WithdrawConfig<F>;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        // Advice columns
+        let old_collateral = meta.advice_column();
+        let amount = meta.advice_column();
+        let new_collateral = meta.advice_column();
+        let debt = meta.advice_column();
+        let salt = meta.advice_column();
+        let max_ltv = meta.advice_column();
+        let debt_scaled = meta.advice_column();
+        let collateral_scaled = meta.advice_column();
+        let commitment = meta.advice_column();
+
+        // Instance for public inputs
+        let instance = meta.instance_column();
+
+        // Enable equality
+        for col in [
+            old_collateral,
+            amount,
+            new_collateral,
+            debt,
+            salt,
+            max_ltv,
+            debt_scaled,
+            collateral_scaled,
+            commitment,
+        ] {
+            meta.enable_equality(col);
+        }
+        meta.enable_equality(instance);
+
+        let q_withdraw = meta.selector();
+
+        // Withdraw gate:
+        //   new_collateral = old_collateral - amount
+        //   debt_scaled = debt * 100
+        //   collateral_scaled = new_collateral * max_ltv
+        meta.create_gate("withdraw", |meta| {
+            let q = meta.query_selector(q_withdraw);
+            let old_collateral = meta.query_advice(old_collateral, Rotation::cur());
+            let amount = meta.query_advice(amount, Rotation::cur());
+            let new_collateral = meta.query_advice(new_collateral, Rotation::cur());
+            let debt = meta.query_advice(debt, Rotation::cur());
+            let max_ltv = meta.query_advice(max_ltv, Rotation::cur());
+            let debt_scaled = meta.query_advice(debt_scaled, Rotation::cur());
+            let collateral_scaled = meta.query_advice(collateral_scaled, Rotation::cur());
+            let precision = Expression::Constant(F::from(LTV_PRECISION));
+
+            vec![
+                q.clone() * (new_collateral.clone() - (old_collateral - amount)),
+                q.clone() * (debt_scaled - debt * precision),
+                q * (collateral_scaled - new_collateral * max_ltv),
+            ]
+        });
+
+        // Poseidon chip for all three commitments
+        let poseidon = PoseidonChip::configure(meta, [old_collateral, salt, commitment]);
+
+        // Merkle path chip on existing columns ([cur, sibling, bit, left, right])
+        let merkle = MerkleChip::configure(meta, [old_collateral, amount, new_collateral, debt, max_ltv]);
+
+        // Comparison config for the LTV check
+        let diff = meta.advice_column();
+        meta.enable_equality(diff);
+        let comparison = ComparisonChip::<F, LOOKUP_BITS>::configure_with_range_bits(
+            meta,
+            collateral_scaled,  // a = new_collateral * max_ltv
+            debt_scaled,        // b = debt * 100
+            diff,
+            SCALED_BITS,
+        );
+
+        WithdrawConfig {
+            old_collateral,
+            amount,
+            new_collateral,
+            debt,
+            salt,
+            max_ltv,
+            debt_scaled,
+            collateral_scaled,
+            commitment,
+            instance,
+            q_withdraw,
+            poseidon,
+            merkle,
+            comparison,
+            _marker: PhantomData,
+        }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        // The path length fixes the circuit shape, so it must match the keys
+        if self.old_siblings.len() != COMMITMENT_TREE_DEPTH {
+            return Err(Error::Synthesis);
+        }
+
+        // Load comparison lookup table
+        let comparison_chip = ComparisonChip::<F, LOOKUP_BITS>::construct(config.comparison.clone());
+        comparison_chip.load_table(layouter.namespace(|| "load table"))?;
+
+        // Main region: balances and scaled amounts
+        let (old_cell, amount_cell, new_cell, debt_cell, max_ltv_cell, debt_scaled_cell, collateral_scaled_cell) =
+            layouter.assign_region(
+                || "withdraw computation",
+                |mut region| {
+                    config.q_withdraw.enable(&mut region, 0)?;
+
+                    let old_cell = region.assign_advice(
+                        || "old_collateral",
+                        config.old_collateral,
+                        0,
+                        || self.old_collateral,
+                    )?;
+                    let amount_cell = region.assign_advice(|| "amount", config.amount, 0, || self.amount)?;
+
+                    // new_collateral = old_collateral - amount
+                    let new_val = self.old_collateral.zip(self.amount).map(|(c, a)| c - a);
+                    let new_cell =
+                        region.assign_advice(|| "new_collateral", config.new_collateral, 0, || new_val)?;
+
+                    let debt_cell = region.assign_advice(|| "debt", config.debt, 0, || self.debt)?;
+                    let max_ltv_cell = region.assign_advice(|| "max_ltv", config.max_ltv, 0, || self.max_ltv)?;
+
+                    // debt_scaled = debt * 100
+                    let debt_scaled_val = self.debt.map(|d| d * F::from(LTV_PRECISION));
+                    let debt_scaled_cell = region.assign_advice(
+                        || "debt_scaled",
+                        config.debt_scaled,
+                        0,
+                        || debt_scaled_val,
+                    )?;
+
+                    // collateral_scaled = new_collateral * max_ltv
+                    let collateral_scaled_val = new_val.zip(self.max_ltv).map(|(c, ltv)| c * ltv);
+                    let collateral_scaled_cell = region.assign_advice(
+                        || "collateral_scaled",
+                        config.collateral_scaled,
+                        0,
+                        || collateral_scaled_val,
+                    )?;
+
+                    Ok((
+                        old_cell,
+                        amount_cell,
+                        new_cell,
+                        debt_cell,
+                        max_ltv_cell,
+                        debt_scaled_cell,
+                        collateral_scaled_cell,
+                    ))
+                },
+            )?;
+
+        // Salts
+        let (old_salt_cell, new_salt_cell, debt_salt_cell) = layouter.assign_region(
+            || "assign salts",
+            |mut region| {
+                let old_salt = region.assign_advice(|| "old_salt", config.salt, 0, || self.old_salt)?;
+                let new_salt = region.assign_advice(|| "new_salt", config.salt, 1, || self.new_salt)?;
+                let debt_salt = region.assign_advice(|| "debt_salt", config.salt, 2, || self.debt_salt)?;
+                Ok((old_salt, new_salt, debt_salt))
+            },
+        )?;
+
+        // Commitments = Poseidon(value, salt)
+        let poseidon_chip = PoseidonChip::construct(config.poseidon.clone());
+        let old_comm_cell = poseidon_chip.hash(
+            layouter.namespace(|| "old collateral commitment"),
+            old_cell.clone(),
+            old_salt_cell.clone(),
+        )?;
+        let new_comm_cell = poseidon_chip.hash(
+            layouter.namespace(|| "new collateral commitment"),
+            new_cell.clone(),
+            new_salt_cell,
+        )?;
+        let debt_comm_cell = poseidon_chip.hash(
+            layouter.namespace(|| "debt commitment"),
+            debt_cell.clone(),
+            debt_salt_cell,
+        )?;
+
+        // nullifier = Poseidon(old_salt, old_commitment), reusing the salt cell
+        // that opened the old commitment
+        let nullifier_cell = poseidon_chip.nullifier(
+            layouter.namespace(|| "nullifier"),
+            old_salt_cell,
+            old_comm_cell.clone(),
+        )?;
+
+        // commitment_root = MerkleRoot(old_commitment, path)
+        let merkle_chip = MerkleChip::construct(config.merkle.clone());
+        let root_cell = merkle_chip.root(
+            layouter.namespace(|| "old commitment membership"),
+            old_comm_cell,
+            &self.old_siblings,
+            &self.old_path_bits,
+        )?;
+
+        // Constrain public inputs
+        // instance[0] = commitment_root
+        // instance[1] = amount
+        // instance[2] = new_commitment
+        // instance[3] = debt_commitment
+        // instance[4] = max_ltv
+        // instance[5] = nullifier
+        layouter.constrain_instance(root_cell.cell(), config.instance, 0)?;
+        layouter.constrain_instance(amount_cell.cell(), config.instance, 1)?;
+        layouter.constrain_instance(new_comm_cell.cell(), config.instance, 2)?;
+        layouter.constrain_instance(debt_comm_cell.cell(), config.instance, 3)?;
+        layouter.constrain_instance(max_ltv_cell.cell(), config.instance, 4)?;
+        layouter.constrain_instance(nullifier_cell.cell(), config.instance, 5)?;
+
+        // Public parameter bound: 0 < max_ltv <= 100
+        comparison_chip.in_range(
+            layouter.namespace(|| "max_ltv bounds"),
+            max_ltv_cell,
+            F::ONE,
+            F::from(MAX_LTV_LIMIT),
+        )?;
+
+        // All amounts stay in range; with old and amount bounded, a 128-bit
+        // new_collateral means the subtraction did not wrap (new >= 0)
+        comparison_chip.range_check(layouter.namespace(|| "old collateral range"), old_cell, RANGE_BITS)?;
+        comparison_chip.range_check(layouter.namespace(|| "amount range"), amount_cell, RANGE_BITS)?;
+        comparison_chip.range_check(layouter.namespace(|| "new collateral range"), new_cell, RANGE_BITS)?;
+        comparison_chip.range_check(layouter.namespace(|| "debt range"), debt_cell, RANGE_BITS)?;
+
+        // LTV check: new_collateral * max_ltv >= debt * 100
+        comparison_chip.gte(
+            layouter.namespace(|| "LTV check"),
+            collateral_scaled_cell,
+            debt_scaled_cell,
+        )?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gadgets::merkle::IncrementalMerkleTree;
+    use crate::gadgets::poseidon::{compute_commitment, compute_nullifier};
+    use pasta_curves::Fp;
+    use halo2_proofs::dev::MockProver;
+
+    const OLD_SALT: u64 = 11111;
+    const NEW_SALT: u64 = 22222;
+    const DEBT_SALT: u64 = 33333;
+
+    /// Commitment tree holding a few other positions and then `commitment`
+    fn registered_tree(commitment: Fp) -> (IncrementalMerkleTree<Fp>, MerklePath<Fp>) {
+        let mut tree = IncrementalMerkleTree::new(COMMITMENT_TREE_DEPTH).unwrap();
+        for i in 0..3u64 {
+            tree.insert(compute_commitment(Fp::from(1000 + i), Fp::from(44444u64))).unwrap();
+        }
+        let index = tree.insert(commitment).unwrap();
+        let path = tree.path(index).unwrap();
+        (tree, path)
+    }
+
+    fn create_withdraw_circuit(
+        old_collateral: u128,
+        amount: u128,
+        debt: u128,
+        max_ltv: u64,
+    ) -> (WithdrawCircuit<Fp>, Vec<Fp>) {
+        let old_fp = Fp::from_u128(old_collateral);
+        let amount_fp = Fp::from_u128(amount);
+        let debt_fp = Fp::from_u128(debt);
+        let max_ltv_fp = Fp::from(max_ltv);
+        let (old_salt, new_salt, debt_salt) =
+            (Fp::from(OLD_SALT), Fp::from(NEW_SALT), Fp::from(DEBT_SALT));
+
+        let old_commitment = compute_commitment(old_fp, old_salt);
+        let new_commitment = compute_commitment(old_fp - amount_fp, new_salt);
+        let debt_commitment = compute_commitment(debt_fp, debt_salt);
+        let nullifier = compute_nullifier(old_salt, old_commitment);
+        let (tree, old_path) = registered_tree(old_commitment);
+
+        let circuit = WithdrawCircuit::new(
+            old_fp, old_salt, &old_path, new_salt, debt_fp, debt_salt, amount_fp, max_ltv_fp,
+        );
+        let public_inputs = vec![
+            tree.root(),
+            amount_fp,
+            new_commitment,
+            debt_commitment,
+            max_ltv_fp,
+            nullifier,
+        ];
+
+        (circuit, public_inputs)
+    }
+
+    #[test]
+    fn test_withdraw_valid() {
+        let k = 17;
+
+        // 100 collateral, 60 debt, withdraw 20 → 80 left, LTV = 75% <= 80% ✓
+        let (circuit, public_inputs) = create_withdraw_circuit(100, 20, 60, 80);
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert_eq!(prover.verify(), Ok(()), "Healthy withdrawal should pass");
+    }
+
+    #[test]
+    fn test_withdraw_at_ltv_limit() {
+        let k = 17;
+
+        // 100 collateral, 60 debt, withdraw 25 → 75 left, LTV = 80% == 80% ✓
+        let (circuit, public_inputs) = create_withdraw_circuit(100, 25, 60, 80);
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert_eq!(prover.verify(), Ok(()), "Exactly at limit should pass");
+    }
+
+    #[test]
+    fn test_withdraw_exceeds_ltv() {
+        let k = 17;
+
+        // 100 collateral, 60 debt, withdraw 30 → 70 left, LTV ≈ 86% > 80% ✗
+        let (circuit, public_inputs) = create_withdraw_circuit(100, 30, 60, 80);
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err(), "Unhealthy withdrawal should fail");
+    }
+
+    #[test]
+    fn test_withdraw_all_without_debt() {
+        let k = 17;
+
+        let (circuit, public_inputs) = create_withdraw_circuit(100, 100, 0, 80);
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert_eq!(prover.verify(), Ok(()), "Full withdrawal with no debt should pass");
+    }
+
+    #[test]
+    fn test_withdraw_more_than_collateral() {
+        let k = 17;
+
+        // new_collateral = 100 - 101 wraps to p - 1
+        let (circuit, public_inputs) = create_withdraw_circuit(100, 101, 0, 80);
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err(), "Overdrawn collateral should fail");
+    }
+
+    #[test]
+    fn test_withdraw_wei_amounts() {
+        let k = 17;
+
+        // 10 ETH collateral, 6 ETH debt, withdraw 2 ETH (in wei)
+        let eth = 1_000_000_000_000_000_000u128;
+        let (circuit, public_inputs) = create_withdraw_circuit(10 * eth, 2 * eth, 6 * eth, 80);
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert_eq!(prover.verify(), Ok(()), "Wei amounts should work");
+    }
+
+    #[test]
+    fn test_withdraw_wrong_new_commitment() {
+        let k = 17;
+
+        // Claim a new commitment to more collateral than remains
+        let (circuit, mut public_inputs) = create_withdraw_circuit(100, 20, 60, 80);
+        public_inputs[2] = compute_commitment(Fp::from(90u64), Fp::from(NEW_SALT));
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err(), "Inflated new commitment should fail");
+    }
+
+    #[test]
+    fn test_withdraw_wrong_amount() {
+        let k = 17;
+
+        // Proof made for 20 cannot be reused to withdraw 40
+        let (circuit, mut public_inputs) = create_withdraw_circuit(100, 20, 60, 80);
+        public_inputs[1] = Fp::from(40u64);
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err(), "Mismatched public amount should fail");
+    }
+
+    #[test]
+    fn test_withdraw_wrong_root() {
+        let k = 17;
+
+        // The old commitment is proven against the registered root, not revealed
+        let (circuit, mut public_inputs) = create_withdraw_circuit(100, 20, 60, 80);
+        public_inputs[0] = compute_commitment(Fp::from(100u64), Fp::from(OLD_SALT));
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err(), "Old commitment in place of the root should fail");
+    }
+
+    #[test]
+    fn test_withdraw_unregistered_commitment() {
+        let k = 17;
+
+        // Tree of other positions only: no path opens the old commitment
+        let mut tree = IncrementalMerkleTree::new(COMMITMENT_TREE_DEPTH).unwrap();
+        for i in 0..3u64 {
+            tree.insert(compute_commitment(Fp::from(1000 + i), Fp::from(44444u64))).unwrap();
+        }
+        let other_path = tree.path(0).unwrap();
+
+        let (mut circuit, mut public_inputs) = create_withdraw_circuit(100, 20, 60, 80);
+        circuit.old_siblings = other_path.siblings.iter().map(|s| Value::known(*s)).collect();
+        circuit.old_path_bits = other_path.path_bits_as_field().into_iter().map(Value::known).collect();
+        public_inputs[0] = tree.root();
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err(), "Unregistered old commitment should fail");
+    }
+
+    #[test]
+    fn test_withdraw_wrong_path_depth() {
+        let k = 17;
+
+        let (mut circuit, public_inputs) = create_withdraw_circuit(100, 20, 60, 80);
+        circuit.old_siblings.pop();
+        circuit.old_path_bits.pop();
+
+        assert!(MockProver::run(k, &circuit, vec![public_inputs]).is_err(), "Short path should not synthesize");
+    }
+
+    #[test]
+    fn test_withdraw_max_ltv_bounds() {
+        let k = 17;
+
+        // debt = 0 would pass the LTV check for any max_ltv; the bound must still hold
+        let (circuit, public_inputs) = create_withdraw_circuit(100, 20, 0, 0);
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err(), "max_ltv = 0 should fail");
+
+        let (circuit, public_inputs) = create_withdraw_circuit(100, 20, 0, 101);
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err(), "max_ltv > 100 should fail");
+    }
+
+    #[test]
+    fn test_withdraw_nullifier_mismatch() {
+        let k = 17;
+
+        // Arbitrary bytes cannot stand in for the nullifier
+        let (circuit, mut public_inputs) = create_withdraw_circuit(100, 20, 60, 80);
+        public_inputs[5] = Fp::from(12345u64);
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err(), "Unrelated nullifier should fail");
+    }
+
+    #[test]
+    fn test_withdraw_second_nullifier_key_fails() {
+        let k = 17;
+
+        // A second key on the same commitment would give a second nullifier
+        // (double spend); the key is the salt, so it no longer opens the commitment
+        let (mut circuit, mut public_inputs) = create_withdraw_circuit(100, 20, 60, 80);
+        let other_salt = Fp::from(99999u64);
+        circuit.old_salt = Value::known(other_salt);
+        let old_commitment = compute_commitment(Fp::from(100u64), Fp::from(OLD_SALT));
+        public_inputs[5] = compute_nullifier(other_salt, old_commitment);
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err(), "Second nullifier for the same commitment should fail");
+    }
+
+    #[test]
+    fn test_withdraw_nullifier_bound_to_old_commitment() {
+        let k = 17;
+
+        // Nullifier of a different commitment cannot be used to spend this one
+        let (circuit, mut public_inputs) = create_withdraw_circuit(100, 20, 60, 80);
+        let other_commitment = compute_commitment(Fp::from(500u64), Fp::from(OLD_SALT));
+        public_inputs[5] = compute_nullifier(Fp::from(OLD_SALT), other_commitment);
+
+        let prover = MockProver::run(k, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err(), "Nullifier of another commitment should fail");
+    }
+}